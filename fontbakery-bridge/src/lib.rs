@@ -1,6 +1,6 @@
 // This is kind of dead for now since we have implemented all the fontbakery checks
-// that it used to cover. In the future, we'll bring it back as a way to run
-// arbitrary fontbakery checks inside of Rust.
+// that it used to cover. It is still used to run arbitrary Python checks inside
+// of Rust; see [register_python_module].
 #![allow(dead_code)]
 #![allow(non_upper_case_globals)]
 #![deny(clippy::unwrap_used, clippy::expect_used)]
@@ -8,46 +8,81 @@
 use fontspector_checkapi::{prelude::*, StatusCode};
 use pyo3::{
     prelude::*,
-    types::{PyList, PyTuple},
+    types::{PyDict, PyList, PyTuple},
 };
-use serde_json::json;
+use serde_json::{json, Map, Value};
 pub struct FontbakeryBridge;
 
+/// Mandatory arguments which mean a check runs on a single font
+const SINGLE_FONT_ARGS: [&str; 2] = ["font", "ttFont"];
+/// Mandatory arguments which mean a check runs on a whole collection of fonts
+const COLLECTION_ARGS: [&str; 2] = ["fonts", "ttFonts"];
+
+/// What a Python check is being run on
+enum PythonTarget<'a> {
+    /// A single font
+    Font(&'a Testable),
+    /// All the fonts in a collection
+    Collection(&'a TestableCollection),
+}
+
 // We isolate the Python part to avoid type/result madness.
 fn python_checkrunner_impl(
     module: &str,
     function: &str,
-    testable: &Testable,
+    target: PythonTarget,
+    configuration: &Map<String, Value>,
 ) -> PyResult<CheckFnResult> {
-    let filename = testable.filename.to_string_lossy();
     Python::with_gil(|py| {
         let module = PyModule::import_bound(py, module)?;
         let check = module.getattr(function)?;
+        let testable = PyModule::import_bound(py, "fontbakery.testable")?;
+        let font_class = testable.getattr("Font")?;
 
-        // Let's check this check's mandatory arguments
-        let args = check.getattr("mandatoryArgs")?.extract::<Vec<String>>()?;
-        if args.len() != 1 {
-            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "Expected exactly one mandatory argument".to_string(),
-            ));
-        }
-        let arg = if args[0] == "font" {
-            // Convert the Testable to a Python Font object
-            let testable = PyModule::import_bound(py, "fontbakery.testable")?;
-            let font = testable.getattr("Font")?;
-            font.call1((filename,))?
-        } else if args[0] == "ttFont" {
-            // Convert the Testable to a Python TTFont object
-            let ttlib = PyModule::import_bound(py, "fontTools.ttLib")?;
-            let ttfont = ttlib.getattr("TTFont")?;
-            ttfont.call1((filename,))?
-        } else {
-            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "Unknown mandatory argument".to_string(),
-            ));
+        // Arguments and conditions are looked up on a fontbakery Font, or for
+        // collection checks, on a CheckRunContext wrapping all the fonts.
+        let subject = match target {
+            PythonTarget::Font(t) => font_class.call1((t.filename.to_string_lossy(),))?,
+            PythonTarget::Collection(c) => {
+                let fonts = c
+                    .iter()
+                    .filter(|t| TTF.applies(t))
+                    .map(|t| font_class.call1((t.filename.to_string_lossy(),)))
+                    .collect::<PyResult<Vec<_>>>()?;
+                testable
+                    .getattr("CheckRunContext")?
+                    .call1((PyList::new_bound(py, fonts),))?
+            }
         };
 
-        let mut checkresult = check.call1((arg,))?;
+        let conditions = check.getattr("conditions")?.extract::<Vec<String>>()?;
+        for condition in conditions.iter() {
+            let (negated, name) = match condition.strip_prefix("not ") {
+                Some(name) => (true, name.trim()),
+                None => (false, condition.as_str()),
+            };
+            if subject.getattr(name)?.is_truthy()? == negated {
+                return Ok(Ok(Status::just_one_skip(
+                    "unfulfilled-conditions",
+                    &format!("Unfulfilled Conditions: {}", condition),
+                )));
+            }
+        }
+
+        let config = PyModule::import_bound(py, "json")?
+            .getattr("loads")?
+            .call1((Value::Object(configuration.clone()).to_string(),))?;
+        let kwargs = PyDict::new_bound(py);
+        for arg in check.getattr("mandatoryArgs")?.extract::<Vec<String>>()? {
+            let value = match arg.as_str() {
+                "font" => subject.clone(),
+                "config" => config.clone(),
+                other => subject.getattr(other)?,
+            };
+            kwargs.set_item(arg, value)?;
+        }
+
+        let mut checkresult = check.call((), Some(&kwargs))?;
         let mut messages: Vec<Status> = vec![];
 
         // If the checkresult is a single tuple, turn it into a list of tuples, and get a generator
@@ -84,15 +119,15 @@ fn python_checkrunner_impl(
                 severity: status,
                 code,
                 metadata: None,
+                overridden: None,
             });
         }
         Ok(return_result(messages))
     })
 }
 
-// This wrapper will work for any fontbakery check that takes a single
-// Font or ttFont object as an argument.
-fn python_checkrunner(c: &Testable, context: &Context) -> CheckFnResult {
+/// Find the Python module and function to call from the check's metadata
+fn python_function(context: &Context) -> Result<(&str, &str), CheckError> {
     let module = context
         .check_metadata
         .get("module")
@@ -105,85 +140,155 @@ fn python_checkrunner(c: &Testable, context: &Context) -> CheckFnResult {
         .ok_or_else(|| CheckError::Error("No function specified".to_string()))?
         .as_str()
         .ok_or_else(|| CheckError::Error("function in metadata was not a string!".to_string()))?;
-    python_checkrunner_impl(module, function, c)
-        .unwrap_or_else(|e| Err(CheckError::Error(format!("Python error: {}", e))))
+    Ok((module, function))
+}
+
+// This wrapper will work for any fontbakery check that takes a single
+// Font or ttFont object as an argument.
+fn python_checkrunner(c: &Testable, context: &Context) -> CheckFnResult {
+    let (module, function) = python_function(context)?;
+    python_checkrunner_impl(
+        module,
+        function,
+        PythonTarget::Font(c),
+        &context.configuration,
+    )
+    .unwrap_or_else(|e| Err(CheckError::Error(format!("Python error: {}", e))))
+}
+
+// And this one for fontbakery checks which take a list of fonts or ttFonts.
+fn python_collection_checkrunner(c: &TestableCollection, context: &Context) -> CheckFnResult {
+    let (module, function) = python_function(context)?;
+    python_checkrunner_impl(
+        module,
+        function,
+        PythonTarget::Collection(c),
+        &context.configuration,
+    )
+    .unwrap_or_else(|e| Err(CheckError::Error(format!("Python error: {}", e))))
+}
+
+/// Register all the fontbakery checks found in a Python module
+///
+/// Returns the IDs of the checks registered.
+fn register_checks_from_module(
+    py: Python,
+    module: &Bound<PyModule>,
+    modulename: &str,
+    cr: &mut Registry,
+) -> PyResult<Vec<String>> {
+    let callable = PyModule::import_bound(py, "fontbakery.callable")?;
+    // Find all functions in the module which are checks
+    let checktype = callable.getattr("FontBakeryCheck")?;
+    let mut registered = vec![];
+    for name in module.dir()?.iter() {
+        let name_str: String = name.extract()?;
+        let obj = module.getattr(name.downcast()?)?;
+        if let Ok(true) = obj.is_instance(&checktype) {
+            let id: String = obj.getattr("id")?.extract()?;
+            // Check the mandatory arguments
+            let args = obj.getattr("mandatoryArgs")?.extract::<Vec<String>>()?;
+            let implementation = if args.iter().any(|a| COLLECTION_ARGS.contains(&a.as_str())) {
+                CheckImplementation::CheckAll(&python_collection_checkrunner)
+            } else if args.iter().any(|a| SINGLE_FONT_ARGS.contains(&a.as_str())) {
+                CheckImplementation::CheckOne(&python_checkrunner)
+            } else {
+                log::warn!(
+                    "Can't load check {}; unable to support arguments: {}",
+                    id,
+                    args.join(", ")
+                );
+                continue;
+            };
+            let title: String = obj.getattr("__doc__")?.extract()?;
+            let py_rationale = obj.getattr("rationale")?;
+            let rationale: String = if py_rationale.is_instance_of::<PyList>() {
+                let r: Vec<String> = py_rationale.extract()?;
+                r.join(", ")
+            } else {
+                py_rationale.extract()?
+            };
+            let py_proposal = obj.getattr("proposal")?;
+            let proposals: Vec<String> = if py_proposal.is_instance_of::<PyList>() {
+                let r: Vec<String> = py_proposal.extract()?;
+                r.iter().map(|s| s.to_string()).collect()
+            } else {
+                let s: String = py_proposal.extract()?;
+                vec![s.to_string()]
+            };
+
+            // Collect into a Vec<&str> instead of a slice
+            // let leaked_proposals = leak_vec_string(proposals);
+            let leaked_proposals: &[&'static str] = proposals
+                .into_iter()
+                .map(|s| s.leak() as &'static str)
+                .collect::<Vec<&'static str>>()
+                .leak();
+
+            log::info!("Registered check: {}", id);
+            let metadata = json!({
+                "module": modulename,
+                "function": name_str,
+            });
+            registered.push(id.clone());
+            cr.register_check(Check {
+                id: id.leak(),
+                title: title.leak(),
+                rationale: rationale.leak(),
+                proposal: leaked_proposals,
+                hotfix: None,
+                fix_source: None,
+                applies_to: "TTF",
+                flags: CheckFlags::default(),
+                tags: &[],
+                codes: &[],
+                implementation,
+                _metadata: Some(metadata.to_string().leak()),
+            })
+        }
+    }
+    Ok(registered)
 }
 
 fn register_python_checks(modulename: &str, source: &str, cr: &mut Registry) -> Result<(), String> {
     Python::with_gil(|py| {
         // Assert that we have loaded the FB prelude
         let _prelude = PyModule::import_bound(py, "fontbakery.prelude")?;
-        let callable = PyModule::import_bound(py, "fontbakery.callable")?;
         let full_source = "from fontbakery.prelude import *\n\n".to_string() + source;
         let module =
             PyModule::from_code_bound(py, &full_source, &format!("{}.py", modulename), modulename)?;
-        // let check = module.getattr("check_hinting_impact")?;
-        // Find all functions in the module which are checks
-        let checktype = callable.getattr("FontBakeryCheck")?;
-        for name in module.dir()?.iter() {
-            let name_str: String = name.extract()?;
-            let obj = module.getattr(name.downcast()?)?;
-            if let Ok(true) = obj.is_instance(&checktype) {
-                let id: String = obj.getattr("id")?.extract()?;
-                // Check the mandatory arguments
-                let args = obj.getattr("mandatoryArgs")?.extract::<Vec<String>>()?;
-                if args.len() != 1 || !(args[0] == "font" || args[0] == "ttFont") {
-                    log::warn!(
-                        "Can't load check {}; unable to support arguments: {}",
-                        id,
-                        args.join(", ")
-                    );
-                    continue;
-                }
-                let title: String = obj.getattr("__doc__")?.extract()?;
-                let py_rationale = obj.getattr("rationale")?;
-                let rationale: String = if py_rationale.is_instance_of::<PyList>() {
-                    let r: Vec<String> = py_rationale.extract()?;
-                    r.join(", ")
-                } else {
-                    py_rationale.extract()?
-                };
-                let py_proposal = obj.getattr("proposal")?;
-                let proposals: Vec<String> = if py_proposal.is_instance_of::<PyList>() {
-                    let r: Vec<String> = py_proposal.extract()?;
-                    r.iter().map(|s| s.to_string()).collect()
-                } else {
-                    let s: String = py_proposal.extract()?;
-                    vec![s.to_string()]
-                };
-
-                // Collect into a Vec<&str> instead of a slice
-                // let leaked_proposals = leak_vec_string(proposals);
-                let leaked_proposals: &[&'static str] = proposals
-                    .into_iter()
-                    .map(|s| s.leak() as &'static str)
-                    .collect::<Vec<&'static str>>()
-                    .leak();
-
-                log::info!("Registered check: {}", id);
-                let metadata = json!({
-                    "module": modulename,
-                    "function": name_str,
-                });
-                cr.register_check(Check {
-                    id: id.leak(),
-                    title: title.leak(),
-                    rationale: rationale.leak(),
-                    proposal: leaked_proposals,
-                    hotfix: None,
-                    fix_source: None,
-                    applies_to: "TTF",
-                    flags: CheckFlags::default(),
-                    implementation: CheckImplementation::CheckOne(&python_checkrunner),
-                    _metadata: Some(metadata.to_string().leak()),
-                })
-            }
-        }
+        register_checks_from_module(py, &module, modulename, cr)?;
         Ok(())
     })
     .map_err(|e: PyErr| format!("Error loading checks: {}", e))
 }
 
+/// Register the fontbakery-style checks in an installed Python module
+///
+/// The module is imported by its dotted path (e.g. `mychecks.outlines`),
+/// from the current directory or anywhere on the Python path, and every
+/// `@check`-decorated function in it is registered. Checks taking a `font`
+/// or `ttFont` argument run on each font; checks taking `fonts` or `ttFonts`
+/// run once on the whole collection. The module is expected to import its
+/// decorators from an installed copy of fontbakery.
+///
+/// Returns the IDs of the checks registered.
+pub fn register_python_module(modulename: &str, cr: &mut Registry) -> Result<Vec<String>, String> {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let sys_path = PyModule::import_bound(py, "sys")?.getattr("path")?;
+        let cwd = PyModule::import_bound(py, "os")?
+            .getattr("getcwd")?
+            .call0()?;
+        if !sys_path.contains(&cwd)? {
+            sys_path.call_method1("insert", (0, cwd))?;
+        }
+        let module = PyModule::import_bound(py, modulename)?;
+        register_checks_from_module(py, &module, modulename, cr)
+    })
+    .map_err(|e: PyErr| format!("Error loading checks from {}: {}", modulename, e))
+}
+
 impl fontspector_checkapi::Plugin for FontbakeryBridge {
     fn register(&self, cr: &mut Registry) -> Result<(), String> {
         pyo3::prepare_freethreaded_python();
@@ -285,4 +390,103 @@ impl fontspector_checkapi::Plugin for FontbakeryBridge {
 }
 
 #[cfg(not(target_family = "wasm"))]
-pluginator::plugin_implementation!(fontspector_checkapi::Plugin, FontbakeryBridge);
+fontspector_checkapi::declare_plugin!(FontbakeryBridge);
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, run_check, run_check_on_collection},
+        TEST_FILE,
+    };
+
+    /// Make the check modules in `tests/` importable
+    ///
+    /// Returns false if there is no installed fontbakery for them to import.
+    fn setup_python() -> bool {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            PyModule::import_bound(py, "sys")
+                .and_then(|sys| sys.getattr("path"))
+                .and_then(|path| {
+                    path.call_method1("insert", (0, concat!(env!("CARGO_MANIFEST_DIR"), "/tests")))
+                })
+                .unwrap();
+            PyModule::import_bound(py, "fontbakery.prelude").is_ok()
+        })
+    }
+
+    #[test]
+    fn test_register_python_module() {
+        if !setup_python() {
+            eprintln!("fontbakery is not installed; skipping");
+            return;
+        }
+        let mut registry = Registry::new();
+        let mut registered = register_python_module("bridge_test_checks", &mut registry).unwrap();
+        registered.sort();
+        assert_eq!(
+            registered,
+            vec![
+                "bridge_test/always_warns",
+                "bridge_test/family_size",
+                "bridge_test/has_glyphs"
+            ]
+        );
+        let check = |id: &str| registry.checks.get(id).unwrap().clone();
+        assert!(matches!(
+            check("bridge_test/has_glyphs").implementation,
+            CheckImplementation::CheckOne(_)
+        ));
+        assert!(matches!(
+            check("bridge_test/family_size").implementation,
+            CheckImplementation::CheckAll(_)
+        ));
+
+        assert_pass(run_check(
+            check("bridge_test/has_glyphs"),
+            TEST_FILE!("nunito/Nunito-Regular.ttf"),
+        ));
+        // A single (status, message) tuple rather than a generator
+        assert_results_contain(
+            run_check(
+                check("bridge_test/always_warns"),
+                TEST_FILE!("nunito/Nunito-Regular.ttf"),
+            ),
+            StatusCode::Warn,
+            Some("always-warns".to_string()),
+        );
+        assert_results_contain(
+            run_check_on_collection(
+                check("bridge_test/family_size"),
+                vec![TEST_FILE!("nunito/Nunito-Regular.ttf")],
+            ),
+            StatusCode::Warn,
+            Some("single-font".to_string()),
+        );
+        assert_pass(run_check_on_collection(
+            check("bridge_test/family_size"),
+            vec![
+                TEST_FILE!("montserrat/Montserrat-Regular.ttf"),
+                TEST_FILE!("montserrat/Montserrat-Bold.ttf"),
+            ],
+        ));
+    }
+
+    #[test]
+    fn test_register_python_module_import_error() {
+        if !setup_python() {
+            eprintln!("fontbakery is not installed; skipping");
+            return;
+        }
+        let mut registry = Registry::new();
+        let error = register_python_module("bridge_test_broken", &mut registry).unwrap_err();
+        assert!(error.contains("bridge_test_broken"));
+        assert!(error.contains("fontspector_no_such_module"));
+        let error =
+            register_python_module("bridge_test_no_such_module", &mut registry).unwrap_err();
+        assert!(error.contains("bridge_test_no_such_module"));
+        assert!(registry.checks.is_empty());
+    }
+}
@@ -0,0 +1,2 @@
+# A module of checks which fails to import, for testing register_python_module
+import fontspector_no_such_module  # noqa: F401
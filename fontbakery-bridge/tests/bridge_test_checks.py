@@ -0,0 +1,36 @@
+# A module of fontbakery-style checks for testing register_python_module
+from fontbakery.prelude import check, Message, PASS, WARN, FAIL
+
+
+@check(
+    id="bridge_test/has_glyphs",
+    rationale="Fonts should have some glyphs.",
+    proposal="https://github.com/fonttools/fontspector",
+)
+def check_has_glyphs(ttFont):
+    """Check the font has glyphs."""
+    if len(ttFont.getGlyphOrder()) > 1:
+        yield PASS, "The font has glyphs."
+    else:
+        yield FAIL, Message("no-glyphs", "The font has no glyphs.")
+
+
+@check(
+    id="bridge_test/always_warns",
+    rationale="Checks can return a single status.",
+    proposal="https://github.com/fonttools/fontspector",
+)
+def check_always_warns(font):
+    """Always warn."""
+    return WARN, Message("always-warns", f"{font.file} was checked.")
+
+
+@check(
+    id="bridge_test/family_size",
+    rationale="Checks can look at all the fonts at once.",
+    proposal=["https://github.com/fonttools/fontspector"],
+)
+def check_family_size(fonts):
+    """Check the family has more than one font."""
+    if len(fonts) < 2:
+        yield WARN, Message("single-font", "The family has only one font.")
@@ -5,7 +5,7 @@ use crate::{
     prelude::FixFnResult,
     status::{CheckError, CheckFnResult},
     testable::{TestableCollection, TestableType},
-    CheckResult, Registry, Status, Testable,
+    CheckResult, Registry, Status, StatusCode, Testable,
 };
 
 /// A check ID is a unique identifier for a check
@@ -20,6 +20,12 @@ type CheckAllSignature = dyn Fn(&TestableCollection, &Context) -> CheckFnResult;
 pub struct CheckFlags {
     /// Whether the check is experimental
     pub experimental: bool,
+    /// Whether the check's results depend on the location in a variable font
+    ///
+    /// When instance sweeping is requested, location-sensitive checks are run
+    /// again at each instance, master and axis extreme of a variable font;
+    /// see [crate::sweep_check_order].
+    pub location_sensitive: bool,
 }
 
 impl CheckFlags {
@@ -29,10 +35,23 @@ impl CheckFlags {
     pub const fn default() -> Self {
         Self {
             experimental: false,
+            location_sensitive: false,
         }
     }
 }
 
+#[derive(Clone, Debug)]
+/// A result code which a check declares that it may emit
+///
+/// Declaring result codes allows overrides to be validated, and allows
+/// documentation to be generated for each check.
+pub struct ResultCode<'a> {
+    /// The code itself, e.g. `bad-entries`
+    pub code: &'a str,
+    /// The severity with which the check normally emits this code
+    pub severity: StatusCode,
+}
+
 #[derive(Clone)]
 /// A check definition
 ///
@@ -70,6 +89,12 @@ pub struct Check<'a> {
     pub applies_to: &'a str,
     /// Additional flags for the check
     pub flags: CheckFlags,
+    /// Free-form tags used to categorise and select checks (e.g. "network", "slow")
+    pub tags: &'a [&'a str],
+    /// The result codes this check may emit
+    ///
+    /// An empty list means that the check has not declared its codes.
+    pub codes: &'a [ResultCode<'a>],
     /// Metadata for the check in JSON format
     pub _metadata: Option<&'static str>,
 }
@@ -98,6 +123,16 @@ impl<'a> Check<'a> {
         }
     }
 
+    /// Does this check carry the given tag?
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    /// Look up a result code in the check's declared catalogue
+    pub fn declared_code(&self, code: &str) -> Option<&ResultCode<'_>> {
+        self.codes.iter().find(|rc| rc.code == code)
+    }
+
     /// Get the metadata for this check
     ///
     /// Each check definition can declare associated metadata; this is
@@ -127,15 +162,29 @@ impl<'a> Check<'a> {
             Err(CheckError::Error(e)) => vec![Status::error(None, &format!("Error: {}", e))],
             Err(CheckError::Skip { code, message }) => vec![Status::skip(&code, &message)],
         };
+        for status in subresults.iter() {
+            if let Some(code) = status.code.as_deref() {
+                debug_assert!(
+                    self.codes.is_empty() || self.declared_code(code).is_some(),
+                    "Check {} emitted result code {} which it does not declare",
+                    self.id,
+                    code
+                );
+            }
+        }
         let mut res = if subresults.is_empty() {
             vec![Status::pass()]
         } else {
             subresults
         };
-        for status in res.iter_mut() {
-            status.process_override(&context.overrides);
+        if !context.overrides.is_empty() {
+            for status in res.iter_mut() {
+                status.process_override(&context.overrides, self.id, filename, context.today);
+            }
         }
-        CheckResult::new(self, filename, source_filename, section, res, duration)
+        let mut result = CheckResult::new(self, filename, source_filename, section, res, duration);
+        result.location = context.location_label();
+        result
     }
 
     /// Run the check, either on a collection or a single file.
@@ -36,6 +36,9 @@ pub struct CheckResult {
     pub filename: Option<String>,
     /// The source where this file came from, if any
     pub source_filename: Option<String>,
+    /// The variable font location(s) at which this result was found, if the
+    /// check was swept across the font's instances
+    pub location: Option<String>,
     /// The section of the profile this check belongs to
     pub section: Option<String>,
     /// The individual results of the check
@@ -50,13 +53,18 @@ pub struct CheckResult {
 
 impl Serialize for CheckResult {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let fields =
-            7 + self.hotfix_result.is_some() as usize + self.sourcefix_result.is_some() as usize;
+        let fields = 7
+            + self.location.is_some() as usize
+            + self.hotfix_result.is_some() as usize
+            + self.sourcefix_result.is_some() as usize;
         let mut s = serializer.serialize_struct("CheckResult", fields)?;
         s.serialize_field("check_id", &self.check_id)?;
         s.serialize_field("check_name", &self.check_name)?;
         s.serialize_field("check_rationale", &self.check_rationale)?;
         s.serialize_field("filename", &self.filename)?;
+        if let Some(location) = &self.location {
+            s.serialize_field("location", location)?;
+        }
         s.serialize_field("section", &self.section)?;
         s.serialize_field("subresults", &self.subresults)?;
         s.serialize_field("worst_status", &self.worst_status())?;
@@ -86,6 +94,7 @@ impl CheckResult {
             check_rationale: check.rationale.to_string(),
             filename: filename.map(|x| x.to_string()),
             source_filename: source_filename.map(|x| x.to_string()),
+            location: None,
             section: section.map(|x| x.to_string()),
             subresults,
             hotfix_result: None,
@@ -94,6 +103,16 @@ impl CheckResult {
         }
     }
 
+    /// The file which was checked, followed by the location it was checked at, if any
+    ///
+    /// e.g. `Foo[wght].ttf @ wght=900`
+    pub fn filename_and_location(&self) -> Option<String> {
+        match (&self.filename, &self.location) {
+            (Some(filename), Some(location)) => Some(format!("{} @ {}", filename, location)),
+            (filename, _) => filename.clone(),
+        }
+    }
+
     /// Get the worst status of all subresults
     pub fn worst_status(&self) -> StatusCode {
         self.subresults
@@ -0,0 +1,719 @@
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    fmt::{Display, Formatter},
+};
+
+use skrifa::{
+    raw::{
+        tables::{
+            gsub::{
+                ChainedSequenceContext, ClassDef, CoverageTable, Gsub,
+                ReverseChainSingleSubstFormat1, SequenceContext, SubstitutionLookupList,
+                SubstitutionSubtables,
+            },
+            layout::SequenceLookupRecord,
+        },
+        ReadError, TableProvider,
+    },
+    GlyphId, GlyphId16, MetadataProvider, Tag,
+};
+
+use crate::{GetSubstitutionMap, SubstitutionMap, TestFont};
+
+/// Nested contextual lookups are only followed to this depth
+const MAX_NESTING_DEPTH: usize = 8;
+
+/// The language tag used for a script's default language system
+const DEFAULT_LANGUAGE: Tag = Tag::new(b"dflt");
+
+/// A script, language system and feature through which a lookup is applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FeaturePath {
+    /// The script tag, e.g. `latn`
+    pub script: Tag,
+    /// The language system tag, or `dflt` for the script's default language system
+    pub language: Tag,
+    /// The feature tag, e.g. `liga`
+    pub feature: Tag,
+}
+
+impl Display for FeaturePath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}/{}", self.script, self.language, self.feature)
+    }
+}
+
+/// How a glyph was first reached while computing a [GsubClosure]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reached {
+    /// The glyph is mapped from this codepoint in the `cmap` table
+    Cmap(u32),
+    /// The glyph was one of the glyphs the closure started from
+    Start,
+    /// The glyph is produced by a substitution
+    Substitution {
+        /// The feature whose lookups made the substitution
+        path: FeaturePath,
+        /// The lookups involved, from the one referenced by the feature to
+        /// the (possibly nested) lookup which made the substitution
+        lookups: Vec<u16>,
+        /// The glyphs which were substituted
+        input: Vec<GlyphId16>,
+    },
+}
+
+/// The set of glyphs reachable through `GSUB` substitutions from a starting set
+///
+/// The closure follows every kind of substitution, including contextual and
+/// chained contextual lookups (and the lookups nested within them), for each
+/// script, language system and feature in the font. As well as which glyphs
+/// can be reached, it remembers how each glyph was first reached, so that
+/// checks can explain the feature path leading to it.
+#[derive(Debug, Clone, Default)]
+pub struct GsubClosure {
+    /// Each reachable glyph, and how it was first reached
+    reached: HashMap<GlyphId16, Reached>,
+}
+
+impl GsubClosure {
+    /// Compute the closure of a set of glyphs
+    ///
+    /// Only lookups used by features for which `include` returns true are
+    /// followed; pass `|_| true` to follow every feature.
+    pub fn new(
+        font: &TestFont,
+        start: impl IntoIterator<Item = (GlyphId16, Reached)>,
+        include: impl Fn(&FeaturePath) -> bool,
+    ) -> Result<Self, ReadError> {
+        let mut reached = HashMap::new();
+        for (gid, how) in start {
+            reached.entry(gid).or_insert(how);
+        }
+        let Ok(gsub) = font.font().gsub() else {
+            return Ok(Self { reached });
+        };
+        let roots = feature_lookups(&gsub, include)?;
+        let mut builder = ClosureBuilder {
+            lookups: gsub.lookup_list()?,
+            simple: HashMap::new(),
+            reached,
+        };
+        // Anything found in one pass may itself be substituted, so keep going
+        // until nothing new turns up.
+        loop {
+            let before = builder.reached.len();
+            for (lookup, path) in roots.iter() {
+                builder.apply(*lookup, path, &[*lookup], None, 0)?;
+            }
+            if builder.reached.len() == before {
+                break;
+            }
+        }
+        Ok(Self {
+            reached: builder.reached,
+        })
+    }
+
+    /// Is the glyph reachable?
+    pub fn contains(&self, gid: impl Into<GlyphId>) -> bool {
+        GlyphId16::try_from(gid.into()).is_ok_and(|gid| self.reached.contains_key(&gid))
+    }
+
+    /// Iterate over the reachable glyphs
+    pub fn glyphs(&self) -> impl Iterator<Item = GlyphId16> + '_ {
+        self.reached.keys().copied()
+    }
+
+    /// How a glyph was first reached, if it is reachable
+    pub fn reached(&self, gid: impl Into<GlyphId>) -> Option<&Reached> {
+        GlyphId16::try_from(gid.into())
+            .ok()
+            .and_then(|gid| self.reached.get(&gid))
+    }
+
+    /// The substitutions leading to a glyph, starting from an encoded (or starting) glyph
+    ///
+    /// Where a substitution has several input glyphs (a ligature, say), the
+    /// path follows the first of them.
+    pub fn path(&self, gid: impl Into<GlyphId>) -> Vec<(GlyphId16, &Reached)> {
+        let mut path = vec![];
+        let mut seen = HashSet::new();
+        let mut current = GlyphId16::try_from(gid.into()).ok();
+        while let Some(gid) = current {
+            let Some(how) = self.reached.get(&gid) else {
+                break;
+            };
+            if !seen.insert(gid) {
+                break;
+            }
+            path.push((gid, how));
+            current = match how {
+                Reached::Substitution { input, .. } => input.first().copied(),
+                _ => None,
+            };
+        }
+        path.reverse();
+        path
+    }
+
+    /// Describe how a glyph is reached, for use in check messages
+    ///
+    /// e.g. `f (U+0066) + i → f_i (latn/dflt/liga, lookup 4)`
+    pub fn explain(&self, font: &TestFont, gid: impl Into<GlyphId>) -> String {
+        let path = self.path(gid);
+        let mut steps = vec![];
+        for (gid, how) in path.iter() {
+            let name = font.glyph_name_for_id_synthesise(*gid);
+            match how {
+                Reached::Cmap(codepoint) => steps.push(format!("{} (U+{:04X})", name, codepoint)),
+                Reached::Start => steps.push(name),
+                Reached::Substitution {
+                    path,
+                    lookups,
+                    input,
+                } => {
+                    let input = input
+                        .iter()
+                        .map(|gid| font.glyph_name_for_id_synthesise(*gid))
+                        .collect::<Vec<_>>()
+                        .join(" + ");
+                    let lookups = lookups
+                        .iter()
+                        .map(|lookup| lookup.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" → ");
+                    steps.push(format!(
+                        "{} → {} ({}, lookup {})",
+                        input, name, path, lookups
+                    ));
+                }
+            }
+        }
+        steps.join("; ")
+    }
+}
+
+impl TestFont<'_> {
+    /// Compute the glyphs reachable through `GSUB` from a set of codepoints
+    ///
+    /// Codepoints which are not in the font are ignored.
+    pub fn gsub_closure(
+        &self,
+        codepoints: impl IntoIterator<Item = u32>,
+    ) -> Result<GsubClosure, ReadError> {
+        let charmap = self.font().charmap();
+        let start = codepoints.into_iter().filter_map(|codepoint| {
+            charmap
+                .map(codepoint)
+                .and_then(|gid| GlyphId16::try_from(gid).ok())
+                .map(|gid| (gid, Reached::Cmap(codepoint)))
+        });
+        GsubClosure::new(self, start, |_| true)
+    }
+}
+
+/// The lookups referenced by each feature, in the order first encountered
+///
+/// Lookups used by more than one feature are attributed to the first one.
+fn feature_lookups(
+    gsub: &Gsub,
+    include: impl Fn(&FeaturePath) -> bool,
+) -> Result<Vec<(u16, FeaturePath)>, ReadError> {
+    let script_list = gsub.script_list()?;
+    let feature_list = gsub.feature_list()?;
+    let feature_variations = gsub.feature_variations().transpose()?;
+    let mut seen = HashSet::new();
+    let mut roots = vec![];
+    for script_record in script_list.script_records() {
+        let script = script_record.script(script_list.offset_data())?;
+        let mut language_systems = vec![];
+        if let Some(langsys) = script.default_lang_sys().transpose()? {
+            language_systems.push((DEFAULT_LANGUAGE, langsys));
+        }
+        for record in script.lang_sys_records() {
+            language_systems.push((
+                record.lang_sys_tag(),
+                record.lang_sys(script.offset_data())?,
+            ));
+        }
+        for (language, langsys) in language_systems {
+            let required = langsys.required_feature_index();
+            let feature_indices = (required != 0xFFFF)
+                .then_some(required)
+                .into_iter()
+                .chain(langsys.feature_indices().iter().map(|index| index.get()));
+            for feature_index in feature_indices {
+                let Some(record) = feature_list.feature_records().get(feature_index as usize)
+                else {
+                    continue;
+                };
+                let path = FeaturePath {
+                    script: script_record.script_tag(),
+                    language,
+                    feature: record.feature_tag(),
+                };
+                if !include(&path) {
+                    continue;
+                }
+                let feature = record.feature(feature_list.offset_data())?;
+                let mut lookups: Vec<u16> = feature
+                    .lookup_list_indices()
+                    .iter()
+                    .map(|index| index.get())
+                    .collect();
+                // Feature variations may swap in other lookups for this feature
+                if let Some(variations) = feature_variations.as_ref() {
+                    for variation in variations.feature_variation_records() {
+                        let Some(substitution) =
+                            variation.feature_table_substitution(variations.offset_data())
+                        else {
+                            continue;
+                        };
+                        let substitution = substitution?;
+                        for alternate in substitution.substitutions() {
+                            if alternate.feature_index() == feature_index {
+                                let feature =
+                                    alternate.alternate_feature(substitution.offset_data())?;
+                                lookups.extend(
+                                    feature
+                                        .lookup_list_indices()
+                                        .iter()
+                                        .map(|index| index.get()),
+                                );
+                            }
+                        }
+                    }
+                }
+                for lookup in lookups {
+                    if seen.insert(lookup) {
+                        roots.push((lookup, path));
+                    }
+                }
+            }
+        }
+    }
+    Ok(roots)
+}
+
+/// A contextual rule which can match: the glyphs which can appear at each
+/// input position, and the nested lookups to apply
+type ContextMatch = (Vec<HashSet<GlyphId16>>, Vec<(u16, u16)>);
+
+/// The state of a closure computation
+struct ClosureBuilder<'a> {
+    /// The font's GSUB lookups
+    lookups: SubstitutionLookupList<'a>,
+    /// The substitutions made by non-contextual lookups, by lookup index
+    simple: HashMap<u16, SubstitutionMap>,
+    /// The glyphs reached so far
+    reached: HashMap<GlyphId16, Reached>,
+}
+
+impl ClosureBuilder<'_> {
+    /// Is a glyph reached, and allowed at the current position?
+    fn allowed(&self, gid: GlyphId16, restrict: Option<&HashSet<GlyphId16>>) -> bool {
+        self.reached.contains_key(&gid) && restrict.is_none_or(|set| set.contains(&gid))
+    }
+
+    /// The reached glyphs in a coverage table
+    fn covered(
+        &self,
+        coverage: &CoverageTable,
+        restrict: Option<&HashSet<GlyphId16>>,
+    ) -> HashSet<GlyphId16> {
+        coverage
+            .iter()
+            .filter(|gid| self.allowed(*gid, restrict))
+            .collect()
+    }
+
+    /// The reached glyphs in each class of a class definition
+    fn classes(&self, classdef: &ClassDef) -> HashMap<u16, HashSet<GlyphId16>> {
+        let mut classes: HashMap<u16, HashSet<GlyphId16>> = HashMap::new();
+        for gid in self.reached.keys() {
+            classes.entry(classdef.get(*gid)).or_default().insert(*gid);
+        }
+        classes
+    }
+
+    /// Record that a lookup has produced some glyphs
+    fn add(&mut self, produced: Vec<(GlyphId16, Reached)>) {
+        for (gid, how) in produced {
+            self.reached.entry(gid).or_insert(how);
+        }
+    }
+
+    /// Apply a lookup to the glyphs reached so far
+    ///
+    /// `chain` is the list of lookups which led here, ending with this one;
+    /// `restrict`, if given, limits the glyphs the lookup may substitute to
+    /// those which can appear at the position where a contextual lookup
+    /// applies it.
+    fn apply(
+        &mut self,
+        lookup_index: u16,
+        path: &FeaturePath,
+        chain: &[u16],
+        restrict: Option<&HashSet<GlyphId16>>,
+        depth: usize,
+    ) -> Result<(), ReadError> {
+        let lookup = self.lookups.lookups().get(lookup_index as usize)?;
+        let matches = match lookup.subtables()? {
+            SubstitutionSubtables::Contextual(subtables) => {
+                let mut matches = vec![];
+                for subtable in subtables.iter() {
+                    matches.extend(self.context_matches(&subtable?, restrict)?);
+                }
+                matches
+            }
+            SubstitutionSubtables::ChainContextual(subtables) => {
+                let mut matches = vec![];
+                for subtable in subtables.iter() {
+                    matches.extend(self.chain_context_matches(&subtable?, restrict)?);
+                }
+                matches
+            }
+            SubstitutionSubtables::Reverse(subtables) => {
+                let mut produced = vec![];
+                for subtable in subtables.iter() {
+                    produced.extend(self.reverse_substitutions(&subtable?, path, chain, restrict)?);
+                }
+                self.add(produced);
+                return Ok(());
+            }
+            subtables => {
+                if let Entry::Vacant(entry) = self.simple.entry(lookup_index) {
+                    entry.insert(subtables.substitutions()?);
+                }
+                let mut produced = vec![];
+                for (lhs, rhs) in self.simple[&lookup_index].iter() {
+                    let Some((first, rest)) = lhs.split_first() else {
+                        continue;
+                    };
+                    if !self.allowed(*first, restrict)
+                        || !rest.iter().all(|gid| self.reached.contains_key(gid))
+                    {
+                        continue;
+                    }
+                    for gid in rhs.iter().filter(|gid| !self.reached.contains_key(gid)) {
+                        produced.push((
+                            *gid,
+                            Reached::Substitution {
+                                path: *path,
+                                lookups: chain.to_vec(),
+                                input: lhs.clone(),
+                            },
+                        ));
+                    }
+                }
+                self.add(produced);
+                return Ok(());
+            }
+        };
+        if depth >= MAX_NESTING_DEPTH {
+            return Ok(());
+        }
+        for (positions, nested) in matches {
+            for (sequence_index, nested_lookup) in nested {
+                let Some(position) = positions.get(sequence_index as usize) else {
+                    continue;
+                };
+                let mut nested_chain = chain.to_vec();
+                nested_chain.push(nested_lookup);
+                self.apply(
+                    nested_lookup,
+                    path,
+                    &nested_chain,
+                    Some(position),
+                    depth + 1,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The rules of a contextual subtable which can match the reached glyphs
+    fn context_matches(
+        &self,
+        subtable: &SequenceContext,
+        restrict: Option<&HashSet<GlyphId16>>,
+    ) -> Result<Vec<ContextMatch>, ReadError> {
+        let mut matches = vec![];
+        match subtable {
+            SequenceContext::Format1(table) => {
+                for (first, rule_set) in table.coverage()?.iter().zip(table.seq_rule_sets().iter())
+                {
+                    let Some(rule_set) = rule_set.filter(|_| self.allowed(first, restrict)) else {
+                        continue;
+                    };
+                    for rule in rule_set?.seq_rules().iter() {
+                        let rule = rule?;
+                        let input = rule.input_sequence();
+                        if input
+                            .iter()
+                            .all(|gid| self.reached.contains_key(&gid.get()))
+                        {
+                            matches.push((
+                                glyph_positions(first, input.iter().map(|gid| gid.get())),
+                                nested_lookups(rule.seq_lookup_records()),
+                            ));
+                        }
+                    }
+                }
+            }
+            SequenceContext::Format2(table) => {
+                let classes = self.classes(&table.class_def()?);
+                let covered = self.covered(&table.coverage()?, restrict);
+                for (class, rule_set) in table.class_seq_rule_sets().iter().enumerate() {
+                    let Some(rule_set) = rule_set else {
+                        continue;
+                    };
+                    let first = class_members(&classes, class as u16)
+                        .intersection(&covered)
+                        .copied()
+                        .collect::<HashSet<_>>();
+                    if first.is_empty() {
+                        continue;
+                    }
+                    for rule in rule_set?.class_seq_rules().iter() {
+                        let rule = rule?;
+                        if let Some(positions) = class_positions(
+                            first.clone(),
+                            rule.input_sequence().iter().map(|class| class.get()),
+                            &classes,
+                        ) {
+                            matches.push((positions, nested_lookups(rule.seq_lookup_records())));
+                        }
+                    }
+                }
+            }
+            SequenceContext::Format3(table) => {
+                let mut positions = vec![];
+                for (i, coverage) in table.coverages().iter().enumerate() {
+                    positions.push(self.covered(&coverage?, restrict.filter(|_| i == 0)));
+                }
+                if positions.iter().all(|position| !position.is_empty()) {
+                    matches.push((positions, nested_lookups(table.seq_lookup_records())));
+                }
+            }
+        }
+        Ok(matches)
+    }
+
+    /// The rules of a chained contextual subtable which can match the reached glyphs
+    fn chain_context_matches(
+        &self,
+        subtable: &ChainedSequenceContext,
+        restrict: Option<&HashSet<GlyphId16>>,
+    ) -> Result<Vec<ContextMatch>, ReadError> {
+        let mut matches = vec![];
+        match subtable {
+            ChainedSequenceContext::Format1(table) => {
+                for (first, rule_set) in table
+                    .coverage()?
+                    .iter()
+                    .zip(table.chained_seq_rule_sets().iter())
+                {
+                    let Some(rule_set) = rule_set.filter(|_| self.allowed(first, restrict)) else {
+                        continue;
+                    };
+                    for rule in rule_set?.chained_seq_rules().iter() {
+                        let rule = rule?;
+                        if rule
+                            .backtrack_sequence()
+                            .iter()
+                            .chain(rule.input_sequence())
+                            .chain(rule.lookahead_sequence())
+                            .all(|gid| self.reached.contains_key(&gid.get()))
+                        {
+                            matches.push((
+                                glyph_positions(
+                                    first,
+                                    rule.input_sequence().iter().map(|gid| gid.get()),
+                                ),
+                                nested_lookups(rule.seq_lookup_records()),
+                            ));
+                        }
+                    }
+                }
+            }
+            ChainedSequenceContext::Format2(table) => {
+                let backtrack = self.classes(&table.backtrack_class_def()?);
+                let input = self.classes(&table.input_class_def()?);
+                let lookahead = self.classes(&table.lookahead_class_def()?);
+                let covered = self.covered(&table.coverage()?, restrict);
+                for (class, rule_set) in table.chained_class_seq_rule_sets().iter().enumerate() {
+                    let Some(rule_set) = rule_set else {
+                        continue;
+                    };
+                    let first = class_members(&input, class as u16)
+                        .intersection(&covered)
+                        .copied()
+                        .collect::<HashSet<_>>();
+                    if first.is_empty() {
+                        continue;
+                    }
+                    for rule in rule_set?.chained_class_seq_rules().iter() {
+                        let rule = rule?;
+                        let context_matches = rule
+                            .backtrack_sequence()
+                            .iter()
+                            .all(|class| backtrack.contains_key(&class.get()))
+                            && rule
+                                .lookahead_sequence()
+                                .iter()
+                                .all(|class| lookahead.contains_key(&class.get()));
+                        if !context_matches {
+                            continue;
+                        }
+                        if let Some(positions) = class_positions(
+                            first.clone(),
+                            rule.input_sequence().iter().map(|class| class.get()),
+                            &input,
+                        ) {
+                            matches.push((positions, nested_lookups(rule.seq_lookup_records())));
+                        }
+                    }
+                }
+            }
+            ChainedSequenceContext::Format3(table) => {
+                for coverage in table
+                    .backtrack_coverages()
+                    .iter()
+                    .chain(table.lookahead_coverages().iter())
+                {
+                    if self.covered(&coverage?, None).is_empty() {
+                        return Ok(matches);
+                    }
+                }
+                let mut positions = vec![];
+                for (i, coverage) in table.input_coverages().iter().enumerate() {
+                    positions.push(self.covered(&coverage?, restrict.filter(|_| i == 0)));
+                }
+                if positions.iter().all(|position| !position.is_empty()) {
+                    matches.push((positions, nested_lookups(table.seq_lookup_records())));
+                }
+            }
+        }
+        Ok(matches)
+    }
+
+    /// The glyphs produced by a reverse chaining substitution subtable
+    fn reverse_substitutions(
+        &self,
+        subtable: &ReverseChainSingleSubstFormat1,
+        path: &FeaturePath,
+        chain: &[u16],
+        restrict: Option<&HashSet<GlyphId16>>,
+    ) -> Result<Vec<(GlyphId16, Reached)>, ReadError> {
+        for coverage in subtable
+            .backtrack_coverages()
+            .iter()
+            .chain(subtable.lookahead_coverages().iter())
+        {
+            if self.covered(&coverage?, None).is_empty() {
+                return Ok(vec![]);
+            }
+        }
+        Ok(subtable
+            .coverage()?
+            .iter()
+            .zip(subtable.substitute_glyph_ids())
+            .filter(|(gid, substitute)| {
+                self.allowed(*gid, restrict) && !self.reached.contains_key(&substitute.get())
+            })
+            .map(|(gid, substitute)| {
+                (
+                    substitute.get(),
+                    Reached::Substitution {
+                        path: *path,
+                        lookups: chain.to_vec(),
+                        input: vec![gid],
+                    },
+                )
+            })
+            .collect())
+    }
+}
+
+/// The glyphs in a class, or none
+fn class_members(classes: &HashMap<u16, HashSet<GlyphId16>>, class: u16) -> HashSet<GlyphId16> {
+    classes.get(&class).cloned().unwrap_or_default()
+}
+
+/// The input positions of a glyph-based contextual rule
+fn glyph_positions(
+    first: GlyphId16,
+    rest: impl Iterator<Item = GlyphId16>,
+) -> Vec<HashSet<GlyphId16>> {
+    std::iter::once(first)
+        .chain(rest)
+        .map(|gid| HashSet::from([gid]))
+        .collect()
+}
+
+/// The input positions of a class-based contextual rule, if every class has reached glyphs
+fn class_positions(
+    first: HashSet<GlyphId16>,
+    rest: impl Iterator<Item = u16>,
+    classes: &HashMap<u16, HashSet<GlyphId16>>,
+) -> Option<Vec<HashSet<GlyphId16>>> {
+    let mut positions = vec![first];
+    for class in rest {
+        positions.push(classes.get(&class)?.clone());
+    }
+    Some(positions)
+}
+
+/// The (sequence index, lookup index) pairs of a rule's nested lookups
+fn nested_lookups(records: &[SequenceLookupRecord]) -> Vec<(u16, u16)> {
+    records
+        .iter()
+        .map(|record| (record.sequence_index(), record.lookup_list_index()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use crate::{Testable, TEST_FILE};
+
+    #[test]
+    fn test_gsub_closure() {
+        let testable = TEST_FILE!("source-sans-pro/TTF/SourceSansPro-Regular.ttf");
+        let f = TestFont::new_from_data(&testable.filename, &testable.contents).unwrap();
+        let gid = |name: &str| {
+            f.all_glyphs()
+                .find(|gid| f.glyph_name_for_id(*gid).as_deref() == Some(name))
+                .unwrap()
+        };
+        let closure = f.gsub_closure(f.codepoints(None)).unwrap();
+        assert_eq!(
+            closure.reached(gid("uni0457")),
+            Some(&Reached::Cmap(0x0457))
+        );
+        // Only reachable through a lookup nested in a chained contextual lookup
+        assert_eq!(
+            closure.explain(&f, gid("uni0457.a")),
+            "uni0457 (U+0457); uni0457 → uni0457.a (DFLT/dflt/ccmp, lookup 8 → 54)"
+        );
+        assert_eq!(
+            closure.explain(&f, gid("iogonek.x")),
+            "i (U+0069); i + uni0328 → iogonek.x (DFLT/dflt/ccmp, lookup 4 → 53)"
+        );
+
+        let without_ccmp = GsubClosure::new(
+            &f,
+            closure
+                .glyphs()
+                .filter(|gid| matches!(closure.reached(*gid), Some(Reached::Cmap(_))))
+                .map(|gid| (gid, Reached::Start)),
+            |path| path.feature != Tag::new(b"ccmp"),
+        )
+        .unwrap();
+        assert!(without_ccmp.contains(gid("uni0457")));
+        assert!(!without_ccmp.contains(gid("uni0457.a")));
+        assert!(without_ccmp.glyphs().count() < closure.glyphs().count());
+    }
+}
@@ -3,10 +3,11 @@
 // No bad thing if we panic in tests
 use crate::{prelude::*, Check, CheckResult, Context, FileTypeConvert, StatusCode};
 use serde_json::Map;
-use skrifa::raw::{types::NameId, TableProvider};
+use skrifa::raw::{types::NameId, TableProvider, TopLevelTable};
 use write_fonts::{
     tables::name::{Name, NameRecord},
-    FontBuilder,
+    validate::Validate,
+    FontBuilder, FontWrite,
 };
 
 #[macro_export]
@@ -18,7 +19,7 @@ macro_rules! TEST_FILE {
         // the manifest dir is the package root; if you're running `cargo test -p foo`,
         // the manifest dir is the workspace root. So ask Cargo for the workspace root
         // and go from there.
-        let mut output = std::process::Command::new(env!("CARGO"))
+        let output = std::process::Command::new(env!("CARGO"))
             .arg("locate-project")
             .arg("--workspace")
             .arg("--message-format=plain")
@@ -28,15 +29,15 @@ macro_rules! TEST_FILE {
         let cargo_path = std::path::Path::new(std::str::from_utf8(&output).unwrap().trim());
         let mut workspace_root = cargo_path.parent().unwrap().to_path_buf();
 
-        workspace_root.push("resources/test/");
+        workspace_root.push("fontspector-py/data/test/");
         let file = workspace_root.join($fname);
         Testable::new(file.clone()).expect(&format!("Couldn't read test file {:?}", file))
     }};
 }
 
-/// Run a check on a font and return the result
-pub fn run_check(check: Check<'_>, font: Testable) -> Option<CheckResult> {
-    let ctx: Context = Context {
+/// Build a context suitable for running a check in a test
+fn test_context(check: &Check<'_>) -> Context {
+    Context {
         skip_network: false,
         network_timeout: Some(10),
         configuration: Map::new(),
@@ -44,10 +45,24 @@ pub fn run_check(check: Check<'_>, font: Testable) -> Option<CheckResult> {
         full_lists: false,
         cache: Default::default(),
         overrides: vec![],
-    };
+        today: chrono::Utc::now().date_naive(),
+        sweep_location: None,
+    }
+}
+
+/// Run a check on a font and return the result
+pub fn run_check(check: Check<'_>, font: Testable) -> Option<CheckResult> {
+    let ctx = test_context(&check);
     check.run(&TestableType::Single(&font), &ctx, None)
 }
 
+/// Run a check on a collection of fonts and return the result
+pub fn run_check_on_collection(check: Check<'_>, fonts: Vec<Testable>) -> Option<CheckResult> {
+    let ctx = test_context(&check);
+    let collection = TestableCollection::from_testables(fonts, None);
+    check.run(&TestableType::Collection(&collection), &ctx, None)
+}
+
 /// Assert that a check passes
 ///
 /// Takes a `CheckResult` and asserts that the worst status is `Pass`
@@ -108,12 +123,24 @@ pub fn set_name_entry(
         })
         .collect();
     new_records.push(new_record);
+    new_records.sort_by_key(|r| (r.platform_id, r.encoding_id, r.language_id, r.name_id));
     let new_nametable = Name::new(new_records);
+    replace_table(font, &new_nametable);
+}
+
+/// Manipulate a font by replacing one of its tables (for testing purposes only)
+///
+/// The table is usually read from the font with `FromTableRef` and then
+/// modified, e.g. to set a field in the `OS/2` table to a bad value.
+pub fn replace_table<T>(font: &mut Testable, table: &T)
+where
+    T: FontWrite + Validate + TopLevelTable,
+{
+    let f = TTF.from_testable(font).unwrap();
     let new_bytes = FontBuilder::new()
-        .add_table(&new_nametable)
+        .add_table(table)
         .unwrap()
         .copy_missing_tables(f.font())
         .build();
-
     font.contents = new_bytes;
 }
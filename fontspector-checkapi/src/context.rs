@@ -3,7 +3,9 @@ use std::{
     sync::{Arc, RwLock},
 };
 
+use chrono::NaiveDate;
 use serde_json::{Map, Value};
+use skrifa::setting::VariationSetting;
 
 use crate::{Check, Override, Profile};
 
@@ -33,6 +35,16 @@ pub struct Context {
     pub cache: Arc<RwLock<Map<String, Value>>>,
     /// Any overrides for this check, from the profile or the user's configuration file.
     pub overrides: Vec<Override>,
+    /// Today's date (UTC), against which override expiry dates are compared
+    ///
+    /// This is worked out once by the caller so that every check in a run
+    /// agrees on whether an override has expired.
+    pub today: NaiveDate,
+    /// The location in a variable font at which to run the check
+    ///
+    /// This is only set when sweeping location-sensitive checks across a
+    /// variable font's instances; otherwise checks look at the default location.
+    pub sweep_location: Option<Vec<VariationSetting>>,
 }
 
 impl Context {
@@ -46,6 +58,8 @@ impl Context {
             full_lists: self.full_lists,
             cache: Arc::new(RwLock::new(Map::new())),
             overrides: self.overrides.clone(),
+            today: self.today,
+            sweep_location: self.sweep_location.clone(),
         }
     }
 
@@ -96,9 +110,35 @@ impl Context {
             full_lists: self.full_lists,
             cache: self.cache.clone(),
             overrides: our_overrides,
+            today: self.today,
+            sweep_location: self.sweep_location.clone(),
         }
     }
 
+    /// The location at which a location-sensitive check should look at the font
+    ///
+    /// This is empty (the default location) unless instances are being swept.
+    pub fn location(&self) -> &[VariationSetting] {
+        self.sweep_location.as_deref().unwrap_or_default()
+    }
+
+    /// A label for the sweep location, such as `wght=900 wdth=75`
+    pub fn location_label(&self) -> Option<String> {
+        self.sweep_location.as_ref().map(|settings| {
+            settings
+                .iter()
+                .map(|setting| {
+                    format!(
+                        "{}={}",
+                        setting.selector,
+                        (setting.value * 100.0).round() / 100.0
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    }
+
     /// Ask a question, using the cache
     pub fn cached_question<T>(
         &self,
@@ -1,11 +1,14 @@
 use crate::{
     constants::{OutlineType, RIBBI_STYLE_NAMES, STATIC_STYLE_NAMES},
     filetype::FileTypeConvert,
-    CheckError, Context, FileType, Testable,
+    pens::BoundsPen,
+    CheckError, Context, FileType, LegacyKern, Testable,
 };
 use itertools::Either;
 use skrifa::{
     font::FontRef,
+    instance::Location,
+    metrics::{BoundingBox, GlyphMetrics},
     outline::{DrawSettings, OutlinePen},
     prelude::Size,
     raw::{
@@ -350,6 +353,230 @@ impl TestFont<'_> {
         Ok(())
     }
 
+    /// Returns the name and user-space location of each of the font's instances
+    ///
+    /// For a variable font, these are its named instances. A static font (or a
+    /// variable font without named instances) has only its default location,
+    /// named after its style. Checks on metrics should look at all of these
+    /// locations so that they are correct for variable fonts.
+    pub fn instance_locations(&self) -> Vec<(String, Vec<VariationSetting>)> {
+        let instances: Vec<(String, Vec<VariationSetting>)> = self
+            .named_instances()
+            .map(|(name, coords)| {
+                let settings = coords
+                    .iter()
+                    .map(|(tag, value)| (tag.as_str(), *value).into())
+                    .collect();
+                (name, settings)
+            })
+            .collect();
+        if instances.is_empty() {
+            vec![(self.style().unwrap_or("Default").to_string(), vec![])]
+        } else {
+            instances
+        }
+    }
+
+    /// Returns the locations at which location-sensitive checks should be swept
+    ///
+    /// For a variable font, these are its default location, each named
+    /// instance, each master (see [TestFont::master_locations]) and the
+    /// minimum and maximum of each axis, in user-space coordinates and with
+    /// duplicates removed. A static font has no locations to sweep.
+    pub fn sweep_locations(&self) -> Vec<Vec<VariationSetting>> {
+        if !self.is_variable_font() {
+            return vec![];
+        }
+        let axes = self.font().axes();
+        let defaults: Vec<f32> = axes.iter().map(|axis| axis.default_value()).collect();
+        let mut candidates = vec![defaults.clone()];
+        candidates.extend(
+            self.font()
+                .named_instances()
+                .iter()
+                .map(|ni| ni.user_coords().collect()),
+        );
+        candidates.extend(
+            self.master_locations()
+                .iter()
+                .map(|peak| self.normalized_to_user(peak)),
+        );
+        for (i, axis) in axes.iter().enumerate() {
+            for extreme in [axis.min_value(), axis.max_value()] {
+                let mut coords = defaults.clone();
+                coords[i] = extreme;
+                candidates.push(coords);
+            }
+        }
+        let mut seen = HashSet::new();
+        candidates
+            .into_iter()
+            .map(|coords| {
+                axes.iter()
+                    .zip(coords)
+                    .map(|(axis, value)| VariationSetting::new(axis.tag(), value))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|settings| seen.insert(axes.location(settings).coords().to_vec()))
+            .collect()
+    }
+
+    /// Draw a glyph at a normalized location
+    fn draw_glyph_at(
+        &self,
+        gid: GlyphId,
+        pen: &mut impl OutlinePen,
+        location: &Location,
+    ) -> Result<(), CheckError> {
+        let glyph = self
+            .font()
+            .outline_glyphs()
+            .get(gid)
+            .ok_or_else(|| CheckError::Error(format!("No glyph with ID {}", gid)))?;
+        glyph
+            .draw(DrawSettings::unhinted(Size::unscaled(), location), pen)
+            .map_err(|_| CheckError::Error("Failed to draw glyph".to_string()))?;
+        Ok(())
+    }
+
+    /// The bounding box of a glyph's outline at a normalized location
+    fn glyph_bounds_at(
+        &self,
+        gid: GlyphId,
+        location: &Location,
+    ) -> Result<Option<BoundingBox>, CheckError> {
+        let mut pen = BoundsPen::new();
+        self.draw_glyph_at(gid, &mut pen, location)?;
+        Ok(pen.bounds())
+    }
+
+    /// The advance width of a glyph at the given location
+    ///
+    /// Variations are applied using the `HVAR` table, or using the `gvar`
+    /// table's phantom points if there is no `HVAR` table. Returns `None` if
+    /// the glyph is not in the font.
+    pub fn advance_width<I>(&self, gid: GlyphId, settings: I) -> Option<f32>
+    where
+        I: IntoIterator,
+        I::Item: Into<VariationSetting>,
+    {
+        let location = self.font().axes().location(settings);
+        GlyphMetrics::new(&self.font(), Size::unscaled(), &location).advance_width(gid)
+    }
+
+    /// The advance height of a glyph at the given location
+    ///
+    /// Variations are applied using the `VVAR` table, or using the `gvar`
+    /// table's phantom points if there is no `VVAR` table. Returns `None` if
+    /// the font has no vertical metrics or the glyph is not in the font.
+    pub fn advance_height<I>(&self, gid: GlyphId, settings: I) -> Option<f32>
+    where
+        I: IntoIterator,
+        I::Item: Into<VariationSetting>,
+    {
+        let font = self.font();
+        let location = font.axes().location(settings);
+        let coords = location.coords();
+        let advance = font.vmtx().ok()?.advance(gid)? as f32;
+        if coords.iter().all(|coord| *coord == Default::default()) {
+            return Some(advance);
+        }
+        let delta = if let Ok(vvar) = font.vvar() {
+            vvar.advance_height_delta(gid, coords).ok()?.to_f32()
+        } else if let (Ok(gvar), Ok(glyf), Ok(loca)) = (font.gvar(), font.glyf(), font.loca(None)) {
+            gvar.phantom_point_deltas(&glyf, &loca, coords, gid)
+                .ok()
+                .flatten()
+                .map(|[_left, _right, top, bottom]| (top.y - bottom.y).to_f32())
+                .unwrap_or(0.0)
+        } else {
+            0.0
+        };
+        Some(advance + delta)
+    }
+
+    /// The left side bearing of a glyph at the given location
+    ///
+    /// This is measured from the glyph's origin to the left edge of its
+    /// outline at that location. Returns `None` if the glyph has no outline.
+    pub fn left_side_bearing<I>(&self, gid: GlyphId, settings: I) -> Result<Option<f32>, CheckError>
+    where
+        I: IntoIterator,
+        I::Item: Into<VariationSetting>,
+    {
+        let location = self.font().axes().location(settings);
+        Ok(self
+            .glyph_bounds_at(gid, &location)?
+            .map(|bounds| bounds.x_min))
+    }
+
+    /// The right side bearing of a glyph at the given location
+    ///
+    /// This is measured from the right edge of the glyph's outline to its
+    /// advance width at that location. Returns `None` if the glyph has no
+    /// outline.
+    pub fn right_side_bearing<I>(
+        &self,
+        gid: GlyphId,
+        settings: I,
+    ) -> Result<Option<f32>, CheckError>
+    where
+        I: IntoIterator,
+        I::Item: Into<VariationSetting>,
+    {
+        let location = self.font().axes().location(settings);
+        let Some(advance) =
+            GlyphMetrics::new(&self.font(), Size::unscaled(), &location).advance_width(gid)
+        else {
+            return Ok(None);
+        };
+        Ok(self
+            .glyph_bounds_at(gid, &location)?
+            .map(|bounds| advance - bounds.x_max))
+    }
+
+    /// The bounding box of a glyph's outline at the given location
+    ///
+    /// Returns `None` if the glyph has no outline.
+    pub fn glyph_bounds<I>(
+        &self,
+        gid: GlyphId,
+        settings: I,
+    ) -> Result<Option<BoundingBox>, CheckError>
+    where
+        I: IntoIterator,
+        I::Item: Into<VariationSetting>,
+    {
+        let location = self.font().axes().location(settings);
+        self.glyph_bounds_at(gid, &location)
+    }
+
+    /// The bounding box of all the glyphs in the font at the given location
+    ///
+    /// Returns `None` if no glyph has an outline.
+    pub fn font_bounds<I>(&self, settings: I) -> Result<Option<BoundingBox>, CheckError>
+    where
+        I: IntoIterator,
+        I::Item: Into<VariationSetting>,
+    {
+        let location = self.font().axes().location(settings);
+        let mut font_bounds: Option<BoundingBox> = None;
+        for gid in self.all_glyphs() {
+            if let Some(bounds) = self.glyph_bounds_at(gid, &location)? {
+                font_bounds = Some(match font_bounds {
+                    Some(b) => BoundingBox {
+                        x_min: b.x_min.min(bounds.x_min),
+                        y_min: b.y_min.min(bounds.y_min),
+                        x_max: b.x_max.max(bounds.x_max),
+                        y_max: b.y_max.max(bounds.y_max),
+                    },
+                    None => bounds,
+                });
+            }
+        }
+        Ok(font_bounds)
+    }
+
     /// Returns the font's FeatureRecord and associated Feature tables
     ///
     /// If `gsub_only` is true, only searches in the `GSUB` table.
@@ -448,6 +675,15 @@ impl TestFont<'_> {
         )
     }
 
+    /// Read the font's legacy `kern` table
+    ///
+    /// Returns `None` if the font has no `kern` table.
+    pub fn legacy_kern(&self) -> Option<Result<LegacyKern, ReadError>> {
+        self.font()
+            .table_data(Tag::new(b"kern"))
+            .map(LegacyKern::read)
+    }
+
     /// Get the best name from a list of name IDs
     pub fn get_best_name(&self, ids: &[StringId]) -> Option<String> {
         for id in ids {
@@ -550,3 +786,70 @@ impl VerticalMetrics {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use crate::TEST_FILE;
+
+    #[test]
+    fn test_variable_metrics() {
+        let testable = TEST_FILE!("mutatorsans-vf/MutatorSans-VF.ttf");
+        let f = TestFont::new_from_data(&testable.filename, &testable.contents).unwrap();
+        let a = f.font().charmap().map('A').unwrap();
+        assert_eq!(f.advance_width(a, DEFAULT_LOCATION), Some(396.0));
+        assert_eq!(f.advance_height(a, DEFAULT_LOCATION), None);
+
+        let bold_condensed = vec![("wdth", 0.0), ("wght", 1000.0)];
+        assert_eq!(f.advance_width(a, bold_condensed.clone()), Some(740.0));
+        assert_eq!(
+            f.left_side_bearing(a, bold_condensed.clone()).unwrap(),
+            Some(-10.0)
+        );
+        assert_eq!(f.right_side_bearing(a, bold_condensed).unwrap(), Some(10.0));
+
+        let bounds = f.font_bounds(DEFAULT_LOCATION).unwrap().unwrap();
+        let head = f.font().head().unwrap();
+        assert_eq!(bounds.x_min, head.x_min() as f32);
+        assert_eq!(bounds.y_min, head.y_min() as f32);
+        assert_eq!(bounds.x_max, head.x_max() as f32);
+        assert_eq!(bounds.y_max, head.y_max() as f32);
+
+        let locations = f.instance_locations();
+        assert!(locations.len() > 1);
+        assert!(locations.iter().any(|(name, _)| name == "BoldWide"));
+    }
+
+    #[test]
+    fn test_sweep_locations() {
+        let testable = TEST_FILE!("cabinvf/Cabin[wdth,wght].ttf");
+        let f = TestFont::new_from_data(&testable.filename, &testable.contents).unwrap();
+        let labels: Vec<String> = f
+            .sweep_locations()
+            .into_iter()
+            .flat_map(|location| {
+                Context {
+                    sweep_location: Some(location),
+                    ..Default::default()
+                }
+                .location_label()
+            })
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                "wght=400 wdth=100",
+                "wght=500 wdth=100",
+                "wght=600 wdth=100",
+                "wght=700 wdth=100",
+                "wght=400 wdth=75",
+                "wght=700 wdth=75",
+            ]
+        );
+
+        let testable = TEST_FILE!("cabin/Cabin-Regular.ttf");
+        let f = TestFont::new_from_data(&testable.filename, &testable.contents).unwrap();
+        assert!(f.sweep_locations().is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::BTreeSet;
+
+use glob_match::glob_match;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
+
+use crate::CheckError;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+/// A set of characters and glyphs which fonts are expected to contain
+///
+/// Glyphsets are declared in the `glyphsets` list of the `glyphset_coverage`
+/// check's configuration, either in a profile's configuration defaults or in
+/// the user's configuration file:
+///
+/// ```toml
+/// [configuration_defaults.glyphset_coverage]
+/// glyphsets = [
+///     { name = "Latin Pro", blocks = ["Basic Latin", "Latin-1 Supplement"], codepoints = ["U+0152-U+0153", "€"] },
+///     { name = "Italic alternates", glyphs = ["a.ss01"], files = "*-Italic.ttf" },
+/// ]
+/// ```
+pub struct Glyphset {
+    /// The name of the glyphset, e.g. "Latin Pro"
+    pub name: String,
+    /// Codepoints in the glyphset
+    ///
+    /// Each entry is a codepoint (`U+0041` or `0x41`), a character (`A`), or an
+    /// inclusive range of codepoints (`U+0400-U+04FF` or `U+0400..U+04FF`).
+    #[serde(default)]
+    pub codepoints: Vec<String>,
+    /// Names of Unicode blocks in the glyphset, e.g. "Cyrillic Supplement"
+    #[serde(default)]
+    pub blocks: Vec<String>,
+    /// Names of glyphs in the glyphset
+    #[serde(default)]
+    pub glyphs: Vec<String>,
+    /// Only expect fonts whose filenames match this glob to contain the glyphset
+    ///
+    /// This allows a glyphset to be declared for a single family (`Foo-*.ttf`)
+    /// or style (`*-Italic.ttf`).
+    #[serde(default)]
+    pub files: Option<String>,
+}
+
+/// Whether a character is one a font would be expected to have a glyph for
+///
+/// Ranges and blocks include unassigned codepoints and control characters,
+/// which fonts don't need to map.
+pub fn is_graphic(c: char) -> bool {
+    !matches!(
+        c.general_category(),
+        GeneralCategory::Unassigned | GeneralCategory::Control
+    )
+}
+
+/// The codepoints from `start` to `end` inclusive which a font would be expected to have
+fn graphic_range(start: u32, end: u32) -> impl Iterator<Item = u32> {
+    (start..=end)
+        .flat_map(char::from_u32)
+        .filter(|c| is_graphic(*c))
+        .map(|c| c as u32)
+}
+
+/// Parse a codepoint written as `U+0041`, `0x41` or `A`
+fn parse_codepoint(codepoint: &str) -> Option<u32> {
+    let codepoint = codepoint.trim();
+    if let Some(hex) = codepoint
+        .strip_prefix("U+")
+        .or_else(|| codepoint.strip_prefix("u+"))
+        .or_else(|| codepoint.strip_prefix("0x"))
+    {
+        return u32::from_str_radix(hex, 16).ok();
+    }
+    let mut chars = codepoint.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c as u32),
+        _ => None,
+    }
+}
+
+/// Find the first and last codepoints of a Unicode block, given its name
+fn find_block(name: &str) -> Option<(u32, u32)> {
+    let mut codepoint = 0;
+    while codepoint <= char::MAX as u32 {
+        let block = char::from_u32(codepoint).and_then(yeslogic_unicode_blocks::find_unicode_block);
+        match block {
+            Some(block) if block.name().eq_ignore_ascii_case(name) => {
+                return Some((block.start(), block.end()))
+            }
+            Some(block) => codepoint = block.end() + 1,
+            None => codepoint += 1,
+        }
+    }
+    None
+}
+
+impl Glyphset {
+    /// Read the glyphsets declared in a check's configuration
+    pub fn from_configuration(configuration: &Map<String, Value>) -> Result<Vec<Self>, CheckError> {
+        configuration
+            .get("glyphsets")
+            .map(|glyphsets| {
+                serde_json::from_value(glyphsets.clone()).map_err(|e| {
+                    CheckError::Error(format!("Could not read glyphset definitions: {}", e))
+                })
+            })
+            .unwrap_or(Ok(vec![]))
+    }
+
+    /// Whether a font with the given filename is expected to contain this glyphset
+    pub fn applies_to(&self, basename: &str) -> bool {
+        self.files
+            .as_ref()
+            .is_none_or(|pattern| glob_match(pattern, basename))
+    }
+
+    /// All the codepoints in the glyphset
+    ///
+    /// Unassigned codepoints and control characters in ranges and blocks are left out.
+    pub fn codepoints(&self) -> Result<BTreeSet<u32>, CheckError> {
+        let bad_definition = |what: &str, entry: &str| {
+            CheckError::Error(format!(
+                "Glyphset {} has an unknown {} \"{}\"",
+                self.name, what, entry
+            ))
+        };
+        let mut codepoints = BTreeSet::new();
+        for entry in self.blocks.iter() {
+            let (start, end) = find_block(entry).ok_or_else(|| bad_definition("block", entry))?;
+            codepoints.extend(graphic_range(start, end));
+        }
+        for entry in self.codepoints.iter() {
+            if let Some(codepoint) = parse_codepoint(entry) {
+                codepoints.insert(codepoint);
+                continue;
+            }
+            let (start, end) = entry
+                .split_once("..")
+                .or_else(|| entry.split_once('-'))
+                .and_then(|(start, end)| Some((parse_codepoint(start)?, parse_codepoint(end)?)))
+                .ok_or_else(|| bad_definition("codepoint", entry))?;
+            codepoints.extend(graphic_range(start, end));
+        }
+        Ok(codepoints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_glyphset_codepoints() {
+        let glyphset = Glyphset {
+            name: "Test".to_string(),
+            codepoints: vec![
+                "U+0152-U+0153".to_string(),
+                "€".to_string(),
+                "0x2030".to_string(),
+                "-".to_string(),
+            ],
+            blocks: vec!["Basic Latin".to_string()],
+            ..Default::default()
+        };
+        let codepoints = glyphset.codepoints().unwrap();
+        assert!(codepoints.contains(&0x41));
+        assert!(codepoints.contains(&0x7E));
+        // Control characters aren't expected
+        assert!(!codepoints.contains(&0x0D));
+        assert!(!codepoints.contains(&0x7F));
+        assert!(codepoints.contains(&0x152));
+        assert!(codepoints.contains(&0x153));
+        assert!(codepoints.contains(&0x20AC));
+        assert!(codepoints.contains(&0x2030));
+        // Basic Latin, which includes the hyphen, and four more
+        assert_eq!(codepoints.len(), 95 + 4);
+
+        let glyphset = Glyphset {
+            name: "Bad".to_string(),
+            blocks: vec!["Basic Klingon".to_string()],
+            ..Default::default()
+        };
+        assert!(glyphset.codepoints().is_err());
+    }
+
+    #[test]
+    fn test_glyphset_applies_to() {
+        let glyphset = Glyphset {
+            name: "Italic alternates".to_string(),
+            files: Some("*-Italic.ttf".to_string()),
+            ..Default::default()
+        };
+        assert!(glyphset.applies_to("Foo-Italic.ttf"));
+        assert!(!glyphset.applies_to("Foo-Regular.ttf"));
+        assert!(Glyphset::default().applies_to("Foo-Regular.ttf"));
+    }
+}
@@ -0,0 +1,624 @@
+// Code to make GPOS tables easier to work with
+use std::collections::{BTreeMap, HashMap};
+
+use skrifa::{
+    raw::{
+        tables::{
+            gpos::{
+                AnchorTable, CursivePosFormat1, DeviceOrVariationIndex, MarkArray,
+                MarkBasePosFormat1, MarkLigPosFormat1, MarkMarkPosFormat1, PairPos,
+                PositionSubtables, ValueRecord,
+            },
+            layout::ClassDef,
+            variations::{FloatItemDelta, FloatItemDeltaTarget, ItemVariationStore},
+        },
+        types::{F2Dot14, FWord},
+        FontData, ReadError, TableProvider,
+    },
+    setting::VariationSetting,
+    GlyphId16, MetadataProvider,
+};
+
+use crate::TestFont;
+
+/// A point at which glyphs are attached to one another, resolved at a location
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anchor {
+    /// The horizontal position of the anchor
+    pub x: f32,
+    /// The vertical position of the anchor
+    pub y: f32,
+}
+
+/// The kind of glyph a mark class attaches to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttachmentType {
+    /// Marks attach to bases (GPOS lookup type 4)
+    Base,
+    /// Marks attach to the components of ligatures (GPOS lookup type 5)
+    Ligature,
+    /// Marks attach to other marks (GPOS lookup type 6)
+    Mark,
+}
+
+/// A class of marks in a mark attachment subtable, together with the glyphs
+/// they attach to
+#[derive(Debug, Clone)]
+pub struct MarkClass {
+    /// The index of the lookup containing the subtable
+    pub lookup: u16,
+    /// The index of the subtable within the lookup
+    pub subtable: usize,
+    /// The class number within the subtable
+    pub class: u16,
+    /// What kind of glyph the marks attach to
+    pub attachment: AttachmentType,
+    /// The marks in the class, and their anchors
+    pub marks: BTreeMap<GlyphId16, Anchor>,
+    /// The glyphs the marks attach to, and their anchors for this class
+    ///
+    /// Ligatures have an entry for each of their components; bases and marks
+    /// have a single entry. An entry is `None` if the glyph has no anchor
+    /// for this class.
+    pub attachments: BTreeMap<GlyphId16, Vec<Option<Anchor>>>,
+}
+
+impl MarkClass {
+    /// Does any glyph have an anchor for this class?
+    pub fn is_attached(&self) -> bool {
+        self.attachments
+            .values()
+            .any(|anchors| anchors.iter().any(Option::is_some))
+    }
+
+    /// The anchor a glyph has for this class (for ligatures, the first component's anchor)
+    pub fn anchor_for(&self, gid: GlyphId16) -> Option<Anchor> {
+        self.attachments
+            .get(&gid)
+            .and_then(|anchors| anchors.iter().flatten().next().copied())
+    }
+}
+
+/// The adjustments a value record makes to a glyph, resolved at a location
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ValueAdjustment {
+    /// Horizontal adjustment to the glyph's position
+    pub x_placement: f32,
+    /// Vertical adjustment to the glyph's position
+    pub y_placement: f32,
+    /// Adjustment to the glyph's horizontal advance
+    pub x_advance: f32,
+    /// Adjustment to the glyph's vertical advance
+    pub y_advance: f32,
+}
+
+impl ValueAdjustment {
+    /// Does the adjustment do nothing?
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// A pair positioning (kerning) adjustment between two glyphs
+///
+/// Class-based pairs are expanded to every pair of glyphs in the classes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PairAdjustment {
+    /// The index of the lookup containing the pair
+    pub lookup: u16,
+    /// The first glyph of the pair
+    pub left: GlyphId16,
+    /// The second glyph of the pair
+    pub right: GlyphId16,
+    /// The adjustment made to the first glyph
+    pub first: ValueAdjustment,
+    /// The adjustment made to the second glyph
+    pub second: ValueAdjustment,
+}
+
+impl PairAdjustment {
+    /// The change in horizontal distance between the two glyphs
+    pub fn kern(&self) -> f32 {
+        self.first.x_advance + self.second.x_placement
+    }
+}
+
+/// The cursive attachment points of a glyph
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursiveAnchors {
+    /// The index of the lookup containing the anchors
+    pub lookup: u16,
+    /// The glyph
+    pub glyph: GlyphId16,
+    /// Where the previous glyph's exit anchor attaches
+    pub entry: Option<Anchor>,
+    /// Where the next glyph's entry anchor attaches
+    pub exit: Option<Anchor>,
+}
+
+/// The positioning rules of a font's `GPOS` table, resolved at a location
+///
+/// Contextual positioning lookups are not included; the lookups they refer
+/// to are included in their own right, as are lookups not referenced by any
+/// feature.
+#[derive(Debug, Clone, Default)]
+pub struct GposModel {
+    /// Mark classes from mark-to-base, mark-to-ligature and mark-to-mark subtables
+    pub mark_classes: Vec<MarkClass>,
+    /// Kerning pairs, in lookup order
+    pub kerning: Vec<PairAdjustment>,
+    /// Cursive attachment points, in lookup order
+    pub cursive: Vec<CursiveAnchors>,
+}
+
+impl GposModel {
+    /// The anchors a glyph has for each mark class
+    pub fn anchors(
+        &self,
+        gid: GlyphId16,
+    ) -> impl Iterator<Item = (&MarkClass, &[Option<Anchor>])> + '_ {
+        self.mark_classes.iter().filter_map(move |class| {
+            class
+                .attachments
+                .get(&gid)
+                .map(|anchors| (class, anchors.as_slice()))
+        })
+    }
+
+    /// The mark classes a mark glyph belongs to
+    pub fn classes_of_mark(&self, gid: GlyphId16) -> impl Iterator<Item = &MarkClass> + '_ {
+        self.mark_classes
+            .iter()
+            .filter(move |class| class.marks.contains_key(&gid))
+    }
+
+    /// Can a mark be attached to a glyph?
+    ///
+    /// True if any mark class containing the mark has an anchor on the glyph.
+    pub fn can_attach(&self, mark: GlyphId16, gid: GlyphId16) -> bool {
+        self.classes_of_mark(mark)
+            .any(|class| class.anchor_for(gid).is_some())
+    }
+
+    /// The total kerning between two glyphs
+    ///
+    /// As with a shaper, only the first matching pair in each lookup counts.
+    pub fn kern(&self, left: GlyphId16, right: GlyphId16) -> Option<f32> {
+        let mut seen_lookups = vec![];
+        let mut total = None;
+        for pair in self.kerning.iter() {
+            if pair.left == left && pair.right == right && !seen_lookups.contains(&pair.lookup) {
+                seen_lookups.push(pair.lookup);
+                total = Some(total.unwrap_or(0.0) + pair.kern());
+            }
+        }
+        total
+    }
+}
+
+impl TestFont<'_> {
+    /// Read the font's `GPOS` table into a [GposModel]
+    ///
+    /// Anchors and adjustments are resolved at the given location, using the
+    /// variation store in the `GDEF` table; pass an empty location for the
+    /// default instance. Device tables (as opposed to variation indices) are
+    /// ignored, as they depend on the size the font is set at.
+    pub fn gpos_model(&self, location: &[VariationSetting]) -> Result<GposModel, ReadError> {
+        let Ok(gpos) = self.font().gpos() else {
+            return Ok(GposModel::default());
+        };
+        let axes = self.font().axes();
+        let location = axes.location(location);
+        let store = self
+            .font()
+            .gdef()
+            .ok()
+            .and_then(|gdef| gdef.item_var_store())
+            .transpose()?;
+        let builder = GposBuilder {
+            coords: location.coords(),
+            store,
+            glyph_count: self.glyph_count as u16,
+        };
+        let mut model = GposModel::default();
+        for (lookup_index, lookup) in gpos.lookup_list()?.lookups().iter().enumerate() {
+            let lookup_index = lookup_index as u16;
+            // As in the gpos7 check, a lookup may legitimately fail to
+            // produce its subtables, so skip it rather than bailing out.
+            let Ok(subtables) = lookup?.subtables() else {
+                continue;
+            };
+            match subtables {
+                PositionSubtables::Pair(subtables) => {
+                    for subtable in subtables.iter() {
+                        builder.pairs(lookup_index, &subtable?, &mut model.kerning)?;
+                    }
+                }
+                PositionSubtables::Cursive(subtables) => {
+                    for subtable in subtables.iter() {
+                        builder.cursive(lookup_index, &subtable?, &mut model.cursive)?;
+                    }
+                }
+                PositionSubtables::MarkToBase(subtables) => {
+                    for (index, subtable) in subtables.iter().enumerate() {
+                        model.mark_classes.extend(builder.mark_to_base(
+                            lookup_index,
+                            index,
+                            &subtable?,
+                        )?);
+                    }
+                }
+                PositionSubtables::MarkToLig(subtables) => {
+                    for (index, subtable) in subtables.iter().enumerate() {
+                        model.mark_classes.extend(builder.mark_to_ligature(
+                            lookup_index,
+                            index,
+                            &subtable?,
+                        )?);
+                    }
+                }
+                PositionSubtables::MarkToMark(subtables) => {
+                    for (index, subtable) in subtables.iter().enumerate() {
+                        model.mark_classes.extend(builder.mark_to_mark(
+                            lookup_index,
+                            index,
+                            &subtable?,
+                        )?);
+                    }
+                }
+                PositionSubtables::Single(_)
+                | PositionSubtables::Contextual(_)
+                | PositionSubtables::ChainContextual(_) => {}
+            }
+        }
+        Ok(model)
+    }
+}
+
+/// Resolves GPOS data at a location
+struct GposBuilder<'a> {
+    /// The normalized coordinates of the location
+    coords: &'a [F2Dot14],
+    /// The `GDEF` variation store, if there is one
+    store: Option<ItemVariationStore<'a>>,
+    /// The number of glyphs in the font, for expanding class 0
+    glyph_count: u16,
+}
+
+impl GposBuilder<'_> {
+    /// Apply the delta from a variation index table, if there is one, to a value
+    fn delta(
+        &self,
+        value: i16,
+        device: Option<Result<DeviceOrVariationIndex, ReadError>>,
+    ) -> Result<f32, ReadError> {
+        let value = FWord::new(value);
+        match (device.transpose()?, self.store.as_ref()) {
+            (Some(DeviceOrVariationIndex::VariationIndex(index)), Some(store)) => {
+                Ok(value.apply_float_delta(store.compute_float_delta(index.into(), self.coords)?))
+            }
+            _ => Ok(value.apply_float_delta(FloatItemDelta::ZERO)),
+        }
+    }
+
+    /// Resolve an anchor table
+    fn anchor(&self, anchor: AnchorTable) -> Result<Anchor, ReadError> {
+        Ok(Anchor {
+            x: self.delta(anchor.x_coordinate(), anchor.x_device())?,
+            y: self.delta(anchor.y_coordinate(), anchor.y_device())?,
+        })
+    }
+
+    /// Resolve an anchor table which may be missing
+    fn optional_anchor(
+        &self,
+        anchor: Option<Result<AnchorTable, ReadError>>,
+    ) -> Result<Option<Anchor>, ReadError> {
+        anchor
+            .transpose()?
+            .map(|anchor| self.anchor(anchor))
+            .transpose()
+    }
+
+    /// Resolve a value record, whose device offsets are relative to `data`
+    fn value(&self, record: &ValueRecord, data: FontData) -> Result<ValueAdjustment, ReadError> {
+        Ok(ValueAdjustment {
+            x_placement: self.delta(
+                record.x_placement().unwrap_or_default(),
+                record.x_placement_device(data),
+            )?,
+            y_placement: self.delta(
+                record.y_placement().unwrap_or_default(),
+                record.y_placement_device(data),
+            )?,
+            x_advance: self.delta(
+                record.x_advance().unwrap_or_default(),
+                record.x_advance_device(data),
+            )?,
+            y_advance: self.delta(
+                record.y_advance().unwrap_or_default(),
+                record.y_advance_device(data),
+            )?,
+        })
+    }
+
+    /// The glyphs in each class of a class definition
+    ///
+    /// Class 0 contains every glyph not in any other class.
+    fn classes(&self, classdef: &ClassDef) -> HashMap<u16, Vec<GlyphId16>> {
+        let mut classes: HashMap<u16, Vec<GlyphId16>> = HashMap::new();
+        for gid in (0..self.glyph_count).map(GlyphId16::new) {
+            classes.entry(classdef.get(gid)).or_default().push(gid);
+        }
+        classes
+    }
+
+    /// Expand a pair positioning subtable into pairs of glyphs
+    fn pairs(
+        &self,
+        lookup: u16,
+        subtable: &PairPos,
+        pairs: &mut Vec<PairAdjustment>,
+    ) -> Result<(), ReadError> {
+        let mut push = |left, right, first: ValueAdjustment, second: ValueAdjustment| {
+            if !first.is_zero() || !second.is_zero() {
+                pairs.push(PairAdjustment {
+                    lookup,
+                    left,
+                    right,
+                    first,
+                    second,
+                });
+            }
+        };
+        match subtable {
+            PairPos::Format1(table) => {
+                for (left, pair_set) in table.coverage()?.iter().zip(table.pair_sets().iter()) {
+                    let pair_set = pair_set?;
+                    // Device offsets in a pair set are relative to the pair set
+                    let data = pair_set.offset_data();
+                    for record in pair_set.pair_value_records().iter() {
+                        let record = record?;
+                        push(
+                            left,
+                            record.second_glyph(),
+                            self.value(record.value_record1(), data)?,
+                            self.value(record.value_record2(), data)?,
+                        );
+                    }
+                }
+            }
+            PairPos::Format2(table) => {
+                let data = table.offset_data();
+                let class_def1 = table.class_def1()?;
+                let class2_glyphs = self.classes(&table.class_def2()?);
+                let class1_records = table.class1_records();
+                for left in table.coverage()?.iter() {
+                    let class1_record = class1_records.get(class_def1.get(left) as usize)?;
+                    for (class2, class2_record) in class1_record.class2_records().iter().enumerate()
+                    {
+                        let class2_record = class2_record?;
+                        let first = self.value(class2_record.value_record1(), data)?;
+                        let second = self.value(class2_record.value_record2(), data)?;
+                        for right in class2_glyphs
+                            .get(&(class2 as u16))
+                            .map(|glyphs| glyphs.as_slice())
+                            .unwrap_or_default()
+                        {
+                            push(left, *right, first, second);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Read the entry and exit anchors of a cursive attachment subtable
+    fn cursive(
+        &self,
+        lookup: u16,
+        subtable: &CursivePosFormat1,
+        cursive: &mut Vec<CursiveAnchors>,
+    ) -> Result<(), ReadError> {
+        let data = subtable.offset_data();
+        for (glyph, record) in subtable
+            .coverage()?
+            .iter()
+            .zip(subtable.entry_exit_record().iter())
+        {
+            cursive.push(CursiveAnchors {
+                lookup,
+                glyph,
+                entry: self.optional_anchor(record.entry_anchor(data))?,
+                exit: self.optional_anchor(record.exit_anchor(data))?,
+            });
+        }
+        Ok(())
+    }
+
+    /// Build empty mark classes from a mark array
+    fn mark_classes(
+        &self,
+        lookup: u16,
+        subtable: usize,
+        attachment: AttachmentType,
+        class_count: u16,
+        coverage: impl Iterator<Item = GlyphId16>,
+        mark_array: MarkArray,
+    ) -> Result<Vec<MarkClass>, ReadError> {
+        let mut classes = (0..class_count)
+            .map(|class| MarkClass {
+                lookup,
+                subtable,
+                class,
+                attachment,
+                marks: BTreeMap::new(),
+                attachments: BTreeMap::new(),
+            })
+            .collect::<Vec<_>>();
+        let data = mark_array.offset_data();
+        for (mark, record) in coverage.zip(mark_array.mark_records().iter()) {
+            if let Some(class) = classes.get_mut(record.mark_class() as usize) {
+                class
+                    .marks
+                    .insert(mark, self.anchor(record.mark_anchor(data)?)?);
+            }
+        }
+        Ok(classes)
+    }
+
+    /// Read the mark classes of a mark-to-base subtable
+    fn mark_to_base(
+        &self,
+        lookup: u16,
+        index: usize,
+        subtable: &MarkBasePosFormat1,
+    ) -> Result<Vec<MarkClass>, ReadError> {
+        let mut classes = self.mark_classes(
+            lookup,
+            index,
+            AttachmentType::Base,
+            subtable.mark_class_count(),
+            subtable.mark_coverage()?.iter(),
+            subtable.mark_array()?,
+        )?;
+        let base_array = subtable.base_array()?;
+        let data = base_array.offset_data();
+        for (base, record) in subtable
+            .base_coverage()?
+            .iter()
+            .zip(base_array.base_records().iter())
+        {
+            for (class, anchor) in classes.iter_mut().zip(record?.base_anchors(data).iter()) {
+                class
+                    .attachments
+                    .insert(base, vec![self.optional_anchor(anchor)?]);
+            }
+        }
+        Ok(classes)
+    }
+
+    /// Read the mark classes of a mark-to-ligature subtable
+    fn mark_to_ligature(
+        &self,
+        lookup: u16,
+        index: usize,
+        subtable: &MarkLigPosFormat1,
+    ) -> Result<Vec<MarkClass>, ReadError> {
+        let mut classes = self.mark_classes(
+            lookup,
+            index,
+            AttachmentType::Ligature,
+            subtable.mark_class_count(),
+            subtable.mark_coverage()?.iter(),
+            subtable.mark_array()?,
+        )?;
+        for (ligature, attach) in subtable
+            .ligature_coverage()?
+            .iter()
+            .zip(subtable.ligature_array()?.ligature_attaches().iter())
+        {
+            let attach = attach?;
+            let data = attach.offset_data();
+            for component in attach.component_records().iter() {
+                let component = component?;
+                for (class, anchor) in classes
+                    .iter_mut()
+                    .zip(component.ligature_anchors(data).iter())
+                {
+                    class
+                        .attachments
+                        .entry(ligature)
+                        .or_default()
+                        .push(self.optional_anchor(anchor)?);
+                }
+            }
+        }
+        Ok(classes)
+    }
+
+    /// Read the mark classes of a mark-to-mark subtable
+    fn mark_to_mark(
+        &self,
+        lookup: u16,
+        index: usize,
+        subtable: &MarkMarkPosFormat1,
+    ) -> Result<Vec<MarkClass>, ReadError> {
+        let mut classes = self.mark_classes(
+            lookup,
+            index,
+            AttachmentType::Mark,
+            subtable.mark_class_count(),
+            subtable.mark1_coverage()?.iter(),
+            subtable.mark1_array()?,
+        )?;
+        let mark2_array = subtable.mark2_array()?;
+        let data = mark2_array.offset_data();
+        for (mark2, record) in subtable
+            .mark2_coverage()?
+            .iter()
+            .zip(mark2_array.mark2_records().iter())
+        {
+            for (class, anchor) in classes.iter_mut().zip(record?.mark2_anchors(data).iter()) {
+                class
+                    .attachments
+                    .insert(mark2, vec![self.optional_anchor(anchor)?]);
+            }
+        }
+        Ok(classes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::{Testable, TEST_FILE};
+    use skrifa::Tag;
+
+    #[test]
+    fn test_gpos_model() {
+        let testable = TEST_FILE!("cabinvf/Cabin[wdth,wght].ttf");
+        let f = TestFont::new_from_data(&testable.filename, &testable.contents).unwrap();
+        let gid = |name: &str| {
+            GlyphId16::try_from(
+                f.all_glyphs()
+                    .find(|gid| f.glyph_name_for_id(*gid).as_deref() == Some(name))
+                    .unwrap(),
+            )
+            .unwrap()
+        };
+        let model = f.gpos_model(&[]).unwrap();
+        assert_eq!(model.kern(gid("A"), gid("V")), Some(-98.0));
+        assert_eq!(model.kern(gid("A"), gid("A")), None);
+        assert!(model.can_attach(gid("acutecomb"), gid("A")));
+        let (class, anchors) = model
+            .anchors(gid("A"))
+            .find(|(class, _)| class.marks.contains_key(&gid("acutecomb")))
+            .unwrap();
+        assert_eq!(class.attachment, AttachmentType::Base);
+        assert_eq!(
+            anchors,
+            &[Some(Anchor {
+                x: 616.0,
+                y: 1400.0
+            })]
+        );
+        assert!(model.mark_classes.iter().all(|class| class.is_attached()));
+
+        // Kerning and anchors vary
+        let bold = f
+            .gpos_model(&[VariationSetting::new(Tag::new(b"wght"), 700.0)])
+            .unwrap();
+        assert_eq!(bold.kern(gid("A"), gid("V")), Some(-88.0));
+        assert_eq!(
+            bold.classes_of_mark(gid("acutecomb"))
+                .find_map(|class| class.anchor_for(gid("A"))),
+            Some(Anchor {
+                x: 626.0,
+                y: 1400.0
+            })
+        );
+    }
+}
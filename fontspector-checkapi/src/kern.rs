@@ -0,0 +1,187 @@
+// A reader for the legacy `kern` table. Fontations doesn't read it (yet):
+// https://github.com/googlefonts/fontations/issues/1183
+use skrifa::{
+    raw::{FontData, ReadError},
+    GlyphId16,
+};
+
+/// The size of a format 0 kerning pair record
+const PAIR_RECORD_SIZE: usize = 6;
+/// The size of the format 0 subtable header (nPairs, searchRange, entrySelector, rangeShift)
+const FORMAT0_HEADER_SIZE: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Which flavour of `kern` table this is
+pub enum KernVersion {
+    /// The Microsoft (OpenType) version 0 table
+    Microsoft,
+    /// The Apple (AAT) version 1.0 table
+    Apple,
+}
+
+#[derive(Debug, Clone)]
+/// A subtable of a legacy `kern` table
+pub struct KernSubtable {
+    /// The subtable format (0, 1, 2 or 3)
+    pub format: u8,
+    /// Whether the subtable contains horizontal kerning data
+    pub horizontal: bool,
+    /// Whether the kerning is perpendicular to the flow of text
+    pub cross_stream: bool,
+    /// For format 0 subtables, the kerning pairs (left glyph, right glyph, value)
+    ///
+    /// This is empty for all other formats.
+    pub pairs: Vec<(GlyphId16, GlyphId16, i16)>,
+}
+
+#[derive(Debug, Clone)]
+/// A parsed legacy `kern` table
+pub struct LegacyKern {
+    /// The table version
+    pub version: KernVersion,
+    /// The subtables in the table
+    pub subtables: Vec<KernSubtable>,
+}
+
+impl LegacyKern {
+    /// Read a `kern` table from its binary data
+    pub fn read(data: FontData) -> Result<Self, ReadError> {
+        if data.read_at::<u16>(0)? == 0 {
+            Self::read_microsoft(data)
+        } else if data.read_at::<u32>(0)? == 0x00010000 {
+            Self::read_apple(data)
+        } else {
+            Err(ReadError::InvalidFormat(data.read_at::<u16>(0)?.into()))
+        }
+    }
+
+    /// Read a Microsoft-style version 0 table
+    fn read_microsoft(data: FontData) -> Result<Self, ReadError> {
+        let n_tables = data.read_at::<u16>(2)?;
+        let mut offset = 4;
+        let mut subtables = vec![];
+        for _ in 0..n_tables {
+            let length = data.read_at::<u16>(offset + 2)? as usize;
+            let coverage = data.read_at::<u16>(offset + 4)?;
+            let format = (coverage >> 8) as u8;
+            let body = offset + 6;
+            let pairs = if format == 0 {
+                read_format0_pairs(data, body)?
+            } else {
+                vec![]
+            };
+            // The subtable length is a u16, and large format 0 subtables
+            // overflow it; in that case, work out the real length.
+            let length = if format == 0 {
+                6 + FORMAT0_HEADER_SIZE + pairs.len() * PAIR_RECORD_SIZE
+            } else {
+                length
+            };
+            subtables.push(KernSubtable {
+                format,
+                horizontal: coverage & 0x0001 != 0,
+                cross_stream: coverage & 0x0004 != 0,
+                pairs,
+            });
+            offset += length;
+        }
+        Ok(LegacyKern {
+            version: KernVersion::Microsoft,
+            subtables,
+        })
+    }
+
+    /// Read an Apple-style version 1.0 table
+    fn read_apple(data: FontData) -> Result<Self, ReadError> {
+        let n_tables = data.read_at::<u32>(4)?;
+        let mut offset = 8;
+        let mut subtables = vec![];
+        for _ in 0..n_tables {
+            let length = data.read_at::<u32>(offset)? as usize;
+            let coverage = data.read_at::<u16>(offset + 4)?;
+            let format = (coverage & 0x00FF) as u8;
+            let body = offset + 8;
+            let pairs = if format == 0 {
+                read_format0_pairs(data, body)?
+            } else {
+                vec![]
+            };
+            subtables.push(KernSubtable {
+                format,
+                horizontal: coverage & 0x8000 == 0,
+                cross_stream: coverage & 0x4000 != 0,
+                pairs,
+            });
+            offset += length;
+        }
+        Ok(LegacyKern {
+            version: KernVersion::Apple,
+            subtables,
+        })
+    }
+
+    /// Does this table have a format 0 subtable?
+    pub fn has_format0(&self) -> bool {
+        self.subtables.iter().any(|s| s.format == 0)
+    }
+}
+
+/// Read the kerning pairs of a format 0 subtable starting at `offset`
+fn read_format0_pairs(
+    data: FontData,
+    offset: usize,
+) -> Result<Vec<(GlyphId16, GlyphId16, i16)>, ReadError> {
+    let n_pairs = data.read_at::<u16>(offset)? as usize;
+    let start = offset + FORMAT0_HEADER_SIZE;
+    (0..n_pairs)
+        .map(|i| {
+            let record = start + i * PAIR_RECORD_SIZE;
+            Ok((
+                GlyphId16::new(data.read_at::<u16>(record)?),
+                GlyphId16::new(data.read_at::<u16>(record + 2)?),
+                data.read_at::<i16>(record + 4)?,
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_microsoft_format0() {
+        let bytes: Vec<u8> = vec![
+            0, 0, 0, 1, // version 0, one subtable
+            0, 0, 0, 26, 0, 1, // subtable version, length, coverage (horizontal, format 0)
+            0, 2, 0, 12, 0, 1, 0, 0, // nPairs=2, search parameters
+            0, 3, 0, 4, 0xFF, 0xCE, // 3, 4 => -50
+            0, 5, 0, 6, 0, 20, // 5, 6 => 20
+        ];
+        let kern = LegacyKern::read(FontData::new(&bytes)).unwrap();
+        assert_eq!(kern.version, KernVersion::Microsoft);
+        assert_eq!(kern.subtables.len(), 1);
+        assert!(kern.has_format0());
+        assert!(kern.subtables[0].horizontal);
+        assert_eq!(
+            kern.subtables[0].pairs,
+            vec![
+                (GlyphId16::new(3), GlyphId16::new(4), -50),
+                (GlyphId16::new(5), GlyphId16::new(6), 20)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apple_format2() {
+        let bytes: Vec<u8> = vec![
+            0, 1, 0, 0, 0, 0, 0, 1, // version 1.0, one subtable
+            0, 0, 0, 8, 0, 2, 0, 0, // length, coverage (horizontal, format 2), tuple index
+        ];
+        let kern = LegacyKern::read(FontData::new(&bytes)).unwrap();
+        assert_eq!(kern.version, KernVersion::Apple);
+        assert!(!kern.has_format0());
+        assert_eq!(kern.subtables[0].format, 2);
+    }
+}
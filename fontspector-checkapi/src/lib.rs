@@ -17,6 +17,8 @@
 mod check;
 /// Data structures representing the result of a check
 mod checkresult;
+/// Computing which glyphs are reachable through GSUB substitutions
+mod closure;
 /// Routines for testing checks
 pub mod codetesting;
 /// Font-related constants which may be useful to check implementors
@@ -27,30 +29,57 @@ mod context;
 mod filetype;
 /// Represents a TrueType font, together with useful routines for dealing with them
 mod font;
+/// User-defined sets of characters and glyphs which fonts are expected to contain
+mod glyphset;
+/// Routines to make dealing with GPOS tables more tractable
+mod gpos;
 /// Routines to make dealing with GSUB tables more tractable
 mod gsub;
+/// A reader for the legacy `kern` table
+mod kern;
+/// Converting between user-space and normalized variation coordinates
+mod location;
 /// [OutlinePen](https://docs.rs/skrifa/latest/skrifa/outline/trait.OutlinePen.html) implementations useful for check implementors
 pub mod pens;
+/// Loading plugins, and checking they were built against a compatible ABI
+mod plugin;
 /// Sets of checks that declare a particular "standard" of QA testing
 mod profile;
 /// The registry of checks and profiles
 mod registry;
 /// Data structures representing the most basic elements of a check's result
 mod status;
+/// Running location-sensitive checks at each location of a variable font
+mod sweep;
 /// Wraps a file or "thing" to be tested
 mod testable;
 /// Common utility functions for check implementors
 mod utils;
-pub use check::{return_result, Check, CheckFlags, CheckId, CheckImplementation, HotfixFunction};
+/// Accepted exceptions to check results, recorded alongside font sources
+mod waivers;
+pub use check::{
+    return_result, Check, CheckFlags, CheckId, CheckImplementation, HotfixFunction, ResultCode,
+};
 pub use checkresult::{CheckResult, FixResult};
+pub use closure::{FeaturePath, GsubClosure, Reached};
 pub use context::Context;
 pub use filetype::{FileType, FileTypeConvert};
 pub use font::{TestFont, DEFAULT_LOCATION, TTF};
+pub use glyphset::{is_graphic, Glyphset};
+pub use gpos::{
+    Anchor, AttachmentType, CursiveAnchors, GposModel, MarkClass, PairAdjustment, ValueAdjustment,
+};
 pub use gsub::{GetSubstitutionMap, SubstitutionMap};
-pub use profile::{Override, Profile, ProfileBuilder};
+pub use kern::{KernSubtable, KernVersion, LegacyKern};
+pub use plugin::{PluginError, PluginInfo, CHECKAPI_VERSION, PLUGIN_ABI_VERSION};
+pub use profile::{
+    CheckSelection, IncludedProfile, Override, Profile, ProfileBuilder, ProfileProblem,
+};
 pub use registry::Registry;
-pub use status::{CheckError, CheckFnResult, Status, StatusCode, StatusList};
+pub use status::{AppliedOverride, CheckError, CheckFnResult, Status, StatusCode, StatusList};
+pub use sweep::{merge_swept_results, sweep_check_order};
 pub use testable::{Testable, TestableCollection, TestableType};
+pub use waivers::{discover_waivers, parse_waivers, waivers_in, Waiver, WAIVERS_FILENAME};
 
 /// The prelude module contains the most common items you will need when writing checks
 pub mod prelude {
@@ -91,8 +120,8 @@ pub mod prelude {
     pub type FixFnResult = Result<bool, String>;
     pub use crate::{
         return_result, utils::*, Check, CheckError, CheckFlags, CheckFnResult, CheckImplementation,
-        Context, FileType, Profile, ProfileBuilder, Registry, Status, StatusList, Testable,
-        TestableCollection, TestableType, TTF,
+        Context, FileType, Profile, ProfileBuilder, Registry, ResultCode, Status, StatusCode,
+        StatusList, Testable, TestableCollection, TestableType, TTF,
     };
 }
 
@@ -100,7 +129,9 @@ pub mod prelude {
 ///
 /// Plugins contain checks and profiles that can be registered with the fontspector
 /// registry. The plugin must implement this trait and provide a function that
-/// returns an instance of the plugin. See [pluginator](https://docs.rs/pluginator/0.1.0/pluginator/)
+/// returns an instance of the plugin; use the [declare_plugin] macro to do this,
+/// which also exports the ABI version information checked when loading the plugin.
+/// See [pluginator](https://docs.rs/pluginator/0.1.0/pluginator/)
 pub trait Plugin {
     /// Register the checks and profiles in the plugin with the registry
     fn register(&self, cr: &mut Registry) -> Result<(), String>;
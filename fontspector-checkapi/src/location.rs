@@ -0,0 +1,265 @@
+use std::collections::HashSet;
+
+use skrifa::{
+    raw::{
+        tables::{avar::SegmentMaps, postscript::dict, variations::ItemVariationStore},
+        types::{F2Dot14, MajorMinor},
+        FontData, FontRead, TableProvider,
+    },
+    setting::VariationSetting,
+    FontRef, MetadataProvider,
+};
+
+use crate::TestFont;
+
+/// How many times each axis is searched when inverting an `avar` version 2 mapping
+const AVAR2_PASSES: usize = 3;
+/// How many times the search range of an axis is halved when inverting an `avar` version 2 mapping
+const AVAR2_STEPS: usize = 20;
+
+/// Map a normalized coordinate back through an `avar` segment map
+fn unmap_avar(maps: &SegmentMaps, coord: f32) -> f32 {
+    let points: Vec<(f32, f32)> = maps
+        .axis_value_maps()
+        .iter()
+        .map(|map| (map.to_coordinate().to_f32(), map.from_coordinate().to_f32()))
+        .collect();
+    for pair in points.windows(2) {
+        let ((to0, from0), (to1, from1)) = (pair[0], pair[1]);
+        if to0 <= coord && coord <= to1 {
+            if to1 == to0 {
+                return from0;
+            }
+            return from0 + (from1 - from0) * (coord - to0) / (to1 - to0);
+        }
+    }
+    coord
+}
+
+/// The item variation store of a `CFF2` table, which is found through its top DICT
+fn cff2_variation_store<'a>(font: &FontRef<'a>) -> Option<ItemVariationStore<'a>> {
+    let cff2 = font.cff2().ok()?;
+    let table_data = cff2.offset_data().as_bytes();
+    dict::entries(cff2.top_dict_data(), None).find_map(|entry| match entry {
+        Ok(dict::Entry::VariationStoreOffset(offset)) => {
+            // The store is preceded by its length
+            let data = table_data.get(offset.checked_add(2)?..)?;
+            ItemVariationStore::read(FontData::new(data)).ok()
+        }
+        _ => None,
+    })
+}
+
+impl TestFont<'_> {
+    /// Convert user-space coordinates to normalized coordinates, applying any `avar` mapping
+    ///
+    /// These are the coordinates at which the font's variation data is
+    /// interpolated. Both `avar` version 1 mappings and version 2 mappings, which
+    /// can move an axis depending on the others, are applied. Axes which aren't
+    /// given are at their default.
+    pub fn user_to_normalized(&self, settings: &[VariationSetting]) -> Vec<F2Dot14> {
+        self.font()
+            .axes()
+            .location(settings.iter().copied())
+            .coords()
+            .to_vec()
+    }
+
+    /// Convert user-space coordinates to normalized coordinates, without any `avar` mapping
+    ///
+    /// These design coordinates give how far each axis is from its default
+    /// towards its minimum (-1.0) or maximum (1.0).
+    pub fn user_to_design_normalized(&self, settings: &[VariationSetting]) -> Vec<F2Dot14> {
+        self.font()
+            .axes()
+            .iter()
+            .map(|axis| {
+                let value = settings
+                    .iter()
+                    .rev()
+                    .find(|setting| setting.selector == axis.tag())
+                    .map_or(axis.default_value(), |setting| setting.value);
+                axis.normalize(value)
+            })
+            .collect()
+    }
+
+    /// Convert normalized coordinates (after `avar` mapping) to user-space coordinates
+    ///
+    /// An `avar` version 1 mapping is inverted directly. A version 2 mapping can
+    /// make each axis depend on all the others and has no direct inverse, so
+    /// the user-space location is searched for one axis at a time, assuming that
+    /// each normalized coordinate increases with its own axis.
+    pub fn normalized_to_user(&self, coords: &[F2Dot14]) -> Vec<f32> {
+        let avar = self.font().avar().ok();
+        let segment_maps: Vec<Option<SegmentMaps>> = avar
+            .as_ref()
+            .map(|avar| {
+                avar.axis_segment_maps()
+                    .iter()
+                    .map(|maps| maps.ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let axes = self.font().axes();
+        let mut user: Vec<f32> = axes
+            .iter()
+            .enumerate()
+            .map(|(i, axis)| {
+                let mut value = coords.get(i).map(|c| c.to_f32()).unwrap_or_default();
+                if let Some(Some(maps)) = segment_maps.get(i) {
+                    value = unmap_avar(maps, value);
+                }
+                let (min, default, max) =
+                    (axis.min_value(), axis.default_value(), axis.max_value());
+                if value < 0.0 {
+                    default + value * (default - min)
+                } else {
+                    default + value * (max - default)
+                }
+            })
+            .collect();
+        if avar.is_none_or(|avar| avar.version() == MajorMinor::VERSION_1_0) {
+            return user;
+        }
+        let settings = |user: &[f32]| {
+            axes.iter()
+                .zip(user)
+                .map(|(axis, value)| VariationSetting::new(axis.tag(), *value))
+                .collect::<Vec<_>>()
+        };
+        for _ in 0..AVAR2_PASSES {
+            for (i, axis) in axes.iter().enumerate() {
+                let target = coords.get(i).copied().unwrap_or_default();
+                let (mut low, mut high) = (axis.min_value(), axis.max_value());
+                for _ in 0..AVAR2_STEPS {
+                    let middle = (low + high) / 2.0;
+                    user[i] = middle;
+                    if self.user_to_normalized(&settings(&user))[i] < target {
+                        low = middle;
+                    } else {
+                        high = middle;
+                    }
+                }
+                user[i] = (low + high) / 2.0;
+            }
+        }
+        user
+    }
+
+    /// The user-space location of normalized coordinates (after `avar` mapping)
+    pub fn user_location(&self, coords: &[F2Dot14]) -> Vec<VariationSetting> {
+        self.font()
+            .axes()
+            .iter()
+            .zip(self.normalized_to_user(coords))
+            .map(|(axis, value)| VariationSetting::new(axis.tag(), value))
+            .collect()
+    }
+
+    /// The normalized locations of the font's masters
+    ///
+    /// These are the default location, followed by the peaks of the regions
+    /// the font's variation data refers to: the tuple variations of each glyph
+    /// in `gvar`, and the regions of the item variation stores in `CFF2` and
+    /// `HVAR`. Duplicates are removed. A static font has no masters.
+    pub fn master_locations(&self) -> Vec<Vec<F2Dot14>> {
+        if !self.is_variable_font() {
+            return vec![];
+        }
+        let font = self.font();
+        let mut seen = HashSet::new();
+        let mut masters = vec![];
+        let mut add = |peak: Vec<F2Dot14>| {
+            if seen.insert(peak.clone()) {
+                masters.push(peak);
+            }
+        };
+        add(vec![F2Dot14::ZERO; font.axes().len()]);
+        if let Ok(gvar) = font.gvar() {
+            for gid in self.all_glyphs() {
+                let Ok(Some(data)) = gvar.glyph_variation_data(gid) else {
+                    continue;
+                };
+                for tuple in data.tuples() {
+                    add(tuple.peak().values.iter().map(|v| v.get()).collect());
+                }
+            }
+        }
+        let stores = [
+            font.hvar()
+                .and_then(|hvar| hvar.item_variation_store())
+                .ok(),
+            cff2_variation_store(&font),
+        ];
+        for store in stores.into_iter().flatten() {
+            let Ok(regions) = store.variation_region_list() else {
+                continue;
+            };
+            for region in regions.variation_regions().iter().flatten() {
+                add(region
+                    .region_axes()
+                    .iter()
+                    .map(|axis| axis.peak_coord())
+                    .collect());
+            }
+        }
+        masters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::{Testable, TEST_FILE};
+
+    #[test]
+    fn test_location_roundtrip() {
+        let testable = TEST_FILE!("cabinvf/Cabin[wdth,wght].ttf");
+        let f = TestFont::new_from_data(&testable.filename, &testable.contents).unwrap();
+        let bold_condensed = [
+            VariationSetting::new(skrifa::Tag::new(b"wght"), 700.0),
+            VariationSetting::new(skrifa::Tag::new(b"wdth"), 75.0),
+        ];
+        let normalized = f.user_to_normalized(&bold_condensed);
+        assert_eq!(
+            normalized,
+            vec![F2Dot14::from_f32(1.0), F2Dot14::from_f32(-1.0)]
+        );
+        assert_eq!(f.normalized_to_user(&normalized), vec![700.0, 75.0]);
+        assert_eq!(
+            f.user_to_design_normalized(&bold_condensed[..1]),
+            vec![F2Dot14::from_f32(1.0), F2Dot14::ZERO]
+        );
+    }
+
+    #[test]
+    fn test_location_roundtrip_avar() {
+        let testable = TEST_FILE!("source-sans-pro/VAR/SourceSansVariable-Roman.otf");
+        let f = TestFont::new_from_data(&testable.filename, &testable.contents).unwrap();
+        let semibold = [VariationSetting::new(skrifa::Tag::new(b"wght"), 600.0)];
+        let normalized = f.user_to_normalized(&semibold);
+        assert_ne!(normalized, f.user_to_design_normalized(&semibold));
+        let user = f.normalized_to_user(&normalized);
+        assert!((user[0] - 600.0).abs() < 1.0, "{:?}", user);
+    }
+
+    #[test]
+    fn test_master_locations() {
+        let testable = TEST_FILE!("cabinvf/Cabin[wdth,wght].ttf");
+        let f = TestFont::new_from_data(&testable.filename, &testable.contents).unwrap();
+        let masters = f.master_locations();
+        assert_eq!(masters[0], vec![F2Dot14::ZERO, F2Dot14::ZERO]);
+        assert!(masters.contains(&vec![F2Dot14::from_f32(1.0), F2Dot14::from_f32(-1.0)]));
+
+        let testable = TEST_FILE!("cabin/Cabin-Regular.ttf");
+        let f = TestFont::new_from_data(&testable.filename, &testable.contents).unwrap();
+        assert!(f.master_locations().is_empty());
+
+        // A CFF2 font has no gvar, so its masters come from its item variation stores
+        let testable = TEST_FILE!("source-sans-pro/VAR/SourceSansVariable-Roman.otf");
+        let f = TestFont::new_from_data(&testable.filename, &testable.contents).unwrap();
+        assert!(f.master_locations().len() > 1);
+    }
+}
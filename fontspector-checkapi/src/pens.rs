@@ -1,4 +1,4 @@
-use skrifa::outline::OutlinePen;
+use skrifa::{metrics::BoundingBox, outline::OutlinePen};
 
 #[derive(Debug, Default)]
 /// A pen for determining the delta between the highest and lowest points in an outline
@@ -182,6 +182,133 @@ impl OutlinePen for AreaPen {
     }
 }
 
+#[derive(Default)]
+/// A pen to determine the bounding box of an outline
+///
+/// The bounds are those of the outline itself, taking account of the extrema
+/// of its curves, rather than the bounds of its control points.
+pub struct BoundsPen {
+    /// The bounds found so far
+    bounds: Option<BoundingBox>,
+    /// The current point
+    current: (f32, f32),
+}
+
+impl BoundsPen {
+    /// Create a new BoundsPen
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The bounding box of the outline, or `None` if it is empty
+    pub fn bounds(&self) -> Option<BoundingBox> {
+        self.bounds
+    }
+
+    /// Extend the bounds to include a point
+    fn add(&mut self, x: f32, y: f32) {
+        self.bounds = Some(match self.bounds {
+            Some(b) => BoundingBox {
+                x_min: b.x_min.min(x),
+                y_min: b.y_min.min(y),
+                x_max: b.x_max.max(x),
+                y_max: b.y_max.max(y),
+            },
+            None => BoundingBox {
+                x_min: x,
+                y_min: y,
+                x_max: x,
+                y_max: y,
+            },
+        });
+    }
+}
+
+/// The parameters in (0, 1) at which `at^2 + bt + c` is zero
+fn quadratic_roots(a: f32, b: f32, c: f32) -> Vec<f32> {
+    let roots = if a.abs() < f32::EPSILON {
+        if b.abs() < f32::EPSILON {
+            vec![]
+        } else {
+            vec![-c / b]
+        }
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            vec![]
+        } else {
+            let root = discriminant.sqrt();
+            vec![(-b + root) / (2.0 * a), (-b - root) / (2.0 * a)]
+        }
+    };
+    roots.into_iter().filter(|t| *t > 0.0 && *t < 1.0).collect()
+}
+
+impl OutlinePen for BoundsPen {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.add(x, y);
+        self.current = (x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.add(x, y);
+        self.current = (x, y);
+    }
+
+    fn quad_to(&mut self, cx0: f32, cy0: f32, x: f32, y: f32) {
+        let (x0, y0) = self.current;
+        let point = |t: f32| {
+            let mt = 1.0 - t;
+            (
+                mt * mt * x0 + 2.0 * mt * t * cx0 + t * t * x,
+                mt * mt * y0 + 2.0 * mt * t * cy0 + t * t * y,
+            )
+        };
+        // The derivative is linear, so each axis has at most one extremum
+        for t in quadratic_roots(0.0, 2.0 * (x0 - 2.0 * cx0 + x), 2.0 * (cx0 - x0))
+            .into_iter()
+            .chain(quadratic_roots(
+                0.0,
+                2.0 * (y0 - 2.0 * cy0 + y),
+                2.0 * (cy0 - y0),
+            ))
+        {
+            let (px, py) = point(t);
+            self.add(px, py);
+        }
+        self.line_to(x, y);
+    }
+
+    fn curve_to(&mut self, cx0: f32, cy0: f32, cx1: f32, cy1: f32, x: f32, y: f32) {
+        let (x0, y0) = self.current;
+        let point = |t: f32| {
+            let mt = 1.0 - t;
+            let (a, b, c, d) = (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+            (
+                a * x0 + b * cx0 + c * cx1 + d * x,
+                a * y0 + b * cy0 + c * cy1 + d * y,
+            )
+        };
+        let derivative_roots = |p0: f32, p1: f32, p2: f32, p3: f32| {
+            quadratic_roots(
+                p3 - 3.0 * p2 + 3.0 * p1 - p0,
+                2.0 * (p2 - 2.0 * p1 + p0),
+                p1 - p0,
+            )
+        };
+        for t in derivative_roots(x0, cx0, cx1, x)
+            .into_iter()
+            .chain(derivative_roots(y0, cy0, cy1, y))
+        {
+            let (px, py) = point(t);
+            self.add(px, py);
+        }
+        self.line_to(x, y);
+    }
+
+    fn close(&mut self) {}
+}
+
 #[cfg(feature = "kurbo")]
 use kurbo::BezPath;
 
@@ -242,6 +369,7 @@ impl skrifa::outline::OutlinePen for BezGlyph {
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used)]
     use super::*;
 
     #[test]
@@ -256,4 +384,19 @@ mod tests {
         pen.close();
         assert_eq!(-104561.0, pen.area().round());
     }
+
+    #[test]
+    fn test_bounds() {
+        let mut pen = BoundsPen::new();
+        assert!(pen.bounds().is_none());
+        pen.move_to(0.0, 0.0);
+        pen.quad_to(50.0, 100.0, 100.0, 0.0);
+        pen.curve_to(100.0, -100.0, 200.0, -100.0, 200.0, 0.0);
+        pen.close();
+        let bounds = pen.bounds().unwrap();
+        assert_eq!(bounds.x_min, 0.0);
+        assert_eq!(bounds.x_max, 200.0);
+        assert_eq!(bounds.y_max, 50.0);
+        assert_eq!(bounds.y_min, -75.0);
+    }
 }
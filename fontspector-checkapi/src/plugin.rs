@@ -0,0 +1,169 @@
+use std::fmt::Display;
+
+/// The version of the plugin ABI
+///
+/// Plugins are compiled against a particular layout of [crate::Check],
+/// [crate::Context] and friends, so fontspector refuses to load plugins built
+/// against an incompatible version of this crate. The ABI version is derived
+/// from this crate's major and minor version (as `major * 1000 + minor`), so
+/// the minor version must be increased whenever any type which crosses the
+/// plugin boundary changes.
+pub const PLUGIN_ABI_VERSION: u32 = parse_version_number(env!("CARGO_PKG_VERSION_MAJOR")) * 1000
+    + parse_version_number(env!("CARGO_PKG_VERSION_MINOR"));
+
+/// Parse a component of this crate's version at compile time
+const fn parse_version_number(number: &str) -> u32 {
+    let digits = number.as_bytes();
+    let mut value = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        value = value * 10 + (digits[i] - b'0') as u32;
+        i += 1;
+    }
+    value
+}
+
+/// The version of fontspector-checkapi, as a NUL-terminated string
+///
+/// This is exported by plugins to help diagnose version mismatches.
+pub const CHECKAPI_VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+/// The name of the symbol exporting the plugin's ABI version
+pub(crate) const ABI_VERSION_SYMBOL: &[u8] = b"fontspector_plugin_abi_version\0";
+/// The name of the symbol exporting the plugin's fontspector-checkapi version
+pub(crate) const CHECKAPI_VERSION_SYMBOL: &[u8] = b"fontspector_plugin_checkapi_version\0";
+
+/// Declare a fontspector plugin
+///
+/// This exports the plugin (via `pluginator`) together with the ABI version
+/// information which fontspector checks before registering the plugin. The
+/// plugin crate must depend on `pluginator`.
+///
+/// ```ignore
+/// fontspector_checkapi::declare_plugin!(MyProfile);
+/// ```
+#[macro_export]
+macro_rules! declare_plugin {
+    ($plugin: expr) => {
+        pluginator::plugin_implementation!($crate::Plugin, $plugin);
+
+        /// The fontspector plugin ABI version this plugin was built against
+        #[no_mangle]
+        pub extern "C" fn fontspector_plugin_abi_version() -> u32 {
+            $crate::PLUGIN_ABI_VERSION
+        }
+
+        /// The fontspector-checkapi version this plugin was built against
+        #[no_mangle]
+        pub extern "C" fn fontspector_plugin_checkapi_version() -> *const ::std::ffi::c_char {
+            $crate::CHECKAPI_VERSION.as_ptr() as *const ::std::ffi::c_char
+        }
+    };
+}
+
+#[derive(Debug)]
+/// Something which went wrong while loading a plugin
+pub enum PluginError {
+    /// The library could not be opened, or did not contain a plugin
+    Load(String),
+    /// The library does not declare which ABI version it was built against
+    ///
+    /// This usually means it was built with an older fontspector-checkapi,
+    /// or without [declare_plugin].
+    MissingAbiVersion,
+    /// The plugin was built against an incompatible version of fontspector-checkapi
+    AbiMismatch {
+        /// The ABI version this fontspector expects
+        expected: u32,
+        /// The ABI version the plugin was built against
+        found: u32,
+        /// The fontspector-checkapi version the plugin was built against, if known
+        checkapi_version: Option<String>,
+    },
+    /// The plugin's own registration function returned an error
+    Register(String),
+}
+
+impl Display for PluginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluginError::Load(e) => write!(f, "could not load plugin: {}", e),
+            PluginError::MissingAbiVersion => write!(
+                f,
+                "plugin does not declare an ABI version; rebuild it against fontspector-checkapi {}",
+                CHECKAPI_VERSION.trim_end_matches('\0')
+            ),
+            PluginError::AbiMismatch {
+                expected,
+                found,
+                checkapi_version,
+            } => {
+                write!(
+                    f,
+                    "plugin was built for ABI version {} but this fontspector needs version {}",
+                    found, expected
+                )?;
+                if let Some(version) = checkapi_version {
+                    write!(f, " (plugin uses fontspector-checkapi {})", version)?;
+                }
+                write!(
+                    f,
+                    "; rebuild it against fontspector-checkapi {}",
+                    CHECKAPI_VERSION.trim_end_matches('\0')
+                )
+            }
+            PluginError::Register(e) => write!(f, "plugin failed to register: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+#[derive(Debug, Clone, Default)]
+/// What a plugin contributed to the registry
+pub struct PluginInfo {
+    /// The path the plugin was loaded from
+    pub path: String,
+    /// The fontspector-checkapi version the plugin was built against
+    pub checkapi_version: Option<String>,
+    /// IDs of the checks the plugin registered
+    pub checks: Vec<String>,
+    /// Names of the profiles the plugin registered
+    pub profiles: Vec<String>,
+    /// Names of the file types the plugin registered
+    pub filetypes: Vec<String>,
+}
+
+/// Check that a plugin library was built against a compatible ABI
+///
+/// Returns the fontspector-checkapi version the plugin was built against, if
+/// it declares one.
+///
+/// # Safety
+///
+/// This loads a dynamic library and calls functions within it.
+#[cfg(not(target_family = "wasm"))]
+pub(crate) unsafe fn check_abi(path: &str) -> Result<Option<String>, PluginError> {
+    let library =
+        unsafe { libloading::Library::new(path) }.map_err(|e| PluginError::Load(e.to_string()))?;
+    let abi_version = unsafe { library.get::<unsafe extern "C" fn() -> u32>(ABI_VERSION_SYMBOL) }
+        .map_err(|_| PluginError::MissingAbiVersion)?;
+    let found = unsafe { abi_version() };
+    let checkapi_version = unsafe {
+        library.get::<unsafe extern "C" fn() -> *const std::ffi::c_char>(CHECKAPI_VERSION_SYMBOL)
+    }
+    .ok()
+    .map(|version| {
+        unsafe { std::ffi::CStr::from_ptr(version()) }
+            .to_string_lossy()
+            .to_string()
+    });
+    if found != PLUGIN_ABI_VERSION {
+        return Err(PluginError::AbiMismatch {
+            expected: PLUGIN_ABI_VERSION,
+            found,
+            checkapi_version,
+        });
+    }
+    Ok(checkapi_version)
+}
@@ -1,9 +1,39 @@
+use chrono::NaiveDate;
+use glob_match::glob_match;
 use indexmap::IndexMap;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::{Map, Value};
 
-use crate::{Check, CheckId, Context, Registry, StatusCode, TestableType};
-use std::collections::HashMap;
+use crate::{
+    discover_waivers, Check, CheckId, Context, Registry, Status, StatusCode, TestableType,
+};
+use std::{collections::HashMap, fmt::Display, path::Path};
+
+/// Deserialize an optional date, given either as a string or a TOML date
+pub(crate) fn deserialize_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    /// The ways a date may be written
+    enum DateRepr {
+        /// `"2025-06-30"`
+        Text(String),
+        /// `2025-06-30` in a TOML file
+        Toml(toml::value::Datetime),
+    }
+    let Some(repr) = Option::<DateRepr>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    let text = match repr {
+        DateRepr::Text(text) => text,
+        DateRepr::Toml(datetime) => datetime.to_string(),
+    };
+    NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 /// An override directive
@@ -12,6 +42,10 @@ use std::collections::HashMap;
 /// decides that they disagree about the severity of a particular problem.
 /// To do this, they match against a result code, and provide a new status
 /// and a reason for the override.
+///
+/// Overrides may be further scoped to a particular check, to files and to
+/// glyph names matching a glob, and may carry an expiry date after which the
+/// check's original status is reported again.
 pub struct Override {
     /// Result code to match against
     pub code: String,
@@ -19,6 +53,29 @@ pub struct Override {
     pub status: StatusCode,
     /// Reason for the override
     pub reason: String,
+    /// Only apply this override to results from this check
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check: Option<CheckId>,
+    /// Only apply this override to files (or family directories) matching this glob
+    ///
+    /// The glob is matched against both the full path and the file name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub files: Option<String>,
+    /// Only apply this override to results about glyphs matching this glob
+    ///
+    /// See [Override::matches_glyphs].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub glyphs: Option<String>,
+    /// The last date on which this override applies
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_date"
+    )]
+    pub until: Option<NaiveDate>,
+    /// Who accepted the override
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepted_by: Option<String>,
 }
 
 impl Override {
@@ -28,11 +85,154 @@ impl Override {
             code: code.to_string(),
             status,
             reason: reason.to_string(),
+            check: None,
+            files: None,
+            glyphs: None,
+            until: None,
+            accepted_by: None,
+        }
+    }
+
+    /// Restrict the override to a single check
+    pub fn with_check(mut self, check_id: &str) -> Self {
+        self.check = Some(check_id.to_string());
+        self
+    }
+
+    /// Restrict the override to files matching a glob
+    pub fn with_files(mut self, glob: &str) -> Self {
+        self.files = Some(glob.to_string());
+        self
+    }
+
+    /// Restrict the override to results about glyphs matching a glob
+    pub fn with_glyphs(mut self, glob: &str) -> Self {
+        self.glyphs = Some(glob.to_string());
+        self
+    }
+
+    /// Make the override expire after the given date
+    pub fn with_expiry(mut self, until: NaiveDate) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Record who accepted the override
+    pub fn with_accepted_by(mut self, who: &str) -> Self {
+        self.accepted_by = Some(who.to_string());
+        self
+    }
+
+    /// Does this override apply to a given result?
+    ///
+    /// This does not take the expiry date into account; see [Override::is_expired].
+    pub fn matches(&self, code: Option<&str>, check_id: &str, filename: Option<&str>) -> bool {
+        if code != Some(self.code.as_str()) {
+            return false;
+        }
+        if self.check.as_ref().is_some_and(|c| c != check_id) {
+            return false;
+        }
+        if let Some(pattern) = &self.files {
+            let Some(filename) = filename else {
+                return false;
+            };
+            let basename = Path::new(filename)
+                .file_name()
+                .map(|b| b.to_string_lossy())
+                .unwrap_or_default();
+            if !glob_match(pattern, filename) && !glob_match(pattern, &basename) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Does this override's glyph pattern match the glyphs a status talks about?
+    ///
+    /// Results don't carry glyph names in a structured way, so the pattern is
+    /// matched against each word of the status message and each string in
+    /// its metadata. An override without a glyph pattern matches any status.
+    pub fn matches_glyphs(&self, status: &Status) -> bool {
+        let Some(pattern) = &self.glyphs else {
+            return true;
+        };
+        let mut candidates: Vec<&str> = status
+            .message
+            .as_deref()
+            .unwrap_or_default()
+            .split(|c: char| !(c.is_alphanumeric() || "._-".contains(c)))
+            .filter(|word| !word.is_empty())
+            .collect();
+        let mut values: Vec<&Value> = status.metadata.iter().collect();
+        while let Some(value) = values.pop() {
+            match value {
+                Value::String(s) => candidates.push(s),
+                Value::Array(items) => values.extend(items),
+                Value::Object(map) => values.extend(map.values()),
+                _ => {}
+            }
+        }
+        candidates
+            .into_iter()
+            .any(|candidate| glob_match(pattern, candidate))
+    }
+
+    /// Has the override passed its expiry date?
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        self.until.is_some_and(|until| today > until)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+/// User-provided criteria for selecting which checks to run
+///
+/// Check IDs are matched by substring; tags are matched exactly. A check
+/// is run if it passes all of the criteria which have been given.
+pub struct CheckSelection {
+    /// Only run checks whose ID contains one of these strings
+    pub include_checks: Option<Vec<String>>,
+    /// Don't run checks whose ID contains one of these strings
+    pub exclude_checks: Option<Vec<String>>,
+    /// Only run checks carrying at least one of these tags
+    pub include_tags: Option<Vec<String>>,
+    /// Don't run checks carrying any of these tags
+    pub exclude_tags: Option<Vec<String>>,
+    /// Run checks marked as experimental
+    ///
+    /// Experimental checks are not run by default, unless they are explicitly
+    /// requested through `include_checks`.
+    pub include_experimental: bool,
+}
+
+impl CheckSelection {
+    /// Should the given check be run?
+    pub fn selects(&self, check: &Check) -> bool {
+        if !included_excluded(check.id, &self.include_checks, &self.exclude_checks) {
+            return false;
+        }
+        if let Some(tags) = &self.include_tags {
+            if !tags.iter().any(|tag| check.has_tag(tag)) {
+                return false;
+            }
+        }
+        if let Some(tags) = &self.exclude_tags {
+            if tags.iter().any(|tag| check.has_tag(tag)) {
+                return false;
+            }
+        }
+        if check.flags.experimental && !self.include_experimental {
+            // Asking for a check by name is enough to opt in to it
+            return self
+                .include_checks
+                .as_ref()
+                .is_some_and(|ids| ids.iter().any(|id| check.id.contains(id)));
         }
+        true
     }
 }
 
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 /// A check profile
 ///
 /// A check profile is a collection of checks that are run together. Vendors
@@ -61,6 +261,12 @@ pub struct Profile {
     /// they can list all the checks they want to run manually to avoid
     /// surprises when new checks are implemented on profiles they intend to use.
     include_profiles: Vec<String>,
+    #[serde(default, rename = "include")]
+    /// Profiles to include, with their own exclusions and section names
+    ///
+    /// This is the long form of `include_profiles`, written as `[[include]]`
+    /// tables. See [IncludedProfile].
+    includes: Vec<IncludedProfile>,
     #[serde(default)]
     /// Checks to exclude from included profiles
     ///
@@ -83,6 +289,58 @@ pub struct Profile {
     /// by providing `WARN_SIZE` and `FAIL_SIZE` values in the profile; the user
     /// can then override these values on the command line.
     configuration_defaults: HashMap<CheckId, HashMap<String, serde_json::Value>>,
+    #[serde(default)]
+    /// Failure thresholds, by section
+    ///
+    /// When a check in one of these sections has a result at or above the
+    /// given status, the run is considered to have failed. Sections not listed
+    /// here use the threshold given on the command line.
+    pub fail_on: IndexMap<String, StatusCode>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+/// A profile to include, with adjustments
+///
+/// ```toml
+/// [[include]]
+/// profile = "googlefonts"
+/// exclude_checks = ["fontbakery_version"]
+/// rename_sections = { "Universal Profile Checks" = "Core", "Outline Checks" = "Core" }
+/// ```
+pub struct IncludedProfile {
+    /// The name of the profile to include
+    pub profile: String,
+    #[serde(default)]
+    /// Checks from this profile to leave out
+    pub exclude_checks: Vec<CheckId>,
+    #[serde(default)]
+    /// New names for the included profile's sections
+    ///
+    /// Renaming several sections to the same name merges them, and renaming a
+    /// section to the name of one of this profile's sections adds the included
+    /// checks to it.
+    pub rename_sections: IndexMap<String, String>,
+    #[serde(default)]
+    /// Put new sections after this profile's own sections, rather than before
+    pub after: bool,
+}
+
+impl IncludedProfile {
+    /// Include a profile as-is
+    pub fn new(profile: &str) -> Self {
+        IncludedProfile {
+            profile: profile.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// The name a section of the included profile should have in this one
+    fn section_name<'a>(&'a self, section: &'a str) -> &'a str {
+        self.rename_sections
+            .get(section)
+            .map(|s| s.as_str())
+            .unwrap_or(section)
+    }
 }
 
 impl Profile {
@@ -94,29 +352,16 @@ impl Profile {
     /// Validate a profile
     ///
     /// This function checks that all the checks in the profile are known to the
-    /// registry, resolving any included profiles and excluded checks, and that
-    /// any filetypes used in checks are known to the registry.
+    /// registry, resolving any included profiles and excluded checks, that
+    /// any filetypes used in checks are known to the registry, and that any
+    /// overrides refer to result codes which their checks declare.
     pub fn validate(&mut self, registry: &Registry) -> Result<(), String> {
         // Resolve "include_profiles" and "exclude_checks" here
-        for included_profile_str in self.include_profiles.iter() {
-            if let Some(profile) = registry.profiles.get(included_profile_str) {
-                // I want any new included sections to be at the top
-                for section in profile.sections.keys().rev() {
-                    if !self.sections.contains_key(section) {
-                        self.sections.insert_before(0, section.clone(), vec![]);
-                    }
-                }
-                for (section, checks) in &profile.sections {
-                    #[allow(clippy::unwrap_used)] // We added all new sections just now
-                    let existing_checks = self.sections.get_mut(section).unwrap();
-                    for check in checks {
-                        if !existing_checks.contains(check) {
-                            existing_checks.push(check.clone());
-                        }
-                    }
-                }
+        for included in self.inclusions() {
+            if let Some(profile) = registry.profiles.get(&included.profile) {
+                self.merge_included(profile, &included);
             } else {
-                return Err(format!("Unknown profile: {}", included_profile_str));
+                return Err(format!("Unknown profile: {}", included.profile));
             }
         }
 
@@ -148,41 +393,63 @@ impl Profile {
                 ));
             }
         }
+
+        for (check_id, overrides) in self.overrides.iter() {
+            let Some(check) = registry.checks.get(check_id) else {
+                log::warn!("Override given for unknown check {}", check_id);
+                continue;
+            };
+            if check.codes.is_empty() {
+                continue;
+            }
+            for override_ in overrides {
+                if check.declared_code(&override_.code).is_none() {
+                    log::warn!(
+                        "Override for check {} refers to unknown result code {}",
+                        check_id,
+                        override_.code
+                    );
+                }
+            }
+        }
         Ok(())
     }
 
     /// Determine a check order based on the profile
     ///
-    /// This applies any user-provided command line configuration (include/exclude checks)
+    /// This applies any user-provided command line configuration (see [CheckSelection])
     /// and works out which checks apply to the set of [TestableType]s provided.
-    /// It returns everything needed to run each check, in order.
+    /// Waivers found alongside each testable (see [discover_waivers]) are added
+    /// to the overrides in its context. It returns everything needed to run
+    /// each check, in order.
     pub fn check_order<'t, 'r>(
         &self,
-        include_checks: &Option<Vec<String>>,
-        exclude_checks: &Option<Vec<String>>,
+        selection: &CheckSelection,
         registry: &'r Registry<'r>,
         general_context: Context,
         configuration: Map<String, serde_json::Value>,
         testables: &'t [TestableType],
     ) -> Vec<(String, &'t TestableType<'t>, &'r Check<'r>, Context)> {
-        // Each testable gets its own context-specific cache.
-        let testable_and_cache = testables
-            .iter()
-            .map(|t| (t, general_context.with_new_cache()));
+        // Each testable gets its own context-specific cache, and any waivers
+        // found alongside it.
+        let testable_and_cache = testables.iter().map(|t| {
+            let mut context = general_context.with_new_cache();
+            context.overrides.extend(discover_waivers(t));
+            (t, context)
+        });
         // I'm just going to cheat and use nested for loops instead of iterator madness.
         let mut order = vec![];
         let mut sections_and_checks = vec![];
         for (section_name, check_ids) in self.sections.iter() {
             for check_id in check_ids.iter() {
-                if !included_excluded(check_id, include_checks, exclude_checks) {
-                    continue;
-                }
                 // The profile itself may have excluded this check
                 if self.exclude_checks.contains(check_id) {
                     continue;
                 }
-                if registry.checks.contains_key(check_id) {
-                    sections_and_checks.push((section_name, check_id))
+                if let Some(check) = registry.checks.get(check_id) {
+                    if selection.selects(check) {
+                        sections_and_checks.push((section_name, check_id))
+                    }
                 } else {
                     log::warn!("Unknown check: {}", check_id);
                 }
@@ -214,6 +481,266 @@ impl Profile {
             .unwrap_or(&HashMap::new())
             .clone()
     }
+
+    /// All included profiles, whether given in short or long form
+    fn inclusions(&self) -> Vec<IncludedProfile> {
+        self.include_profiles
+            .iter()
+            .map(|name| IncludedProfile::new(name))
+            .chain(self.includes.iter().cloned())
+            .collect()
+    }
+
+    /// Merge the sections of an included profile into this one
+    fn merge_included(&mut self, profile: &Profile, included: &IncludedProfile) {
+        let mut sections: IndexMap<&str, Vec<&CheckId>> = IndexMap::new();
+        for (section, checks) in &profile.sections {
+            sections
+                .entry(included.section_name(section))
+                .or_default()
+                .extend(
+                    checks
+                        .iter()
+                        .filter(|check| !included.exclude_checks.contains(check)),
+                );
+        }
+        if included.after {
+            for section in sections.keys() {
+                if !self.sections.contains_key(*section) {
+                    self.sections.insert(section.to_string(), vec![]);
+                }
+            }
+        } else {
+            // I want any new included sections to be at the top
+            for section in sections.keys().rev() {
+                if !self.sections.contains_key(*section) {
+                    self.sections.insert_before(0, section.to_string(), vec![]);
+                }
+            }
+        }
+        for (section, checks) in sections {
+            #[allow(clippy::unwrap_used)] // We added all new sections just now
+            let existing_checks = self.sections.get_mut(section).unwrap();
+            for check in checks {
+                if !existing_checks.contains(check) {
+                    existing_checks.push(check.clone());
+                }
+            }
+        }
+        for (section, threshold) in &profile.fail_on {
+            self.fail_on
+                .entry(included.section_name(section).to_string())
+                .or_insert(*threshold);
+        }
+    }
+
+    /// The status at or above which results in a section count as a failure
+    ///
+    /// Returns `None` if the profile does not set a threshold for the section.
+    pub fn fail_threshold(&self, section: &str) -> Option<StatusCode> {
+        self.fail_on.get(section).copied()
+    }
+
+    /// Resolve included profiles and excluded checks
+    ///
+    /// This returns a copy of the profile with the sections of its included
+    /// profiles merged in and its excluded checks removed; that is, the
+    /// checks which will actually be run. Unknown included profiles are
+    /// skipped (see [Profile::lint]).
+    pub fn resolved(&self, registry: &Registry) -> Profile {
+        let mut resolved = self.clone();
+        for included in self.inclusions() {
+            if let Some(profile) = registry.profiles.get(&included.profile) {
+                resolved.merge_included(profile, &included);
+            }
+        }
+        resolved.include_profiles.clear();
+        resolved.includes.clear();
+        for checks in resolved.sections.values_mut() {
+            checks.retain(|check| !self.exclude_checks.contains(check));
+        }
+        resolved.sections.retain(|_, checks| !checks.is_empty());
+        resolved.exclude_checks.clear();
+        resolved
+    }
+
+    /// Look for problems in a profile
+    ///
+    /// Unlike [Profile::validate], this does not stop at the first problem,
+    /// and also reports things which are legal but probably mistakes.
+    pub fn lint(&self, registry: &Registry) -> Vec<ProfileProblem> {
+        let mut problems = vec![];
+        for included in self.inclusions() {
+            let Some(profile) = registry.profiles.get(&included.profile) else {
+                problems.push(ProfileProblem::UnknownProfile(included.profile.clone()));
+                continue;
+            };
+            for section in included.rename_sections.keys() {
+                if !profile.sections.contains_key(section) {
+                    problems.push(ProfileProblem::UnknownSection {
+                        profile: included.profile.clone(),
+                        section: section.clone(),
+                    });
+                }
+            }
+        }
+
+        let resolved = self.resolved(registry);
+        let mut sections_by_check: IndexMap<&str, Vec<&str>> = IndexMap::new();
+        for (section, checks) in resolved.sections.iter() {
+            for check_id in checks {
+                if !registry.checks.contains_key(check_id) {
+                    problems.push(ProfileProblem::UnknownCheck {
+                        check: check_id.clone(),
+                        section: section.clone(),
+                    });
+                }
+                sections_by_check
+                    .entry(check_id.as_str())
+                    .or_default()
+                    .push(section.as_str());
+            }
+        }
+        for (check_id, sections) in sections_by_check.iter() {
+            if sections.len() > 1 {
+                problems.push(ProfileProblem::DuplicateCheck {
+                    check: check_id.to_string(),
+                    sections: sections.iter().map(|s| s.to_string()).collect(),
+                });
+            }
+        }
+
+        let mut override_checks: Vec<&CheckId> = self.overrides.keys().collect();
+        override_checks.sort();
+        for check_id in override_checks {
+            if !sections_by_check.contains_key(check_id.as_str()) {
+                problems.push(ProfileProblem::OverrideForMissingCheck(check_id.clone()));
+            }
+            let Some(check) = registry.checks.get(check_id) else {
+                continue;
+            };
+            if check.codes.is_empty() {
+                continue;
+            }
+            for override_ in self.overrides.get(check_id).into_iter().flatten() {
+                if check.declared_code(&override_.code).is_none() {
+                    problems.push(ProfileProblem::UnknownOverrideCode {
+                        check: check_id.clone(),
+                        code: override_.code.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut configured_checks: Vec<&CheckId> = self.configuration_defaults.keys().collect();
+        configured_checks.sort();
+        for check_id in configured_checks {
+            if !sections_by_check.contains_key(check_id.as_str()) {
+                problems.push(ProfileProblem::ConfigurationForMissingCheck(
+                    check_id.clone(),
+                ));
+            }
+        }
+
+        for section in self.fail_on.keys() {
+            if !resolved.sections.contains_key(section) {
+                problems.push(ProfileProblem::ThresholdForMissingSection(section.clone()));
+            }
+        }
+        problems
+    }
+
+    /// Write the profile out as TOML
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Something which is probably wrong with a profile, found by [Profile::lint]
+pub enum ProfileProblem {
+    /// An included profile is not known
+    UnknownProfile(String),
+    /// A check in the profile is not known
+    UnknownCheck {
+        /// The check ID
+        check: CheckId,
+        /// The section the check appears in
+        section: String,
+    },
+    /// A check appears more than once
+    DuplicateCheck {
+        /// The check ID
+        check: CheckId,
+        /// The sections the check appears in
+        sections: Vec<String>,
+    },
+    /// An override refers to a result code which the check does not declare
+    UnknownOverrideCode {
+        /// The check ID
+        check: CheckId,
+        /// The result code
+        code: String,
+    },
+    /// Overrides are given for a check which is not in the profile
+    OverrideForMissingCheck(CheckId),
+    /// Configuration defaults are given for a check which is not in the profile
+    ConfigurationForMissingCheck(CheckId),
+    /// An included profile is asked to rename a section it does not have
+    UnknownSection {
+        /// The included profile
+        profile: String,
+        /// The section name
+        section: String,
+    },
+    /// A failure threshold is given for a section which is not in the profile
+    ThresholdForMissingSection(String),
+}
+
+impl Display for ProfileProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileProblem::UnknownProfile(name) => {
+                write!(f, "Included profile {} is not known", name)
+            }
+            ProfileProblem::UnknownCheck { check, section } => {
+                write!(f, "Unknown check {} in section {}", check, section)
+            }
+            ProfileProblem::DuplicateCheck { check, sections } => write!(
+                f,
+                "Check {} appears more than once, in sections: {}",
+                check,
+                sections.join(", ")
+            ),
+            ProfileProblem::UnknownOverrideCode { check, code } => write!(
+                f,
+                "Override for check {} refers to unknown result code {}",
+                check, code
+            ),
+            ProfileProblem::OverrideForMissingCheck(check) => {
+                write!(
+                    f,
+                    "Overrides given for check {} which is not in the profile",
+                    check
+                )
+            }
+            ProfileProblem::ConfigurationForMissingCheck(check) => write!(
+                f,
+                "Configuration defaults given for check {} which is not in the profile",
+                check
+            ),
+            ProfileProblem::UnknownSection { profile, section } => write!(
+                f,
+                "Included profile {} has no section called {}",
+                profile, section
+            ),
+            ProfileProblem::ThresholdForMissingSection(section) => write!(
+                f,
+                "Failure threshold given for section {} which is not in the profile",
+                section
+            ),
+        }
+    }
 }
 
 /// Apply inclusions and exclusions to a list of checks
@@ -294,6 +821,18 @@ impl<'a> ProfileBuilder<'a> {
         self
     }
 
+    /// Include another profile, with adjustments
+    pub fn include(mut self, included: IncludedProfile) -> Self {
+        self.profile.includes.push(included);
+        self
+    }
+
+    /// Set the status at or above which results in a section count as a failure
+    pub fn fail_on(mut self, section: &str, threshold: StatusCode) -> Self {
+        self.profile.fail_on.insert(section.to_string(), threshold);
+        self
+    }
+
     /// Exclude a check
     pub fn exclude_check(mut self, check: &str) -> Self {
         self.profile.exclude_checks.push(check.to_string());
@@ -334,3 +873,355 @@ impl Default for ProfileBuilder<'_> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::{CheckFlags, CheckFnResult, CheckImplementation, ResultCode, Status, Testable};
+
+    fn nothing(_t: &Testable, _c: &Context) -> CheckFnResult {
+        Ok(Status::just_one_pass())
+    }
+
+    const fn check(
+        id: &'static str,
+        tags: &'static [&'static str],
+        experimental: bool,
+    ) -> Check<'static> {
+        Check {
+            id,
+            title: "",
+            rationale: "",
+            proposal: &[],
+            implementation: CheckImplementation::CheckOne(&nothing),
+            hotfix: None,
+            fix_source: None,
+            applies_to: "TTF",
+            flags: CheckFlags {
+                experimental,
+                location_sensitive: false,
+            },
+            tags,
+            codes: &[],
+            _metadata: None,
+        }
+    }
+
+    #[test]
+    fn test_selection_by_tag() {
+        let namecheck = check("fontdata_namecheck", &["network"], false);
+        let interpolation = check("interpolation_issues", &["slow", "outline"], false);
+        let names = check("name/trailing_spaces", &["naming"], false);
+
+        let fast_offline = CheckSelection {
+            exclude_tags: Some(vec!["slow".to_string(), "network".to_string()]),
+            ..Default::default()
+        };
+        assert!(!fast_offline.selects(&namecheck));
+        assert!(!fast_offline.selects(&interpolation));
+        assert!(fast_offline.selects(&names));
+
+        let outlines = CheckSelection {
+            include_tags: Some(vec!["outline".to_string()]),
+            ..Default::default()
+        };
+        assert!(outlines.selects(&interpolation));
+        assert!(!outlines.selects(&names));
+
+        // Tags combine with check ID matching
+        let name_outlines = CheckSelection {
+            include_checks: Some(vec!["name".to_string()]),
+            include_tags: Some(vec!["outline".to_string()]),
+            ..Default::default()
+        };
+        assert!(!name_outlines.selects(&interpolation));
+        assert!(!name_outlines.selects(&names));
+    }
+
+    #[test]
+    fn test_selection_experimental() {
+        let experimental = check("caps_vertically_centered", &["outline"], true);
+        assert!(!CheckSelection::default().selects(&experimental));
+        assert!(CheckSelection {
+            include_experimental: true,
+            ..Default::default()
+        }
+        .selects(&experimental));
+        assert!(CheckSelection {
+            include_checks: Some(vec!["caps_vertically".to_string()]),
+            ..Default::default()
+        }
+        .selects(&experimental));
+        assert!(!CheckSelection {
+            include_tags: Some(vec!["outline".to_string()]),
+            ..Default::default()
+        }
+        .selects(&experimental));
+        assert!(CheckSelection {
+            include_tags: Some(vec!["outline".to_string()]),
+            include_experimental: true,
+            ..Default::default()
+        }
+        .selects(&experimental));
+    }
+
+    #[test]
+    fn test_override_codes_validated() {
+        let mut declared = check("test/declared", &[], false);
+        declared.codes = &[ResultCode {
+            code: "bad-entries",
+            severity: StatusCode::Info,
+        }];
+        let mut registry = Registry::new();
+        let good = ProfileBuilder::new()
+            .add_section("Test")
+            .add_and_register_check(declared.clone())
+            .with_overrides(
+                "test/declared",
+                vec![Override::new("bad-entries", StatusCode::Warn, "Louder")],
+            )
+            .build("good", &mut registry);
+        assert!(good.is_ok());
+
+        let typo = ProfileBuilder::new()
+            .add_section("Test")
+            .add_and_register_check(declared)
+            .with_overrides(
+                "test/declared",
+                vec![Override::new("bad-entry", StatusCode::Warn, "Louder")],
+            )
+            .build("typo", &mut registry);
+        // Only a warning when loading; `profile lint` reports it as a problem
+        assert!(typo.is_ok());
+        assert!(registry.get_profile("typo").is_some_and(|profile| profile
+            .lint(&registry)
+            .iter()
+            .any(|problem| matches!(
+                problem,
+                ProfileProblem::UnknownOverrideCode { code, .. } if code == "bad-entry"
+            ))));
+        assert_eq!(registry.code_is_declared("bad-entries"), Some(true));
+        assert_eq!(registry.code_is_declared("bad-entry"), Some(false));
+    }
+
+    #[test]
+    fn test_scoped_overrides() {
+        let override_ = Override::new("bad-entries", StatusCode::Pass, "Known issue")
+            .with_check("opentype/family_naming_recommendations")
+            .with_files("Foo-*.ttf");
+        let check_id = "opentype/family_naming_recommendations";
+        assert!(override_.matches(Some("bad-entries"), check_id, Some("fonts/Foo-Bold.ttf")));
+        assert!(!override_.matches(Some("bad-entries"), check_id, Some("fonts/Bar-Bold.ttf")));
+        assert!(!override_.matches(Some("bad-entries"), "name_id_1", Some("fonts/Foo-Bold.ttf")));
+        assert!(!override_.matches(Some("other"), check_id, Some("fonts/Foo-Bold.ttf")));
+        assert!(!override_.matches(Some("bad-entries"), check_id, None));
+    }
+
+    #[test]
+    fn test_override_expiry() {
+        let until = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        let overrides = vec![
+            Override::new("bad-entries", StatusCode::Pass, "Known issue")
+                .with_expiry(until)
+                .with_accepted_by("QA team"),
+        ];
+
+        let mut status = Status::fail("bad-entries", "Too long");
+        status.process_override(&overrides, "x", None, until);
+        assert_eq!(status.severity, StatusCode::Pass);
+        let applied = status.overridden.as_ref().unwrap();
+        assert_eq!(applied.original_severity, StatusCode::Fail);
+        assert_eq!(applied.accepted_by.as_deref(), Some("QA team"));
+        assert!(!applied.expired);
+
+        let mut status = Status::fail("bad-entries", "Too long");
+        status.process_override(&overrides, "x", None, until.succ_opt().unwrap());
+        assert_eq!(status.severity, StatusCode::Fail);
+        assert!(status.overridden.as_ref().unwrap().expired);
+    }
+
+    #[test]
+    fn test_profile_lint() {
+        let mut declared = check("test/declared", &[], false);
+        declared.codes = &[ResultCode {
+            code: "bad-entries",
+            severity: StatusCode::Info,
+        }];
+        let mut registry = Registry::new();
+        ProfileBuilder::new()
+            .add_section("Base")
+            .add_and_register_check(declared)
+            .add_and_register_check(check("test/other", &[], false))
+            .build("base", &mut registry)
+            .unwrap();
+
+        let profile = Profile::from_toml(
+            r#"
+            include_profiles = ["base", "nonexistent"]
+            exclude_checks = ["test/other"]
+
+            [sections]
+            "Mine" = ["test/declared", "test/misspelled"]
+
+            [[overrides."test/declared"]]
+            code = "bad-entry"
+            status = "WARN"
+            reason = "Typo"
+
+            [configuration_defaults."test/other"]
+            LIMIT = 3
+            "#,
+        )
+        .unwrap();
+        let resolved = profile.resolved(&registry);
+        assert_eq!(
+            resolved.sections.keys().collect::<Vec<_>>(),
+            vec!["Base", "Mine"]
+        );
+        assert_eq!(resolved.sections["Base"], vec!["test/declared"]);
+
+        let problems = profile.lint(&registry);
+        assert_eq!(
+            problems,
+            vec![
+                ProfileProblem::UnknownProfile("nonexistent".to_string()),
+                ProfileProblem::UnknownCheck {
+                    check: "test/misspelled".to_string(),
+                    section: "Mine".to_string()
+                },
+                ProfileProblem::DuplicateCheck {
+                    check: "test/declared".to_string(),
+                    sections: vec!["Base".to_string(), "Mine".to_string()]
+                },
+                ProfileProblem::UnknownOverrideCode {
+                    check: "test/declared".to_string(),
+                    code: "bad-entry".to_string()
+                },
+                ProfileProblem::ConfigurationForMissingCheck("test/other".to_string()),
+            ]
+        );
+        assert!(Profile::from_toml(&resolved.to_toml().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_profile_composition() {
+        let mut registry = Registry::new();
+        ProfileBuilder::new()
+            .add_section("Names")
+            .add_and_register_check(check("name/a", &[], false))
+            .add_and_register_check(check("name/b", &[], false))
+            .add_section("Outlines")
+            .add_and_register_check(check("outline/a", &[], false))
+            .add_section("Metrics")
+            .add_and_register_check(check("metrics/a", &[], false))
+            .build("base", &mut registry)
+            .unwrap();
+
+        let profile = Profile::from_toml(
+            r#"
+            [[include]]
+            profile = "base"
+            exclude_checks = ["name/b"]
+            rename_sections = { "Outlines" = "Release blockers", "Metrics" = "Advisory", "Names" = "Advisory" }
+
+            [sections]
+            "Release blockers" = ["name/b"]
+
+            [fail_on]
+            "Release blockers" = "WARN"
+            "Advisory" = "ERROR"
+            "#,
+        )
+        .unwrap();
+        let resolved = profile.resolved(&registry);
+        assert_eq!(
+            resolved.sections.keys().collect::<Vec<_>>(),
+            vec!["Advisory", "Release blockers"]
+        );
+        assert_eq!(resolved.sections["Advisory"], vec!["name/a", "metrics/a"]);
+        // Our own checks come first, and the exclusion only applies to the included profile
+        assert_eq!(
+            resolved.sections["Release blockers"],
+            vec!["name/b", "outline/a"]
+        );
+        assert_eq!(
+            resolved.fail_threshold("Release blockers"),
+            Some(StatusCode::Warn)
+        );
+        assert_eq!(resolved.fail_threshold("Other"), None);
+        assert!(profile.lint(&registry).is_empty());
+
+        let after = ProfileBuilder::new()
+            .add_section("Mine")
+            .add_and_register_check(check("mine/a", &[], false))
+            .include(IncludedProfile {
+                after: true,
+                ..IncludedProfile::new("base")
+            })
+            .build("after", &mut registry);
+        assert!(after.is_ok());
+        assert_eq!(
+            registry
+                .get_profile("after")
+                .unwrap()
+                .sections
+                .keys()
+                .collect::<Vec<_>>(),
+            vec!["Mine", "Names", "Outlines", "Metrics"]
+        );
+    }
+
+    #[test]
+    fn test_check_order_finds_waivers() {
+        let directory = std::env::temp_dir().join("fontspector-test-check-order-waivers");
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(
+            directory.join(crate::WAIVERS_FILENAME),
+            r#"
+            [[waiver]]
+            check = "test/waived"
+            code = "bad-entries"
+            reason = "Known issue"
+            "#,
+        )
+        .unwrap();
+
+        let mut registry = Registry::new();
+        ProfileBuilder::new()
+            .add_section("Test")
+            .add_and_register_check(check("test/waived", &[], false))
+            .build("test", &mut registry)
+            .unwrap();
+        let profile = registry.get_profile("test").unwrap();
+        let near = Testable {
+            filename: directory.join("Family-Regular.ttf"),
+            source: None,
+            contents: vec![],
+        };
+        let elsewhere = Testable {
+            filename: "Family-Regular.ttf".into(),
+            source: None,
+            contents: vec![],
+        };
+        let testables = [
+            TestableType::Single(&near),
+            TestableType::Single(&elsewhere),
+        ];
+        let order = profile.check_order(
+            &CheckSelection::default(),
+            &registry,
+            Context::default(),
+            Map::new(),
+            &testables,
+        );
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(order.len(), 2);
+        assert!(matches!(
+            order[0].3.overrides.as_slice(),
+            [waiver] if waiver.status == StatusCode::Waived && waiver.code == "bad-entries"
+        ));
+        assert!(order[1].3.overrides.is_empty());
+    }
+}
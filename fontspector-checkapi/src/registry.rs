@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 use crate::{Check, CheckId, FileType, Profile, Testable, TTF};
+#[cfg(not(target_family = "wasm"))]
+use crate::{PluginError, PluginInfo};
 
 #[derive(Default)]
 /// The Registry object
@@ -13,6 +15,14 @@ pub struct Registry<'a> {
     pub(crate) profiles: HashMap<String, Profile>,
     /// All known filetypes, by name
     pub(crate) filetypes: HashMap<String, FileType<'a>>,
+    /// Information about the plugins which have been loaded
+    #[cfg(not(target_family = "wasm"))]
+    pub plugins: Vec<PluginInfo>,
+    /// Handles to loaded plugin libraries
+    ///
+    /// These must outlive the checks they registered, so they come last.
+    #[cfg(not(target_family = "wasm"))]
+    loaded_plugins: Vec<pluginator::LoadedPlugin<dyn crate::Plugin>>,
 }
 
 impl<'a> Registry<'a> {
@@ -29,12 +39,61 @@ impl<'a> Registry<'a> {
     }
 
     /// Load a plugin from a path
+    ///
+    /// The plugin's ABI version is checked before it is allowed to register
+    /// anything, and a summary of what it registered is recorded in
+    /// [Registry::plugins].
     #[cfg(not(target_family = "wasm"))]
-    pub fn load_plugin(&mut self, plugin_path: &str) -> Result<(), String> {
-        let plugin = unsafe { crate::load_plugin(plugin_path) }.unwrap_or_else(|e| {
-            panic!("Could not load plugin {:?}: {:?}", plugin_path, e);
-        });
-        plugin.register(self)
+    pub fn load_plugin(&mut self, plugin_path: &str) -> Result<&PluginInfo, PluginError> {
+        let checkapi_version = unsafe { crate::plugin::check_abi(plugin_path) }?;
+        let plugin = unsafe { crate::load_plugin(plugin_path) }
+            .map_err(|e| PluginError::Load(format!("{:?}", e)))?;
+
+        let result = self.register_plugin(&*plugin, plugin_path, checkapi_version);
+        // A plugin which failed may still have replaced existing entries with
+        // ones pointing into its code, so the library is kept loaded regardless.
+        self.loaded_plugins.push(plugin);
+        result?;
+        #[allow(clippy::unwrap_used)] // register_plugin just pushed it
+        Ok(self.plugins.last().unwrap())
+    }
+
+    /// Let a plugin register its checks, profiles and file types
+    ///
+    /// If the plugin fails partway through, whatever it managed to register is
+    /// taken back out. Otherwise, a summary of what it registered is recorded
+    /// in [Registry::plugins].
+    #[cfg(not(target_family = "wasm"))]
+    pub fn register_plugin(
+        &mut self,
+        plugin: &dyn crate::Plugin,
+        plugin_path: &str,
+        checkapi_version: Option<String>,
+    ) -> Result<(), PluginError> {
+        let checks_before: Vec<String> = self.checks.keys().cloned().collect();
+        let profiles_before: Vec<String> = self.profiles.keys().cloned().collect();
+        let filetypes_before: Vec<String> = self.filetypes.keys().cloned().collect();
+        if let Err(e) = plugin.register(self) {
+            self.checks.retain(|id, _| checks_before.contains(id));
+            self.profiles
+                .retain(|name, _| profiles_before.contains(name));
+            self.filetypes
+                .retain(|name, _| filetypes_before.contains(name));
+            return Err(PluginError::Register(e));
+        }
+
+        let mut info = PluginInfo {
+            path: plugin_path.to_string(),
+            checkapi_version,
+            checks: new_keys(&self.checks, &checks_before),
+            profiles: new_keys(&self.profiles, &profiles_before),
+            filetypes: new_keys(&self.filetypes, &filetypes_before),
+        };
+        info.checks.sort();
+        info.profiles.sort();
+        info.filetypes.sort();
+        self.plugins.push(info);
+        Ok(())
     }
 
     /// Register a new [Profile]
@@ -49,6 +108,11 @@ impl<'a> Registry<'a> {
         self.profiles.get(name)
     }
 
+    /// Get an iterator over all profiles, with their names
+    pub fn iter_profiles(&self) -> impl Iterator<Item = (&String, &Profile)> {
+        self.profiles.iter()
+    }
+
     /// Register a new filetype
     pub fn register_filetype(&mut self, name: &str, filetype: FileType<'a>) {
         self.filetypes.insert(name.to_string(), filetype);
@@ -83,8 +147,102 @@ impl<'a> Registry<'a> {
             .is_some_and(|c| c.flags.experimental)
     }
 
+    /// Is a result code declared by any registered check?
+    ///
+    /// Returns `None` if no check declares the code but some checks have
+    /// not declared their result codes, so we cannot be sure.
+    pub fn code_is_declared(&self, code: &str) -> Option<bool> {
+        if self
+            .checks
+            .values()
+            .any(|c| c.declared_code(code).is_some())
+        {
+            Some(true)
+        } else if self.checks.values().any(|c| c.codes.is_empty()) {
+            None
+        } else {
+            Some(false)
+        }
+    }
+
     /// Returns true if a Testable is recognised by any filetype
     pub fn is_known_file(&self, file: &Testable) -> bool {
         self.filetypes.values().any(|ft| ft.applies(file))
     }
 }
+
+/// Keys of a map which were not present in a previous list of keys
+#[cfg(not(target_family = "wasm"))]
+fn new_keys<V>(map: &HashMap<String, V>, before: &[String]) -> Vec<String> {
+    map.keys()
+        .filter(|k| !before.contains(k))
+        .cloned()
+        .collect()
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::{CheckFlags, CheckFnResult, CheckImplementation, Context, Plugin, Status};
+
+    fn nothing(_t: &Testable, _c: &Context) -> CheckFnResult {
+        Ok(Status::just_one_pass())
+    }
+
+    fn check(id: &'static str) -> Check<'static> {
+        Check {
+            id,
+            title: "",
+            rationale: "",
+            proposal: &[],
+            implementation: CheckImplementation::CheckOne(&nothing),
+            hotfix: None,
+            fix_source: None,
+            applies_to: "TTF",
+            flags: CheckFlags {
+                experimental: false,
+                location_sensitive: false,
+            },
+            tags: &[],
+            codes: &[],
+            _metadata: None,
+        }
+    }
+
+    struct Partial {
+        fail: bool,
+    }
+
+    impl Plugin for Partial {
+        fn register(&self, cr: &mut Registry) -> Result<(), String> {
+            cr.register_check(check("partial/first"));
+            if self.fail {
+                return Err("second check is broken".to_string());
+            }
+            cr.register_check(check("partial/second"));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_register_plugin_rolls_back() {
+        let mut registry = Registry::new();
+        registry.register_check(check("existing"));
+
+        let result = registry.register_plugin(&Partial { fail: true }, "partial", None);
+        assert!(matches!(result, Err(PluginError::Register(_))));
+        assert_eq!(registry.checks.len(), 1);
+        assert!(registry.checks.contains_key("existing"));
+        assert!(registry.plugins.is_empty());
+
+        registry
+            .register_plugin(&Partial { fail: false }, "partial", None)
+            .unwrap();
+        assert_eq!(registry.checks.len(), 3);
+        assert_eq!(
+            registry.plugins[0].checks,
+            vec!["partial/first", "partial/second"]
+        );
+    }
+}
@@ -1,12 +1,15 @@
 use std::str::FromStr;
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 use crate::Override;
-#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Copy, Clone, Serialize, Deserialize, Hash)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[serde(rename_all = "UPPERCASE")]
 /// A severity level for a single check subresult
+///
+/// Status codes are ordered by severity; see [StatusCode::rank].
 pub enum StatusCode {
     /// Skip: the check didn't run because some condition was not met
     Skip,
@@ -26,6 +29,41 @@ pub enum StatusCode {
     /// other words, it's something so bad there's no point continuing
     /// with the check; it's equivalent to a Fontbakery FATAL.
     Error,
+    /// Waived: a problem which has been accepted in a waivers file
+    ///
+    /// This comes last so that the other variants keep their values, but
+    /// it is less severe than a warning.
+    Waived,
+}
+
+impl StatusCode {
+    /// The position of this status in order of severity
+    ///
+    /// A waived problem ranks between Info and Warn, so it never reaches a
+    /// failure threshold on its own.
+    fn rank(&self) -> u8 {
+        match self {
+            StatusCode::Skip => 0,
+            StatusCode::Pass => 1,
+            StatusCode::Info => 2,
+            StatusCode::Waived => 3,
+            StatusCode::Warn => 4,
+            StatusCode::Fail => 5,
+            StatusCode::Error => 6,
+        }
+    }
+}
+
+impl Ord for StatusCode {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for StatusCode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl FromStr for StatusCode {
@@ -35,6 +73,7 @@ impl FromStr for StatusCode {
         match s {
             "SKIP" => Ok(StatusCode::Skip),
             "INFO" => Ok(StatusCode::Info),
+            "WAIVED" => Ok(StatusCode::Waived),
             "PASS" => Ok(StatusCode::Pass),
             "WARN" => Ok(StatusCode::Warn),
             "FAIL" => Ok(StatusCode::Fail),
@@ -54,6 +93,7 @@ impl StatusCode {
             StatusCode::Skip,
             StatusCode::Info,
             StatusCode::Pass,
+            StatusCode::Waived,
             StatusCode::Warn,
             StatusCode::Fail,
             StatusCode::Error,
@@ -77,10 +117,31 @@ impl std::fmt::Display for StatusCode {
             StatusCode::Fail => write!(f, "FAIL"),
             StatusCode::Warn => write!(f, "WARN"),
             StatusCode::Info => write!(f, "INFO"),
+            StatusCode::Waived => write!(f, "WAIVED"),
             StatusCode::Error => write!(f, "ERROR"),
         }
     }
 }
+#[derive(Debug, Clone, Serialize)]
+/// Details of an override which matched a status
+///
+/// This is kept on the [Status] so that reporters can list every waiver
+/// which was in effect (or which has expired) during a run.
+pub struct AppliedOverride {
+    /// The severity which the check originally reported
+    pub original_severity: StatusCode,
+    /// The reason given for the override
+    pub reason: String,
+    /// Who accepted the override
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_by: Option<String>,
+    /// The last date on which the override applies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<NaiveDate>,
+    /// Whether the override had expired, and so was not applied
+    pub expired: bool,
+}
+
 #[derive(Debug, Clone, Serialize)]
 /// A status message from a check
 ///
@@ -99,6 +160,9 @@ pub struct Status {
     /// Additional metadata provided to the reporter
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
+    /// The override which matched this status, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overridden: Option<AppliedOverride>,
 }
 
 impl std::fmt::Display for Status {
@@ -110,6 +174,21 @@ impl std::fmt::Display for Status {
         if let Some(message) = self.message.as_ref() {
             write!(f, "{:}", message)?;
         }
+        if let Some(applied) = self.overridden.as_ref() {
+            if applied.expired {
+                write!(f, " (Override expired")?;
+            } else {
+                write!(f, " (Overridden from {}", applied.original_severity)?;
+            }
+            write!(f, ": {}", applied.reason)?;
+            if let Some(who) = applied.accepted_by.as_ref() {
+                write!(f, "; accepted by {}", who)?;
+            }
+            if let Some(until) = applied.until.as_ref() {
+                write!(f, "; until {}", until)?;
+            }
+            write!(f, ")")?;
+        }
         Ok(())
     }
 }
@@ -147,6 +226,7 @@ impl Status {
             code: None,
             severity: StatusCode::Pass,
             metadata: None,
+            overridden: None,
         }
     }
     /// Create a status with a fail severity
@@ -156,6 +236,7 @@ impl Status {
             code: Some(code.to_string()),
             severity: StatusCode::Fail,
             metadata: None,
+            overridden: None,
         }
     }
     /// Create a status with a warning severity
@@ -165,6 +246,7 @@ impl Status {
             code: Some(code.to_string()),
             severity: StatusCode::Warn,
             metadata: None,
+            overridden: None,
         }
     }
     /// Create a status with an info severity
@@ -174,6 +256,7 @@ impl Status {
             code: Some(code.to_string()),
             severity: StatusCode::Skip,
             metadata: None,
+            overridden: None,
         }
     }
     /// Create a status with an info severity
@@ -183,6 +266,7 @@ impl Status {
             code: Some(code.to_string()),
             severity: StatusCode::Info,
             metadata: None,
+            overridden: None,
         }
     }
     /// Create a status with an error severity
@@ -192,24 +276,43 @@ impl Status {
             code: code.map(|x| x.to_string()),
             severity: StatusCode::Error,
             metadata: None,
+            overridden: None,
         }
     }
 
     /// Apply an override to the status
     ///
     /// Overrides are provided by the profile or by the user's configuration file;
-    /// they are used to override the severity of a check result.
-    pub fn process_override(&mut self, overrides: &[Override]) {
+    /// they are used to override the severity of a check result. The first
+    /// override which matches the result's code, check and file and which
+    /// has not expired is applied. If only expired overrides match, the
+    /// original severity stands, but the expired override is recorded.
+    pub fn process_override(
+        &mut self,
+        overrides: &[Override],
+        check_id: &str,
+        filename: Option<&str>,
+        today: NaiveDate,
+    ) {
         let code = self.code.as_deref();
-        if let Some(override_) = overrides.iter().find(|x| Some(x.code.as_str()) == code) {
+        let mut matching = overrides
+            .iter()
+            .filter(|x| x.matches(code, check_id, filename) && x.matches_glyphs(self))
+            .peekable();
+        let Some(first) = matching.peek().copied() else {
+            return;
+        };
+        let override_ = matching.find(|x| !x.is_expired(today)).unwrap_or(first);
+        let expired = override_.is_expired(today);
+        self.overridden = Some(AppliedOverride {
+            original_severity: self.severity,
+            reason: override_.reason.clone(),
+            accepted_by: override_.accepted_by.clone(),
+            until: override_.until,
+            expired,
+        });
+        if !expired {
             self.severity = override_.status;
-            self.message = Some(format!(
-                "{} (Overriden: {})",
-                self.message
-                    .clone()
-                    .unwrap_or("No original message".to_string()),
-                override_.reason
-            ))
         }
     }
 }
@@ -0,0 +1,151 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use skrifa::setting::VariationSetting;
+
+use crate::{Check, CheckResult, Context, FileTypeConvert, TestableType, TTF};
+
+/// An entry in a check order: the section name, the testable, the check and its context
+type CheckOrderEntry<'t, 'r> = (String, &'t TestableType<'t>, &'r Check<'r>, Context);
+
+/// Expand a check order so that location-sensitive checks are run at every
+/// location of a variable font
+///
+/// Each location-sensitive check on a variable font is replaced by one run per
+/// location returned by [crate::TestFont::sweep_locations]; everything else is
+/// left alone. Use [merge_swept_results] on the results afterwards.
+pub fn sweep_check_order<'t, 'r>(
+    order: Vec<CheckOrderEntry<'t, 'r>>,
+) -> Vec<CheckOrderEntry<'t, 'r>> {
+    let mut locations: HashMap<PathBuf, Vec<Vec<VariationSetting>>> = HashMap::new();
+    let mut swept = vec![];
+    for (section, testable, check, context) in order.into_iter() {
+        let TestableType::Single(t) = testable else {
+            swept.push((section, testable, check, context));
+            continue;
+        };
+        if !check.flags.location_sensitive {
+            swept.push((section, testable, check, context));
+            continue;
+        }
+        let font_locations = locations.entry(t.filename.clone()).or_insert_with(|| {
+            TTF.from_testable(t)
+                .map(|f| f.sweep_locations())
+                .unwrap_or_default()
+        });
+        if font_locations.is_empty() {
+            swept.push((section, testable, check, context));
+            continue;
+        }
+        for location in font_locations.iter() {
+            let context = Context {
+                sweep_location: Some(location.clone()),
+                ..context.clone()
+            };
+            swept.push((section.clone(), testable, check, context));
+        }
+    }
+    swept
+}
+
+/// Do two check results have the same statuses?
+fn same_subresults(a: &CheckResult, b: &CheckResult) -> bool {
+    a.subresults.len() == b.subresults.len()
+        && a.subresults
+            .iter()
+            .zip(b.subresults.iter())
+            .all(|(x, y)| x.severity == y.severity && x.code == y.code && x.message == y.message)
+}
+
+/// Merge the results of a swept check order
+///
+/// Results which are identical at several locations are reported once, with
+/// their locations listed together; if a check gave the same result at every
+/// location of a font, its result carries no location at all.
+pub fn merge_swept_results(results: Vec<CheckResult>) -> Vec<CheckResult> {
+    let mut merged: Vec<CheckResult> = vec![];
+    let mut groups: HashMap<(String, Option<String>, Option<String>), Vec<usize>> = HashMap::new();
+    for result in results.into_iter() {
+        let Some(location) = result.location.as_ref() else {
+            merged.push(result);
+            continue;
+        };
+        let group = groups
+            .entry((
+                result.check_id.clone(),
+                result.filename.clone(),
+                result.section.clone(),
+            ))
+            .or_default();
+        if let Some(existing) = group
+            .iter()
+            .copied()
+            .find(|&ix| same_subresults(&merged[ix], &result))
+        {
+            let existing = &mut merged[existing];
+            existing.location = existing
+                .location
+                .as_ref()
+                .map(|locations| format!("{}, {}", locations, location));
+            existing.time += result.time;
+        } else {
+            group.push(merged.len());
+            merged.push(result);
+        }
+    }
+    for group in groups.values() {
+        if let [only] = group.as_slice() {
+            merged[*only].location = None;
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Status;
+
+    fn result_at(location: &str, status: Status) -> CheckResult {
+        CheckResult {
+            check_id: "outline_short_segments".to_string(),
+            check_name: "".to_string(),
+            check_rationale: "".to_string(),
+            filename: Some("Foo[wght].ttf".to_string()),
+            source_filename: None,
+            location: Some(location.to_string()),
+            section: None,
+            subresults: vec![status],
+            hotfix_result: None,
+            sourcefix_result: None,
+            time: Duration::default(),
+        }
+    }
+
+    #[test]
+    fn test_merge_swept_results() {
+        let merged = merge_swept_results(vec![
+            result_at("wght=100", Status::pass()),
+            result_at("wght=400", Status::pass()),
+            result_at("wght=900", Status::pass()),
+        ]);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].location, None);
+        assert_eq!(merged[0].filename_and_location().unwrap(), "Foo[wght].ttf");
+
+        let short = || Status::warn("found-short-segments", "a contains a short segment");
+        let merged = merge_swept_results(vec![
+            result_at("wght=100", Status::pass()),
+            result_at("wght=400", Status::pass()),
+            result_at("wght=900", short()),
+        ]);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].location.as_deref(), Some("wght=100, wght=400"));
+        assert_eq!(
+            merged[1].filename_and_location().unwrap(),
+            "Foo[wght].ttf @ wght=900"
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::{profile::deserialize_date, CheckId, Override, StatusCode, TestableType};
+
+/// The name of the file, next to the fonts or their sources, which holds waivers
+pub const WAIVERS_FILENAME: &str = ".fontspector-waivers.toml";
+
+#[derive(Deserialize, Debug, Clone)]
+/// An accepted exception to a check result, recorded alongside the font sources
+///
+/// Waivers are applied like [Override]s, but the result is reported with a
+/// status of `WAIVED` rather than being passed.
+pub struct Waiver {
+    /// The check this waiver applies to
+    pub check: CheckId,
+    /// The result code being waived
+    pub code: String,
+    /// Only waive results for files (or family directories) matching this glob
+    #[serde(default)]
+    pub files: Option<String>,
+    /// Only waive results about glyphs matching this glob
+    ///
+    /// See [Override::matches_glyphs] for how glyph names are found.
+    #[serde(default)]
+    pub glyphs: Option<String>,
+    /// Why the result is being waived
+    pub reason: String,
+    /// Who accepted the waiver
+    #[serde(default)]
+    pub accepted_by: Option<String>,
+    /// The last date on which the waiver applies
+    #[serde(default, deserialize_with = "deserialize_date")]
+    pub until: Option<NaiveDate>,
+}
+
+impl From<Waiver> for Override {
+    fn from(waiver: Waiver) -> Self {
+        Override {
+            code: waiver.code,
+            status: StatusCode::Waived,
+            reason: waiver.reason,
+            check: Some(waiver.check),
+            files: waiver.files,
+            glyphs: waiver.glyphs,
+            until: waiver.until,
+            accepted_by: waiver.accepted_by,
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+/// The contents of a waivers file
+struct WaiverFile {
+    /// The waivers, written as `[[waiver]]` tables
+    #[serde(default, rename = "waiver")]
+    waivers: Vec<Waiver>,
+}
+
+/// Parse the contents of a waivers file
+pub fn parse_waivers(toml: &str) -> Result<Vec<Waiver>, toml::de::Error> {
+    toml::from_str::<WaiverFile>(toml).map(|file| file.waivers)
+}
+
+/// The directories in which to look for waivers for a testable
+///
+/// This is the directory containing each font, and the directory containing
+/// its source file, if known. For a collection, it is the family directory.
+fn waiver_directories(testable: &TestableType) -> Vec<PathBuf> {
+    let mut directories: Vec<PathBuf> = vec![];
+    match testable {
+        TestableType::Single(t) => {
+            if let Some(parent) = t.filename.parent() {
+                directories.push(parent.to_path_buf());
+            }
+            if let Some(parent) = t.source.as_deref().and_then(Path::parent) {
+                directories.push(parent.to_path_buf());
+            }
+        }
+        TestableType::Collection(c) => directories.push(PathBuf::from(&c.directory)),
+    }
+    directories.dedup();
+    directories
+}
+
+/// Read the waivers file in a directory, if there is one, as overrides
+///
+/// A waivers file which cannot be parsed is reported and ignored.
+pub fn waivers_in(directory: &Path) -> Vec<Override> {
+    let path = directory.join(WAIVERS_FILENAME);
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return vec![];
+    };
+    match parse_waivers(&contents) {
+        Ok(waivers) => waivers.into_iter().map(Override::from).collect(),
+        Err(e) => {
+            log::warn!("Could not parse waivers file {:?}: {}", path, e);
+            vec![]
+        }
+    }
+}
+
+/// Find any waivers which apply to a testable, as overrides
+///
+/// These are read from the directory of each font and of its source; see
+/// [waivers_in].
+pub fn discover_waivers(testable: &TestableType) -> Vec<Override> {
+    waiver_directories(testable)
+        .iter()
+        .flat_map(|directory| waivers_in(directory))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_parse_waivers() {
+        let waivers = parse_waivers(
+            r#"
+            [[waiver]]
+            check = "contour_count"
+            code = "contour-count"
+            files = "*-Italic.ttf"
+            reason = "The italic a is single-storey"
+            accepted_by = "Jane Smith"
+            until = 2025-06-30
+
+            [[waiver]]
+            check = "legacy_accents"
+            code = "legacy-accents-width"
+            reason = "Intentional"
+            until = "2025-01-01"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(waivers.len(), 2);
+        assert_eq!(
+            waivers[0].until,
+            Some(NaiveDate::from_ymd_opt(2025, 6, 30).unwrap())
+        );
+        assert_eq!(
+            waivers[1].until,
+            Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())
+        );
+
+        let override_ = Override::from(waivers[0].clone());
+        assert_eq!(override_.status, StatusCode::Waived);
+        assert!(override_.matches(
+            Some("contour-count"),
+            "contour_count",
+            Some("fonts/Family-Italic.ttf")
+        ));
+        assert!(!override_.matches(
+            Some("contour-count"),
+            "contour_count",
+            Some("fonts/Family-Regular.ttf")
+        ));
+    }
+
+    #[test]
+    fn test_glyph_waivers() {
+        let waivers = parse_waivers(
+            r#"
+            [[waiver]]
+            check = "alt_caron"
+            code = "decomposed-outline"
+            glyphs = "?caron"
+            reason = "Slovak carons are drawn by hand"
+            "#,
+        )
+        .unwrap();
+        let overrides: Vec<Override> = waivers.into_iter().map(Override::from).collect();
+        let today = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+
+        let mut dcaron = crate::Status::warn(
+            "decomposed-outline",
+            "dcaron is decomposed and therefore could not be checked.",
+        );
+        dcaron.process_override(&overrides, "alt_caron", None, today);
+        assert_eq!(dcaron.severity, StatusCode::Waived);
+
+        let mut ecaron_sc = crate::Status::warn(
+            "decomposed-outline",
+            "ecaron.sc is decomposed and therefore could not be checked.",
+        );
+        ecaron_sc.process_override(&overrides, "alt_caron", None, today);
+        assert_eq!(ecaron_sc.severity, StatusCode::Warn);
+
+        let mut tcaron = crate::Status::warn("decomposed-outline", "Bad outline");
+        tcaron.metadata = Some(serde_json::json!({"glyphs": ["tcaron"]}));
+        tcaron.process_override(&overrides, "alt_caron", None, today);
+        assert_eq!(tcaron.severity, StatusCode::Waived);
+    }
+}
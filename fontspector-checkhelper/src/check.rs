@@ -2,9 +2,14 @@ use std::str::FromStr;
 
 use darling::{ast::NestedMeta, Error, FromMeta};
 use proc_macro::TokenStream;
-use proc_macro2::Span;
+use proc_macro2::{Delimiter, Span, TokenStream as TokenStream2, TokenTree};
 use quote::quote;
-use syn::{parse_macro_input, Ident, ItemFn};
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Ident, ItemFn, LitStr, Token,
+};
 
 #[derive(Default)]
 enum Implementation {
@@ -35,6 +40,100 @@ impl FromMeta for Implementation {
     }
 }
 
+/// A list of tags, written as an array of string literals: `tags = ["a", "b"]`
+#[derive(Default)]
+struct Tags(Vec<String>);
+
+impl FromMeta for Tags {
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        match expr {
+            syn::Expr::Array(array) => array
+                .elems
+                .iter()
+                .map(|elem| match elem {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }) => Ok(s.value()),
+                    _ => Err(Error::unexpected_expr_type(elem)),
+                })
+                .collect::<darling::Result<Vec<_>>>()
+                .map(Tags),
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(s),
+                ..
+            }) => Ok(Tags(vec![s.value()])),
+            _ => Err(Error::unexpected_expr_type(expr)),
+        }
+    }
+}
+
+/// A single entry in a result code catalogue: `"bad-entries": WARN`
+struct ResultCodeDecl {
+    code: LitStr,
+    severity: Ident,
+}
+
+impl Parse for ResultCodeDecl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let code: LitStr = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let severity: Ident = input.parse()?;
+        Ok(ResultCodeDecl { code, severity })
+    }
+}
+
+impl ResultCodeDecl {
+    fn status_code(&self) -> syn::Result<proc_macro2::TokenStream> {
+        match self.severity.to_string().as_str() {
+            "SKIP" => Ok(quote!(StatusCode::Skip)),
+            "PASS" => Ok(quote!(StatusCode::Pass)),
+            "INFO" => Ok(quote!(StatusCode::Info)),
+            "WAIVED" => Ok(quote!(StatusCode::Waived)),
+            "WARN" => Ok(quote!(StatusCode::Warn)),
+            "FAIL" => Ok(quote!(StatusCode::Fail)),
+            "ERROR" => Ok(quote!(StatusCode::Error)),
+            _ => Err(syn::Error::new(
+                self.severity.span(),
+                "expected one of SKIP, PASS, INFO, WAIVED, WARN, FAIL or ERROR",
+            )),
+        }
+    }
+}
+
+/// Pull the `codes = { ... }` catalogue out of the macro arguments
+///
+/// A brace-delimited map isn't valid attribute syntax as far as darling is
+/// concerned, so we parse it ourselves and hand the rest of the arguments on.
+fn extract_codes(args: TokenStream2) -> syn::Result<(TokenStream2, Vec<ResultCodeDecl>)> {
+    let tokens: Vec<TokenTree> = args.into_iter().collect();
+    let mut remaining = vec![];
+    let mut codes = vec![];
+    let mut i = 0;
+    while i < tokens.len() {
+        if let (
+            TokenTree::Ident(ident),
+            Some(TokenTree::Punct(eq)),
+            Some(TokenTree::Group(group)),
+        ) = (&tokens[i], tokens.get(i + 1), tokens.get(i + 2))
+        {
+            if ident == "codes" && eq.as_char() == '=' && group.delimiter() == Delimiter::Brace {
+                let parser = Punctuated::<ResultCodeDecl, Token![,]>::parse_terminated;
+                codes.extend(parser.parse2(group.stream())?);
+                i += 3;
+                // Swallow the separating comma, if any
+                if matches!(tokens.get(i), Some(TokenTree::Punct(p)) if p.as_char() == ',') {
+                    i += 1;
+                }
+                continue;
+            }
+        }
+        remaining.push(tokens[i].clone());
+        i += 1;
+    }
+    Ok((remaining.into_iter().collect(), codes))
+}
+
 fn dedent_and_unwrap_rationale(rationale: &str) -> String {
     let mut new_rationale = String::new();
     let paras = rationale.split("\n\n");
@@ -81,11 +180,21 @@ struct CheckParams {
     hotfix: Option<Ident>,
     fix_source: Option<Ident>,
     metadata: Option<String>,
+    #[darling(default)]
+    tags: Tags,
+    #[darling(default)]
+    experimental: bool,
+    #[darling(default)]
+    location_sensitive: bool,
 }
 
 pub(crate) fn check_impl(args: TokenStream, input: TokenStream) -> TokenStream {
+    let (args, codes) = match extract_codes(args.into()) {
+        Ok(v) => v,
+        Err(e) => return e.to_compile_error().into(),
+    };
     // Parse argument tokens as a list of NestedMeta items
-    let attr_args = match NestedMeta::parse_meta_list(args.into()) {
+    let attr_args = match NestedMeta::parse_meta_list(args) {
         Ok(v) => v,
         Err(e) => {
             // Write error to output token stream if there is one
@@ -153,7 +262,29 @@ pub(crate) fn check_impl(args: TokenStream, input: TokenStream) -> TokenStream {
         Some(metadata) => quote!(Some(&#metadata)),
         None => quote!(None),
     };
-    let doc_string = format!(
+    let tag_items: Vec<syn::LitStr> = params
+        .tags
+        .0
+        .iter()
+        .map(|tag| syn::LitStr::new(tag, Span::call_site()))
+        .collect();
+    let tags = quote!(&[#(#tag_items),*]);
+    let experimental = params.experimental;
+    let location_sensitive = params.location_sensitive;
+    let mut code_items = vec![];
+    for decl in codes.iter() {
+        let severity = match decl.status_code() {
+            Ok(severity) => severity,
+            Err(e) => return e.to_compile_error().into(),
+        };
+        let code = &decl.code;
+        code_items.push(quote!(ResultCode { code: #code, severity: #severity }));
+    }
+    let codes_table = codes
+        .iter()
+        .map(|decl| format!("- `{}`: {}\n", decl.code.value(), decl.severity))
+        .collect::<String>();
+    let mut doc_string = format!(
         "`{}`: {}\n\n{}\n\n## Proposal\n\n{}",
         id.value(),
         title.value(),
@@ -165,6 +296,10 @@ pub(crate) fn check_impl(args: TokenStream, input: TokenStream) -> TokenStream {
             .collect::<Vec<_>>()
             .join(", ")
     );
+    if !codes_table.is_empty() {
+        doc_string.push_str("\n\n## Result codes\n\n");
+        doc_string.push_str(&codes_table);
+    }
     quote!(
         #(#attrs)*
         #vis #new_sig {
@@ -182,7 +317,9 @@ pub(crate) fn check_impl(args: TokenStream, input: TokenStream) -> TokenStream {
             implementation: #implementation,
             hotfix: #hotfix,
             fix_source: #fix_source,
-            flags: CheckFlags::default(),
+            flags: CheckFlags { experimental: #experimental, location_sensitive: #location_sensitive },
+            tags: #tags,
+            codes: &[#(#code_items),*],
             _metadata: #metadata,
         };
     )
@@ -1,4 +1,4 @@
-use clap::{ArgAction, Parser};
+use clap::{ArgAction, Parser, Subcommand};
 use fontspector_checkapi::StatusCode;
 
 /// Quality control for OpenType fonts
@@ -9,6 +9,14 @@ pub struct Args {
     #[clap(long, value_delimiter = ',')]
     pub plugins: Vec<String>,
 
+    /// Sandboxed WebAssembly plugins to load
+    #[clap(long, value_delimiter = ',')]
+    pub wasm_plugins: Vec<String>,
+
+    /// List the loaded plugins and what each of them provides
+    #[clap(long)]
+    pub list_plugins: bool,
+
     /// Profile to check
     #[clap(short, long, default_value = "universal")]
     pub profile: String,
@@ -37,6 +45,23 @@ pub struct Args {
     #[clap(short = 'x', long)]
     pub exclude_checkid: Option<Vec<String>>,
 
+    /// Only run checks carrying one of these tags (e.g. outline, naming, variable)
+    #[clap(long)]
+    pub tag: Option<Vec<String>>,
+
+    /// Exclude checks carrying any of these tags (e.g. network, slow)
+    #[clap(long)]
+    pub exclude_tag: Option<Vec<String>>,
+
+    /// Also run checks marked as experimental (they run anyway when selected with -c)
+    #[clap(long)]
+    pub include_experimental: bool,
+
+    /// Run location-sensitive checks (e.g. outline checks) at every named instance,
+    /// master and axis extreme of variable fonts
+    #[clap(long)]
+    pub sweep_instances: bool,
+
     /// Report full lists of items instead of abbreviated lists
     #[clap(long)]
     pub full_lists: bool,
@@ -90,6 +115,12 @@ pub struct Args {
     #[clap(long, help_heading = "Reports")]
     pub html: Option<String>,
 
+    /// Write a report of the languages, Unicode blocks and glyphsets each font supports
+    /// to the given filename, instead of running checks. JSON if the filename ends in
+    /// `.json`, otherwise Markdown.
+    #[clap(long, help_heading = "Reports")]
+    pub language_report: Option<String>,
+
     /// Copy bundled templates to user template directory
     #[clap(long, help_heading = "Reports")]
     pub update_templates: bool,
@@ -99,6 +130,11 @@ pub struct Args {
     #[cfg(feature = "python")]
     pub use_python: bool,
 
+    /// Python modules (e.g. `mychecks.outlines`) containing fontbakery-style checks to load
+    #[clap(long, value_delimiter = ',')]
+    #[cfg(feature = "python")]
+    pub python_checks: Vec<String>,
+
     /// Hotfix found problems in the binaries
     #[clap(long, help_heading = "Fix problems")]
     pub hotfix: bool,
@@ -107,6 +143,71 @@ pub struct Args {
     #[clap(long, help_heading = "Fix problems")]
     pub fix_sources: bool,
 
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+
     /// Input files
     pub inputs: Vec<String>,
 }
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Inspect and lint profiles
+    Profile {
+        #[clap(subcommand)]
+        action: ProfileCommand,
+    },
+    /// Explain what a check does, and where it is used
+    Explain {
+        /// The check ID
+        #[clap(required_unless_present = "all")]
+        check_id: Option<String>,
+        /// Explain every check
+        #[clap(long)]
+        all: bool,
+        /// Output JSON instead of Markdown
+        #[clap(long)]
+        json: bool,
+        /// Write a documentation site for every check into this directory
+        #[clap(long, requires = "all")]
+        output: Option<String>,
+    },
+    /// Work with shaping regression tests
+    Shaping {
+        #[clap(subcommand)]
+        action: ShapingCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ProfileCommand {
+    /// Check a profile file for problems, and print it with its included profiles resolved
+    Lint {
+        /// The profile TOML file
+        file: String,
+    },
+    /// Print a known profile, with its included profiles resolved
+    Show {
+        /// The name of the profile
+        name: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ShapingCommand {
+    /// Record the shaping of known-good fonts as the expectations of the shaping tests
+    Record {
+        /// The known-good fonts; with several, expectations are recorded for each font
+        #[clap(required = true)]
+        fonts: Vec<String>,
+        /// Directory of shaping test files [default: `shaping.test_directory` from the configuration file]
+        #[clap(long)]
+        test_directory: Option<String>,
+        /// Include clusters and positions in expectations for tests which don't have one yet
+        #[clap(long)]
+        positions: bool,
+        /// Report the changes without writing them to the test files
+        #[clap(long)]
+        dry_run: bool,
+    },
+}
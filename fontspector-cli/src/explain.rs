@@ -0,0 +1,340 @@
+//! Documentation for checks, drawn from the registry
+
+use std::path::Path;
+
+use fontspector_checkapi::{Check, Registry};
+use serde_json::{json, Map, Value};
+
+/// The profiles and sections which include a check, sorted by profile name
+fn check_usage(registry: &Registry, check_id: &str) -> Vec<(String, String)> {
+    let mut usage = vec![];
+    for (name, profile) in registry.iter_profiles() {
+        for (section, checks) in profile.resolved(registry).sections.iter() {
+            if checks.iter().any(|id| id == check_id) {
+                usage.push((name.clone(), section.clone()));
+            }
+        }
+    }
+    usage.sort();
+    usage
+}
+
+/// The configuration defaults each profile gives a check, sorted by profile name
+fn configuration_defaults(
+    registry: &Registry,
+    check_id: &str,
+) -> Vec<(String, Map<String, Value>)> {
+    let mut defaults: Vec<_> = registry
+        .iter_profiles()
+        .map(|(name, profile)| (name.clone(), profile.defaults(check_id)))
+        .filter(|(_, defaults)| !defaults.is_empty())
+        .map(|(name, defaults)| (name, defaults.into_iter().collect()))
+        .collect();
+    defaults.sort_by(|a, b| a.0.cmp(&b.0));
+    defaults
+}
+
+/// Describe a check as JSON
+pub fn explain_json(registry: &Registry, check: &Check) -> Value {
+    json!({
+        "id": check.id,
+        "title": check.title,
+        "rationale": check.rationale,
+        "proposal": check.proposal,
+        "applies_to": check.applies_to,
+        "runs_on_collection": check.runs_on_collection(),
+        "experimental": check.flags.experimental,
+        "location_sensitive": check.flags.location_sensitive,
+        "tags": check.tags,
+        "profiles": check_usage(registry, check.id)
+            .into_iter()
+            .map(|(profile, section)| json!({"profile": profile, "section": section}))
+            .collect::<Vec<_>>(),
+        "configuration_defaults": configuration_defaults(registry, check.id)
+            .into_iter()
+            .map(|(profile, defaults)| (profile, Value::Object(defaults)))
+            .collect::<Map<_, _>>(),
+        "hotfix": check.hotfix.is_some(),
+        "fix_source": check.fix_source.is_some(),
+        "codes": check
+            .codes
+            .iter()
+            .map(|rc| (rc.code.to_string(), json!(rc.severity)))
+            .collect::<Map<_, _>>(),
+    })
+}
+
+/// Describe a check as Markdown
+pub fn explain_markdown(registry: &Registry, check: &Check) -> String {
+    let mut md = format!("# {}\n\n`{}`", check.title, check.id);
+    if check.flags.experimental {
+        md.push_str(" *(experimental)*");
+    }
+    md.push_str(&format!("\n\n{}\n\n", check.rationale.trim()));
+
+    let mut details = vec![format!(
+        "Applies to **{}** files{}",
+        check.applies_to,
+        if check.runs_on_collection() {
+            ", as a family"
+        } else {
+            ""
+        }
+    )];
+    if !check.tags.is_empty() {
+        details.push(format!("Tags: {}", check.tags.join(", ")));
+    }
+    if check.flags.location_sensitive {
+        details
+            .push("Runs at every instance of a variable font with `--sweep-instances`".to_string());
+    }
+    details.push(
+        match (check.hotfix.is_some(), check.fix_source.is_some()) {
+            (true, true) => "Can fix font binaries (`--hotfix`) and sources (`--fix-sources`)",
+            (true, false) => "Can fix font binaries (`--hotfix`)",
+            (false, true) => "Can fix font sources (`--fix-sources`)",
+            (false, false) => "No automatic fixes",
+        }
+        .to_string(),
+    );
+    for detail in details {
+        md.push_str(&format!("* {}\n", detail));
+    }
+
+    if !check.proposal.is_empty() {
+        md.push_str("\n## Proposed in\n\n");
+        for proposal in check.proposal {
+            md.push_str(&format!("* {}\n", proposal));
+        }
+    }
+
+    let usage = check_usage(registry, check.id);
+    md.push_str("\n## Profiles\n\n");
+    if usage.is_empty() {
+        md.push_str("Not included in any profile\n");
+    }
+    for (profile, section) in usage {
+        md.push_str(&format!("* **{}**, in section *{}*\n", profile, section));
+    }
+
+    let defaults = configuration_defaults(registry, check.id);
+    if !defaults.is_empty() {
+        md.push_str("\n## Configuration defaults\n\n");
+        for (profile, values) in defaults {
+            for (key, value) in values {
+                md.push_str(&format!("* `{}` = `{}` (**{}**)\n", key, value, profile));
+            }
+        }
+    }
+
+    if !check.codes.is_empty() {
+        md.push_str("\n## Result codes\n\n|Code|Default status|\n|:-|:-|\n");
+        for rc in check.codes {
+            md.push_str(&format!("|`{}`|{}|\n", rc.code, rc.severity));
+        }
+    }
+    md
+}
+
+/// The file name of a check's page in the documentation site
+fn page_name(check: &Check) -> String {
+    format!("{}.html", check.id.replace('/', "_"))
+}
+
+/// Escape text for inclusion in HTML
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Wrap a Markdown document in an HTML page
+///
+/// If the Markdown cannot be rendered, it is shown as preformatted text.
+fn html_page(title: &str, markdown: &str) -> String {
+    let body = markdown::to_html_with_options(markdown, &markdown::Options::gfm())
+        .unwrap_or_else(|_| format!("<pre>{}</pre>", escape_html(markdown)));
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}\n</body>\n</html>\n",
+        escape_html(title),
+        body
+    )
+}
+
+/// Write a static documentation site for all checks into a directory
+pub fn write_site(registry: &Registry, directory: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(directory)?;
+    let mut checks: Vec<&Check> = registry.iter().collect();
+    checks.sort_by_key(|check| check.id);
+    let mut index = "# Fontspector checks\n\n".to_string();
+    for check in checks {
+        index.push_str(&format!(
+            "* [`{}`]({}): {}\n",
+            check.id,
+            page_name(check),
+            check.title
+        ));
+        std::fs::write(
+            directory.join(page_name(check)),
+            html_page(check.id, &explain_markdown(registry, check)),
+        )?;
+    }
+    std::fs::write(
+        directory.join("index.html"),
+        html_page("Fontspector checks", &index),
+    )
+}
+
+/// Explain one or all checks, then exit
+pub fn explain_command(
+    registry: &Registry,
+    check_id: Option<&str>,
+    all: bool,
+    as_json: bool,
+    output: Option<&str>,
+) -> ! {
+    let mut checks: Vec<&Check> = if all {
+        registry.iter().collect()
+    } else if let Some(check_id) = check_id {
+        match registry.checks.get(check_id) {
+            Some(check) => vec![check],
+            None => {
+                log::error!("Unknown check {}", check_id);
+                let similar: Vec<&str> = registry
+                    .iter()
+                    .map(|check| check.id)
+                    .filter(|id| id.contains(check_id))
+                    .collect();
+                if !similar.is_empty() {
+                    eprintln!("Did you mean one of: {}?", similar.join(", "));
+                }
+                std::process::exit(1);
+            }
+        }
+    } else {
+        log::error!("Give a check ID to explain, or --all");
+        std::process::exit(1);
+    };
+    checks.sort_by_key(|check| check.id);
+
+    if let Some(output) = output {
+        if let Err(e) = write_site(registry, Path::new(output)) {
+            log::error!("Could not write documentation to {}: {}", output, e);
+            std::process::exit(1);
+        }
+    } else if as_json {
+        let explanations: Vec<Value> = checks
+            .iter()
+            .map(|check| explain_json(registry, check))
+            .collect();
+        let value = if all {
+            Value::Array(explanations)
+        } else {
+            explanations.into_iter().next().unwrap_or_default()
+        };
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&value).unwrap_or_default()
+        );
+    } else {
+        for check in checks {
+            termimad::print_text(&explain_markdown(registry, check));
+        }
+    }
+    std::process::exit(0);
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        CheckFlags, CheckFnResult, CheckImplementation, Context, ProfileBuilder, ResultCode,
+        Status, StatusCode, Testable,
+    };
+
+    fn nothing(_t: &Testable, _c: &Context) -> CheckFnResult {
+        Ok(Status::just_one_pass())
+    }
+
+    const EXAMPLE: Check = Check {
+        id: "example/widths",
+        title: "Check <widths> & spacing",
+        rationale: "Glyphs should be wide enough.",
+        proposal: &["https://github.com/fonttools/fontspector/issues/1"],
+        implementation: CheckImplementation::CheckOne(&nothing),
+        hotfix: None,
+        fix_source: None,
+        applies_to: "TTF",
+        flags: CheckFlags {
+            experimental: true,
+            location_sensitive: false,
+        },
+        tags: &["outline"],
+        codes: &[ResultCode {
+            code: "too-narrow",
+            severity: StatusCode::Warn,
+        }],
+        _metadata: None,
+    };
+
+    fn registry() -> Registry<'static> {
+        let mut registry = Registry::new();
+        ProfileBuilder::new()
+            .add_section("Outlines")
+            .add_and_register_check(EXAMPLE)
+            .with_configuration_defaults(
+                "example/widths",
+                [("minimum".to_string(), json!(100))].into_iter().collect(),
+            )
+            .build("example", &mut registry)
+            .unwrap();
+        registry
+    }
+
+    #[test]
+    fn test_explain_json() {
+        let registry = registry();
+        let check = registry.checks.get("example/widths").unwrap();
+        let explanation = explain_json(&registry, check);
+        assert_eq!(explanation["id"], "example/widths");
+        assert_eq!(explanation["experimental"], true);
+        assert_eq!(explanation["runs_on_collection"], false);
+        assert_eq!(explanation["tags"], json!(["outline"]));
+        assert_eq!(
+            explanation["profiles"],
+            json!([{"profile": "example", "section": "Outlines"}])
+        );
+        assert_eq!(
+            explanation["configuration_defaults"],
+            json!({"example": {"minimum": 100}})
+        );
+        assert_eq!(explanation["codes"], json!({"too-narrow": "WARN"}));
+    }
+
+    #[test]
+    fn test_explain_markdown() {
+        let registry = registry();
+        let check = registry.checks.get("example/widths").unwrap();
+        let markdown = explain_markdown(&registry, check);
+        assert!(markdown.starts_with(
+            "# Check <widths> & spacing\n\n`example/widths` *(experimental)*\n\nGlyphs should be wide enough.\n\n"
+        ));
+        assert!(markdown.contains("* Applies to **TTF** files\n"));
+        assert!(markdown.contains("* Tags: outline\n"));
+        assert!(markdown.contains("* No automatic fixes\n"));
+        assert!(markdown
+            .contains("## Proposed in\n\n* https://github.com/fonttools/fontspector/issues/1\n"));
+        assert!(markdown.contains("* **example**, in section *Outlines*\n"));
+        assert!(markdown.contains("* `minimum` = `100` (**example**)\n"));
+        assert!(markdown.contains("|`too-narrow`|WARN|\n"));
+    }
+
+    #[test]
+    fn test_html_page_escapes_title() {
+        let page = html_page(EXAMPLE.title, "Some *text*");
+        assert!(page.contains("<title>Check &lt;widths&gt; &amp; spacing</title>"));
+        assert!(page.contains("<em>text</em>"));
+    }
+}
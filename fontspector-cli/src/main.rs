@@ -2,6 +2,7 @@
 //! Quality control for OpenType fonts
 
 mod args;
+mod explain;
 mod reporters;
 
 use std::{
@@ -11,20 +12,32 @@ use std::{
     time::{Duration, Instant},
 };
 
-use args::Args;
+use args::{Args, Command, ProfileCommand, ShapingCommand};
 use clap::Parser;
+use colored::Colorize;
 
 #[cfg(feature = "python")]
 use fontbakery_bridge::FontbakeryBridge;
 
 use fontspector_checkapi::{
-    Check, CheckResult, Context, FixResult, HotfixFunction, Override, Plugin, Profile, Registry,
-    StatusCode, Testable, TestableCollection, TestableType,
+    merge_swept_results, sweep_check_order, Check, CheckResult, CheckSelection, Context, FixResult,
+    Glyphset, HotfixFunction, Override, Plugin, PluginError, PluginInfo, Profile, Registry,
+    StatusCode, Testable, TestableCollection, TestableType, TTF,
 };
+use fontspector_wasmplugin::WasmPlugin;
 use itertools::Either;
+#[cfg(feature = "adobe")]
+use profile_adobe::Adobe;
+#[cfg(feature = "designspace")]
+use profile_designspace::Designspace;
+use profile_fontwerk::Fontwerk;
 use profile_googlefonts::GoogleFonts;
 use profile_iso15008::Iso15008;
+#[cfg(feature = "microsoft")]
+use profile_microsoft::Microsoft;
+use profile_notofonts::NotoFonts;
 use profile_opentype::OpenType;
+use profile_typenetwork::TypeNetwork;
 use profile_universal::Universal;
 use reporters::{process_reporter_args, terminal::TerminalReporter, Reporter, RunResults};
 use serde_json::{json, Map};
@@ -100,11 +113,97 @@ fn main() {
         .register(&mut registry)
         .expect("Couldn't register iso15008 profile, fontspector bug");
 
+    #[allow(clippy::expect_used)] // If this fails, I *want* to panic
+    Fontwerk
+        .register(&mut registry)
+        .expect("Couldn't register fontwerk profile, fontspector bug");
+
+    #[allow(clippy::expect_used)] // If this fails, I *want* to panic
+    NotoFonts
+        .register(&mut registry)
+        .expect("Couldn't register notofonts profile, fontspector bug");
+
+    #[allow(clippy::expect_used)] // If this fails, I *want* to panic
+    TypeNetwork
+        .register(&mut registry)
+        .expect("Couldn't register typenetwork profile, fontspector bug");
+
+    #[cfg(feature = "microsoft")]
+    #[allow(clippy::expect_used)] // If this fails, I *want* to panic
+    Microsoft
+        .register(&mut registry)
+        .expect("Couldn't register microsoft profile, fontspector bug");
+
+    #[cfg(feature = "adobe")]
+    #[allow(clippy::expect_used)] // If this fails, I *want* to panic
+    Adobe
+        .register(&mut registry)
+        .expect("Couldn't register adobe profile, fontspector bug");
+
+    #[cfg(feature = "designspace")]
+    #[allow(clippy::expect_used)] // If this fails, I *want* to panic
+    Designspace
+        .register(&mut registry)
+        .expect("Couldn't register designspace profile, fontspector bug");
+
     for plugin_path in args.plugins.iter() {
-        if let Err(err) = registry.load_plugin(plugin_path) {
-            log::error!("Could not load plugin {:}: {:}", plugin_path, err);
+        match registry.load_plugin(plugin_path) {
+            Ok(info) => log::info!(
+                "Loaded plugin {}: {} checks, {} profiles, {} file types",
+                plugin_path,
+                info.checks.len(),
+                info.profiles.len(),
+                info.filetypes.len()
+            ),
+            Err(err) => log::error!("Could not load plugin {:}: {:}", plugin_path, err),
         }
     }
+    #[cfg(feature = "python")]
+    for module in args.python_checks.iter() {
+        match fontbakery_bridge::register_python_module(module, &mut registry) {
+            Ok(checks) => log::info!("Loaded {} Python checks from {}", checks.len(), module),
+            Err(err) => log::error!("{}", err),
+        }
+    }
+    for plugin_path in args.wasm_plugins.iter() {
+        match load_wasm_plugin(&mut registry, plugin_path) {
+            Ok(info) => log::info!(
+                "Loaded WebAssembly plugin {}: {} checks, {} profiles, {} file types",
+                plugin_path,
+                info.checks.len(),
+                info.profiles.len(),
+                info.filetypes.len()
+            ),
+            Err(err) => log::error!("Could not load plugin {:}: {:}", plugin_path, err),
+        }
+    }
+
+    if args.list_plugins {
+        list_plugins(&registry);
+        std::process::exit(0);
+    }
+
+    match &args.command {
+        Some(Command::Profile { action }) => profile_command(&registry, action),
+        Some(Command::Explain {
+            check_id,
+            all,
+            json,
+            output,
+        }) => explain::explain_command(
+            &registry,
+            check_id.as_deref(),
+            *all,
+            *json,
+            output.as_deref(),
+        ),
+        Some(Command::Shaping { action }) => shaping_command(&args, action),
+        None => {}
+    }
+
+    if let Some(filename) = &args.language_report {
+        language_report(&args, filename);
+    }
 
     // Load the relevant profile - maybe it's a file?
     let profile_name = if args.profile.ends_with(".toml") {
@@ -146,13 +245,29 @@ fn main() {
         std::process::exit(1);
     });
 
+    let selection = check_selection(&args);
+
     if args.list_checks || args.list_checks_json {
         let mut checks_per_section = HashMap::new();
         for (section, checks) in profile.sections.iter() {
             let checks: Vec<_> = checks
                 .iter()
                 .flat_map(|check| registry.checks.get(check))
-                .map(|check| json!({ "id": check.id, "title": check.title }))
+                .filter(|check| selection.selects(check))
+                .map(|check| {
+                    json!({
+                        "id": check.id,
+                        "title": check.title,
+                        "tags": check.tags,
+                        "codes": check
+                            .codes
+                            .iter()
+                            .map(|rc| (rc.code.to_string(), json!(rc.severity)))
+                            .collect::<Map<_, _>>(),
+                        "experimental": check.flags.experimental,
+                        "location_sensitive": check.flags.location_sensitive,
+                    })
+                })
                 .collect();
             if checks.is_empty() {
                 continue;
@@ -202,12 +317,11 @@ fn main() {
 
     // Load configuration
     let configuration: Map<String, serde_json::Value> = load_configuration(&args);
-    let overrides = load_overrides(&configuration);
+    let overrides = load_overrides(&configuration, &registry);
 
     // Establish a check order
-    let checkorder: Vec<(String, &TestableType, &Check, Context)> = profile.check_order(
-        &args.checkid,
-        &args.exclude_checkid,
+    let mut checkorder: Vec<(String, &TestableType, &Check, Context)> = profile.check_order(
+        &selection,
         &registry,
         Context {
             skip_network: args.skip_network,
@@ -217,10 +331,15 @@ fn main() {
             full_lists: args.full_lists,
             cache: Default::default(),
             overrides,
+            today: chrono::Utc::now().date_naive(),
+            sweep_location: None,
         },
         configuration,
         &testables,
     );
+    if args.sweep_instances {
+        checkorder = sweep_check_order(checkorder);
+    }
 
     // The testables are the collection object plus the files; only count the files.
     let count_of_files = testables.iter().filter(|x| x.is_single()).count();
@@ -254,7 +373,7 @@ fn main() {
         Either::Right(checkorder.par_iter())
     };
 
-    let mut results: RunResults = checkorder_iterator
+    let results: Vec<CheckResult> = checkorder_iterator
         .map(|(sectionname, testable, check, context)| {
             (
                 testable,
@@ -263,14 +382,23 @@ fn main() {
             )
         })
         .filter_map(|(_, _, result)| result)
-        .collect::<Vec<CheckResult>>()
-        .into();
+        .collect();
+    let mut results: RunResults = if args.sweep_instances {
+        merge_swept_results(results).into()
+    } else {
+        results.into()
+    };
 
     if args.hotfix || args.fix_sources {
         try_fixing_stuff(&mut results, &args, &registry);
     }
 
-    let worst_status = results.worst_status();
+    // Sections may set their own failure thresholds in the profile
+    let failed = results.any_failures(|section| {
+        section
+            .and_then(|section| profile.fail_threshold(section))
+            .unwrap_or(args.error_code_on)
+    });
 
     let mut reporters: Vec<Box<dyn Reporter>> = vec![];
     if !args.quiet {
@@ -289,6 +417,7 @@ fn main() {
             start_time.elapsed().as_secs_f32()
         );
         TerminalReporter::summary_report(results.summary());
+        TerminalReporter::waiver_report(&results);
     }
 
     if args.verbose >= 1 {
@@ -307,7 +436,7 @@ fn main() {
         }
     }
 
-    if worst_status >= args.error_code_on {
+    if failed {
         std::process::exit(1);
     }
 }
@@ -351,6 +480,222 @@ fn group_inputs(args: &Args) -> Vec<TestableCollection> {
         .collect()
 }
 
+/// Lint or show a profile, then exit
+fn profile_command(registry: &Registry, action: &ProfileCommand) -> ! {
+    let profile = match action {
+        ProfileCommand::Lint { file } => {
+            let toml = std::fs::read_to_string(file).unwrap_or_else(|e| {
+                log::error!("Could not read profile {:}: {:}", file, e);
+                std::process::exit(1);
+            });
+            Profile::from_toml(&toml).unwrap_or_else(|e| {
+                log::error!("Could not parse profile {:}: {:}", file, e);
+                std::process::exit(1);
+            })
+        }
+        ProfileCommand::Show { name } => registry.get_profile(name).cloned().unwrap_or_else(|| {
+            log::error!("Could not find profile {:}", name);
+            std::process::exit(1);
+        }),
+    };
+    let problems = profile.lint(registry);
+    let resolved = profile.resolved(registry);
+    let check_count: usize = resolved.sections.values().map(|checks| checks.len()).sum();
+    match resolved.to_toml() {
+        Ok(toml) => println!(
+            "# {} sections, {} checks\n{}",
+            resolved.sections.len(),
+            check_count,
+            toml
+        ),
+        Err(e) => log::error!("Could not serialize profile: {:}", e),
+    }
+    for problem in problems.iter() {
+        eprintln!("{} {}", "Problem:".yellow(), problem);
+    }
+    if matches!(action, ProfileCommand::Lint { .. }) && !problems.is_empty() {
+        eprintln!("{} problems found", problems.len());
+        std::process::exit(1);
+    }
+    std::process::exit(0);
+}
+
+/// Record shaping test expectations, then exit
+fn shaping_command(args: &Args, action: &ShapingCommand) -> ! {
+    let ShapingCommand::Record {
+        fonts,
+        test_directory,
+        positions,
+        dry_run,
+    } = action;
+    let configuration = load_configuration(args);
+    let test_directory = test_directory
+        .as_deref()
+        .or_else(|| profile_googlefonts::shaping_test_directory(&configuration))
+        .unwrap_or_else(|| {
+            log::error!(
+                "Give a --test-directory, or set shaping.test_directory in the configuration file"
+            );
+            std::process::exit(1);
+        });
+    let fonts = fonts
+        .iter()
+        .map(|font| {
+            Testable::new(font).unwrap_or_else(|e| {
+                log::error!("Could not read font {:}: {:}", font, e);
+                std::process::exit(1);
+            })
+        })
+        .collect::<Vec<_>>();
+    let changes = profile_googlefonts::record_expectations(
+        &fonts,
+        test_directory,
+        &configuration,
+        *positions,
+        !dry_run,
+    )
+    .unwrap_or_else(|e| {
+        log::error!("Could not record shaping expectations: {:?}", e);
+        std::process::exit(1);
+    });
+    for change in changes.iter() {
+        println!(
+            "{} ({}{}): {}{}",
+            change.file.bold(),
+            change.font,
+            change.location,
+            change.input,
+            change
+                .note
+                .as_ref()
+                .map(|note| format!(" ({})", note))
+                .unwrap_or_default()
+        );
+        for line in change.diff().lines() {
+            if line.starts_with('+') {
+                println!("{}", line.green());
+            } else if line.starts_with('-') {
+                println!("{}", line.red());
+            } else {
+                println!("{}", line);
+            }
+        }
+        println!();
+    }
+    println!(
+        "{} expectations {}",
+        changes.len(),
+        if *dry_run { "would change" } else { "changed" }
+    );
+    std::process::exit(0);
+}
+
+/// Write a language support report for the input fonts, then exit
+fn language_report(args: &Args, filename: &str) -> ! {
+    let configuration = load_configuration(args);
+    let glyphsets = Glyphset::from_configuration(
+        &configuration
+            .get("glyphset_coverage")
+            .and_then(|config| config.as_object())
+            .cloned()
+            .unwrap_or_default(),
+    )
+    .unwrap_or_else(|e| {
+        log::error!("{:?}", e);
+        std::process::exit(1);
+    });
+    let reports = args
+        .inputs
+        .iter()
+        .flat_map(|input| Testable::new(input).ok())
+        .filter(|testable| TTF.applies(testable))
+        .map(|testable| {
+            profile_googlefonts::language_report::language_report(&testable, &glyphsets)
+                .unwrap_or_else(|e| {
+                    log::error!(
+                        "Could not report on {:}: {:?}",
+                        testable.filename.display(),
+                        e
+                    );
+                    std::process::exit(1);
+                })
+        })
+        .collect::<Vec<_>>();
+    if reports.is_empty() {
+        log::error!("No fonts to report on");
+        std::process::exit(1);
+    }
+    let output = if filename.ends_with(".json") {
+        serde_json::to_string_pretty(&reports).unwrap_or_else(|e| {
+            log::error!("Could not serialize language report: {:}", e);
+            std::process::exit(1);
+        })
+    } else {
+        reports
+            .iter()
+            .map(|report| report.to_markdown())
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    if let Err(e) = std::fs::write(filename, output) {
+        log::error!("Could not write language report to {:}: {:}", filename, e);
+        std::process::exit(1);
+    }
+    println!(
+        "Wrote language report for {} fonts to {}",
+        reports.len(),
+        filename
+    );
+    std::process::exit(0);
+}
+
+/// Load a WebAssembly plugin from a file and register its checks and profiles
+fn load_wasm_plugin(registry: &mut Registry, plugin_path: &str) -> Result<PluginInfo, PluginError> {
+    let wasm = std::fs::read(plugin_path).map_err(|e| PluginError::Load(e.to_string()))?;
+    let plugin = WasmPlugin::new(plugin_path, &wasm)?;
+    registry.register_plugin(&plugin, plugin_path, None)?;
+    #[allow(clippy::unwrap_used)] // register_plugin just pushed it
+    Ok(registry.plugins.last().unwrap().clone())
+}
+
+fn list_plugins(registry: &Registry) {
+    if registry.plugins.is_empty() {
+        println!("No plugins loaded");
+        return;
+    }
+    for plugin in registry.plugins.iter() {
+        termimad::print_text(&format!(
+            "\n# {}\n\nBuilt against fontspector-checkapi {}\n",
+            plugin.path,
+            plugin.checkapi_version.as_deref().unwrap_or("(unknown)")
+        ));
+        for (kind, items) in [
+            ("Profiles", &plugin.profiles),
+            ("File types", &plugin.filetypes),
+            ("Checks", &plugin.checks),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+            let mut text = format!("\n## {}\n\n", kind);
+            for item in items.iter() {
+                text.push_str(&format!("* {}\n", item));
+            }
+            termimad::print_text(&text);
+        }
+    }
+}
+
+fn check_selection(args: &Args) -> CheckSelection {
+    CheckSelection {
+        include_checks: args.checkid.clone(),
+        exclude_checks: args.exclude_checkid.clone(),
+        include_tags: args.tag.clone(),
+        exclude_tags: args.exclude_tag.clone(),
+        include_experimental: args.include_experimental,
+    }
+}
+
 fn load_configuration(args: &Args) -> Map<String, serde_json::Value> {
     args.configuration
         .as_ref()
@@ -428,24 +773,49 @@ fn try_fixing_stuff(results: &mut RunResults, args: &Args, registry: &Registry)
     }
 }
 
-fn load_overrides(configuration: &Map<String, serde_json::Value>) -> Vec<Override> {
+fn load_overrides(
+    configuration: &Map<String, serde_json::Value>,
+    registry: &Registry,
+) -> Vec<Override> {
     let mut overrides = vec![];
     if let Some(config_overrides) = configuration.get("overrides").and_then(|v| v.as_array()) {
         for override_value in config_overrides {
-            if let Some(override_map) = override_value.as_object() {
-                if let (Some(code), Some(status), Some(reason)) = (
-                    override_map.get("code").and_then(|v| v.as_str()),
-                    override_map
-                        .get("status")
-                        .and_then(|v| v.as_str())
-                        .and_then(StatusCode::from_string),
-                    override_map.get("reason").and_then(|v| v.as_str()),
-                ) {
-                    overrides.push(Override::new(code, status, reason));
-                } else {
-                    log::warn!("Invalid override entry: {:?}", override_value);
+            let override_: Override = match serde_json::from_value(override_value.clone()) {
+                Ok(override_) => override_,
+                Err(e) => {
+                    log::warn!("Invalid override entry {:?}: {}", override_value, e);
+                    continue;
+                }
+            };
+            let code = override_.code.as_str();
+            if let Some(check_id) = override_.check.as_deref() {
+                match registry.checks.get(check_id) {
+                    None => log::warn!("Override given for unknown check {}", check_id),
+                    Some(check)
+                        if !check.codes.is_empty() && check.declared_code(code).is_none() =>
+                    {
+                        log::warn!(
+                            "Override code {} is not declared by check {}; it will have no effect",
+                            code,
+                            check_id
+                        )
+                    }
+                    Some(_) => {}
+                }
+            } else {
+                match registry.code_is_declared(code) {
+                    Some(false) => log::warn!(
+                        "Override code {} is not declared by any check; it will have no effect",
+                        code
+                    ),
+                    None => log::debug!(
+                        "Override code {} is not declared by any check which lists its codes",
+                        code
+                    ),
+                    Some(true) => {}
                 }
             }
+            overrides.push(override_);
         }
     }
     overrides
@@ -42,6 +42,7 @@ fn emoticon(v: &Value, _options: &HashMap<String, Value>) -> tera::Result<Value>
         "FAIL" => "🔥",
         "WARN" => "⚠️",
         "INFO" => "ℹ️",
+        "WAIVED" => "🙈",
         "SKIP" => "⏩",
         "PASS" => "✅",
         "DEBUG" => "🔎",
@@ -161,7 +162,9 @@ impl Reporter for JinjaTemplatedReporter {
             },
         );
         for result in results.iter() {
-            let filename = result.filename.as_ref().unwrap_or(&all_fonts).as_str();
+            let filename = result
+                .filename_and_location()
+                .unwrap_or_else(|| all_fonts.clone());
             if result.worst_status() < args.loglevel {
                 continue;
             }
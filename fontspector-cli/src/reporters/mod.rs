@@ -1,5 +1,5 @@
 use crate::{reporters::csv::CsvReporter, Args};
-use fontspector_checkapi::{CheckResult, Registry, StatusCode};
+use fontspector_checkapi::{CheckResult, Registry, Status, StatusCode};
 use jinja::JinjaTemplatedReporter;
 use json::JsonReporter;
 use std::{collections::HashMap, path::PathBuf};
@@ -28,13 +28,11 @@ impl RunResults {
         self.results.iter_mut()
     }
 
-    /// Get the worst status of all checks
-    pub fn worst_status(&self) -> StatusCode {
+    /// Whether any check's worst status reaches the failure threshold for its section
+    pub fn any_failures(&self, threshold: impl Fn(Option<&str>) -> StatusCode) -> bool {
         self.results
             .iter()
-            .map(|r| r.worst_status())
-            .max()
-            .unwrap_or(StatusCode::Pass)
+            .any(|r| r.worst_status() >= threshold(r.section.as_deref()))
     }
 
     /// Get a summary of the results by status code
@@ -49,6 +47,17 @@ impl RunResults {
         summary
     }
 
+    /// Iterate over every status which matched an override, active or expired
+    pub fn overridden(&self) -> impl Iterator<Item = (&CheckResult, &Status)> {
+        self.results.iter().flat_map(|result| {
+            result
+                .subresults
+                .iter()
+                .filter(|status| status.overridden.is_some())
+                .map(move |status| (result, status))
+        })
+    }
+
     /// Organize the results by testable and section
     pub fn organize(&self) -> OrganisedResults {
         let mut organised_results: OrganisedResults = HashMap::new();
@@ -56,8 +65,7 @@ impl RunResults {
             let section = organised_results
                 .entry(
                     checkresult
-                        .filename
-                        .clone()
+                        .filename_and_location()
                         .unwrap_or("All fonts".to_string()),
                 )
                 .or_default();
@@ -178,3 +186,48 @@ pub(crate) fn process_reporter_args(args: &Args, reporters: &mut Vec<Box<dyn Rep
         )));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use fontspector_checkapi::Override;
+    use std::time::Duration;
+
+    fn result_with(status: Status) -> CheckResult {
+        CheckResult {
+            check_id: "contour_count".to_string(),
+            check_name: "".to_string(),
+            check_rationale: "".to_string(),
+            filename: Some("Family-Italic.ttf".to_string()),
+            source_filename: None,
+            location: None,
+            section: None,
+            subresults: vec![status],
+            hotfix_result: None,
+            sourcefix_result: None,
+            time: Duration::default(),
+        }
+    }
+
+    #[test]
+    fn test_waived_fail_does_not_trip_error_code_on() {
+        let waiver = Override::new("contour-count", StatusCode::Waived, "Single-storey a")
+            .with_check("contour_count");
+        let today = chrono::Utc::now().date_naive();
+        let mut status = Status::fail("contour-count", "Unexpected contour count");
+        status.process_override(&[waiver], "contour_count", Some("Family-Italic.ttf"), today);
+        assert_eq!(status.severity, StatusCode::Waived);
+
+        let waived: RunResults = vec![result_with(status)].into();
+        assert!(!waived.any_failures(|_| StatusCode::Fail));
+        assert!(!waived.any_failures(|_| StatusCode::Warn));
+
+        let failed: RunResults = vec![result_with(Status::fail(
+            "contour-count",
+            "Unexpected contour count",
+        ))]
+        .into();
+        assert!(failed.any_failures(|_| StatusCode::Fail));
+    }
+}
@@ -26,6 +26,7 @@ fn colored_status(c: StatusCode, s: Option<&str>) -> ColoredString {
         StatusCode::Fail => string.red(),
         StatusCode::Warn => string.yellow(),
         StatusCode::Info => string.cyan(),
+        StatusCode::Waived => string.magenta(),
         StatusCode::Skip => string.blue(),
         StatusCode::Pass => string.green(),
     }
@@ -129,4 +130,41 @@ impl TerminalReporter {
         }
         println!();
     }
+
+    pub fn waiver_report(results: &RunResults) {
+        let mut overridden = results.overridden().peekable();
+        if overridden.peek().is_none() {
+            return;
+        }
+        println!("\nOverrides:");
+        for (result, status) in overridden {
+            #[allow(clippy::unwrap_used)] // We filtered on this
+            let applied = status.overridden.as_ref().unwrap();
+            let mut line = format!(
+                "  {} {} [{}]: ",
+                result.filename.as_deref().unwrap_or("All fonts"),
+                result.check_id.bright_cyan(),
+                status.code.as_deref().unwrap_or("-"),
+            );
+            if applied.expired {
+                line.push_str(&format!(
+                    "{} (expired)",
+                    colored_status(status.severity, None)
+                ));
+            } else {
+                line.push_str(&format!(
+                    "{} -> {}",
+                    colored_status(applied.original_severity, None),
+                    colored_status(status.severity, None)
+                ));
+            }
+            if let Some(who) = applied.accepted_by.as_deref() {
+                line.push_str(&format!(", accepted by {}", who));
+            }
+            if let Some(until) = applied.until {
+                line.push_str(&format!(", until {}", until));
+            }
+            println!("{}", line);
+        }
+    }
 }
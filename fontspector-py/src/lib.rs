@@ -1,12 +1,18 @@
 #![deny(clippy::unwrap_used, clippy::expect_used)]
-use std::{env, path::Path, vec};
+use std::{collections::BTreeMap, env, path::Path, vec};
 // Provide an environment where we can run fontbakery tests
 // as-is, but have them call a Rust implementation underneath
 use fontspector_checkapi::{
-    CheckImplementation, Context, Plugin, Registry, StatusCode, Testable, TestableCollection,
-    TestableType,
+    waivers_in, CheckImplementation, Context, Plugin, Registry, StatusCode, Testable,
+    TestableCollection, TestableType,
 };
+#[cfg(feature = "adobe")]
+use profile_adobe::Adobe;
+#[cfg(feature = "designspace")]
+use profile_designspace::Designspace;
 use profile_googlefonts::GoogleFonts;
+#[cfg(feature = "microsoft")]
+use profile_microsoft::Microsoft;
 use profile_opentype::OpenType;
 use profile_universal::Universal;
 use pyo3::{
@@ -16,28 +22,60 @@ use pyo3::{
 };
 use pythonize::depythonize;
 
+/// Register all the profiles which this build knows about
+fn register_profiles(registry: &mut Registry) -> PyResult<()> {
+    OpenType.register(registry).map_err(|_| {
+        PyValueError::new_err("Couldn't register opentype profile, fontspector bug")
+    })?;
+    Universal.register(registry).map_err(|_| {
+        PyValueError::new_err("Couldn't register universal profile, fontspector bug")
+    })?;
+    GoogleFonts.register(registry).map_err(|_| {
+        PyValueError::new_err("Couldn't register Google Fonts profile, fontspector bug")
+    })?;
+    #[cfg(feature = "microsoft")]
+    Microsoft.register(registry).map_err(|_| {
+        PyValueError::new_err("Couldn't register Microsoft profile, fontspector bug")
+    })?;
+    #[cfg(feature = "adobe")]
+    Adobe
+        .register(registry)
+        .map_err(|_| PyValueError::new_err("Couldn't register Adobe profile, fontspector bug"))?;
+    #[cfg(feature = "designspace")]
+    Designspace.register(registry).map_err(|_| {
+        PyValueError::new_err("Couldn't register Designspace profile, fontspector bug")
+    })?;
+    Ok(())
+}
+
 #[pyclass]
 struct CheckTester {
     check_id: String,
     profile: Option<String>,
 }
 
-fn obj_to_testable(py: Python, arg: &Bound<'_, PyAny>) -> PyResult<Testable> {
+/// The path of the file a font argument (a filename or a TTFont) came from
+fn obj_to_filename(py: Python, arg: &Bound<'_, PyAny>) -> PyResult<String> {
     let ttfont_class = py.import_bound("fontTools.ttLib")?.getattr("TTFont")?;
-    // if it's a string, just return a new testable
     if arg.is_instance_of::<PyString>() {
-        let filename: String = arg.extract()?;
-        return Testable::new(&filename)
-            .map_err(|e| PyValueError::new_err(format!("Couldn't create testable object: {}", e)));
+        return arg.extract();
     }
     if !arg.is_instance(&ttfont_class)? {
         panic!("I can't handle args {:?}", arg);
     }
-    let filename: String = arg
-        .getattr("reader")?
+    arg.getattr("reader")?
         .getattr("file")?
         .getattr("name")?
-        .extract()?;
+        .extract()
+}
+
+fn obj_to_testable(py: Python, arg: &Bound<'_, PyAny>) -> PyResult<Testable> {
+    let filename = obj_to_filename(py, arg)?;
+    // if it's a string, just return a new testable
+    if arg.is_instance_of::<PyString>() {
+        return Testable::new(&filename)
+            .map_err(|e| PyValueError::new_err(format!("Couldn't create testable object: {}", e)));
+    }
     let basename = Path::new(&filename)
         .file_name()
         .ok_or_else(|| PyValueError::new_err("Couldn't extract basename from filename"))?;
@@ -68,15 +106,7 @@ impl CheckTester {
     ) -> PyResult<Vec<Bound<'a, PyAny>>> {
         // Spin up a new fontspector (each time, how extravagant)
         let mut registry = Registry::new();
-        OpenType.register(&mut registry).map_err(|_| {
-            PyValueError::new_err("Couldn't register opentype profile, fontspector bug")
-        })?;
-        Universal.register(&mut registry).map_err(|_| {
-            PyValueError::new_err("Couldn't register universal profile, fontspector bug")
-        })?;
-        GoogleFonts.register(&mut registry).map_err(|_| {
-            PyValueError::new_err("Couldn't register Google Fonts profile, fontspector bug")
-        })?;
+        register_profiles(&mut registry)?;
 
         let check = registry
             .checks
@@ -87,15 +117,23 @@ impl CheckTester {
         let first_arg = args
             .get_item(0)
             .map_err(|_| PyValueError::new_err("No args found"))?;
-        let testables = if first_arg.is_instance_of::<PyList>() {
-            first_arg
-                .iter()?
-                .flatten()
-                .map(|a| obj_to_testable(py, &a))
-                .collect::<Result<Vec<_>, _>>()?
+        let font_args = if first_arg.is_instance_of::<PyList>() {
+            first_arg.iter()?.flatten().collect()
         } else {
-            vec![obj_to_testable(py, &first_arg)?]
+            vec![first_arg]
         };
+        let testables = font_args
+            .iter()
+            .map(|a| obj_to_testable(py, a))
+            .collect::<Result<Vec<_>, _>>()?;
+        // TTFonts are checked from a temporary copy, so look for waivers
+        // next to the files they were loaded from.
+        let mut waivers = vec![];
+        for arg in font_args.iter() {
+            if let Some(directory) = Path::new(&obj_to_filename(py, arg)?).parent() {
+                waivers.extend(waivers_in(directory));
+            }
+        }
         let collection = TestableCollection {
             testables,
             directory: "".to_string(),
@@ -108,6 +146,7 @@ impl CheckTester {
 
         let mut fontspector_config = serde_json::Map::new();
         let mut skip_network = false;
+        let mut sweep_location = None;
 
         if let Some(kwargs) = kwargs {
             if let Some(config) = kwargs.get_item("config")? {
@@ -116,12 +155,24 @@ impl CheckTester {
             if let Some(skip_network_arg) = kwargs.get_item("skip_network")? {
                 skip_network = skip_network_arg.as_any().extract()?;
             }
+            if let Some(location_arg) = kwargs.get_item("location")? {
+                let location: BTreeMap<String, f32> = location_arg.as_any().extract()?;
+                sweep_location = Some(
+                    location
+                        .iter()
+                        .map(|(tag, value)| (tag.as_str(), *value).into())
+                        .collect(),
+                );
+            }
         }
 
         let mut context = Context {
             configuration: fontspector_config,
             full_lists: true,
             skip_network,
+            overrides: waivers,
+            today: chrono::Utc::now().date_naive(),
+            sweep_location,
             ..Default::default()
         };
         if let Some(profile_name) = &self.profile {
@@ -143,7 +194,8 @@ impl CheckTester {
         for subresult in result.subresults {
             let severity = match subresult.severity {
                 StatusCode::Skip => status_module.getattr("SKIP")?,
-                StatusCode::Info => status_module.getattr("INFO")?,
+                // Fontbakery has no notion of waivers
+                StatusCode::Info | StatusCode::Waived => status_module.getattr("INFO")?,
                 StatusCode::Warn => status_module.getattr("WARN")?,
                 StatusCode::Pass => status_module.getattr("PASS")?,
                 StatusCode::Fail => status_module.getattr("FAIL")?,
@@ -166,15 +218,7 @@ impl CheckTester {
 #[pyfunction]
 fn registered_checks() -> PyResult<Vec<String>> {
     let mut registry = Registry::new();
-    OpenType.register(&mut registry).map_err(|_| {
-        PyValueError::new_err("Couldn't register opentype profile, fontspector bug")
-    })?;
-    Universal.register(&mut registry).map_err(|_| {
-        PyValueError::new_err("Couldn't register universal profile, fontspector bug")
-    })?;
-    GoogleFonts.register(&mut registry).map_err(|_| {
-        PyValueError::new_err("Couldn't register Google Fonts profile, fontspector bug")
-    })?;
+    register_profiles(&mut registry)?;
     Ok(registry.checks.keys().cloned().collect())
 }
 
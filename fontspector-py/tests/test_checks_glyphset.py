@@ -2,11 +2,12 @@ from fontTools.ttLib import TTFont
 
 from fontbakery.codetesting import (
     assert_PASS,
+    assert_SKIP,
     assert_results_contain,
     portable_path,
     TEST_FILE,
 )
-from fontbakery.status import FAIL
+from fontbakery.status import FAIL, WARN
 from conftest import check_id
 
 
@@ -61,3 +62,44 @@ def test_check_family_control_chars(check):
         "unacceptable",
         "with multiple bad fonts that have multiple bad chars...",
     )
+
+
+@check_id("glyphset_coverage")
+def test_check_glyphset_coverage(check):
+    """Check fonts cover the glyphsets declared for them."""
+    font = TEST_FILE("nunito/Nunito-Regular.ttf")
+
+    assert_SKIP(check(font), "with no glyphsets declared...")
+
+    def config(*glyphsets):
+        return {"glyphset_coverage": {"glyphsets": list(glyphsets)}}
+
+    cyrillic = {"name": "Cyrillic", "codepoints": ["U+0416", "A"]}
+    assert_results_contain(
+        check(font, config=config(cyrillic)),
+        FAIL,
+        "missing-codepoints",
+        "with a glyphset the font doesn't cover...",
+    )
+
+    alternates = {"name": "Alternates", "glyphs": ["A", "a.nonexistent"]}
+    assert_results_contain(
+        check(font, config=config(alternates)),
+        FAIL,
+        "missing-glyphs",
+        "with a glyph the font doesn't have...",
+    )
+
+    latin = {"name": "Latin", "blocks": ["Basic Latin", "Latin-1 Supplement"]}
+    assert_results_contain(
+        check(font, config=config(latin)),
+        WARN,
+        "extra-codepoints",
+        "with characters in none of the glyphsets...",
+    )
+
+    italic = dict(cyrillic, files="*-Italic.ttf")
+    assert_SKIP(
+        check(font, config=config(italic)),
+        "with a glyphset only for italics...",
+    )
@@ -3713,6 +3713,24 @@ def test_check_shape_languages(check):
     assert_results_contain(check(test_font), FAIL, "failed-language-shaping")
 
 
+@check_id("googlefonts/glyphsets/mark_anchors")
+def test_check_mark_anchors(check):
+    """Check that bases have anchors for the marks used with them in supported languages"""
+    ttFont = TTFont(TEST_FILE("cabinvf/Cabin[wdth,wght].ttf"))
+    assert_PASS(check(ttFont))
+
+    # Take away the base anchors of the mark-to-base lookups, so that marks
+    # used without a precomposed glyph have nothing to attach to
+    for lookup in ttFont["GPOS"].table.LookupList.Lookup:
+        if lookup.LookupType != 4:
+            continue
+        for subtable in lookup.SubTable:
+            for record in subtable.BaseArray.BaseRecord:
+                record.BaseAnchor = [None] * len(record.BaseAnchor)
+    message = assert_results_contain(check(ttFont), WARN, "missing-mark-anchors")
+    assert " + " in message
+
+
 @check_id("googlefonts/metadata/validate")
 def test_check_metadata_minisite_url(check, tmp_path):
     """Validate minisite_url field"""
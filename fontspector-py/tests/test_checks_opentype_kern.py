@@ -0,0 +1,35 @@
+from fontTools.ttLib import TTFont, newTable
+from fontTools.ttLib.tables._k_e_r_n import KernTable_format_0
+
+from fontbakery.status import INFO, FAIL
+from fontbakery.codetesting import (
+    assert_PASS,
+    assert_results_contain,
+    TEST_FILE,
+)
+from conftest import check_id
+
+
+def _add_kern_table(ttFont, pairs):
+    subtable = KernTable_format_0()
+    subtable.version = 0
+    subtable.coverage = 1
+    subtable.kernTable = pairs
+    ttFont["kern"] = newTable("kern")
+    ttFont["kern"].version = 0
+    ttFont["kern"].kernTables = [subtable]
+
+
+@check_id("opentype/kern_table")
+def test_check_kern_table(check):
+    """Is there a usable "kern" table declared in the font?"""
+    ttFont = TTFont(TEST_FILE("mada/Mada-Regular.ttf"))
+    assert "kern" not in ttFont
+    assert_PASS(check(ttFont), "with a font without a kern table.")
+
+    cmap = ttFont.getBestCmap()
+    _add_kern_table(ttFont, {(cmap[ord("A")], cmap[ord("V")]): -50})
+    assert_results_contain(check(ttFont), INFO, "kern-found")
+
+    _add_kern_table(ttFont, {(".notdef", cmap[ord("V")]): -50})
+    assert_results_contain(check(ttFont), FAIL, "kern-non-character-glyphs")
@@ -73,6 +73,10 @@ def test_check_outline_short_segments(check):
     font = TEST_FILE("source-sans-pro/VAR/SourceSansVariable-Roman.otf")
     msg = assert_results_contain(check(font), SKIP, "variable-font")
 
+    # ...unless we are sweeping its instances
+    results = check(font, location={"wght": 900})
+    assert not any([r.status == SKIP for r in results])
+
 
 @check_id("outline_colinear_vectors")
 def test_check_outline_colinear_vectors(check):
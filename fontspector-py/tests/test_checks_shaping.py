@@ -76,6 +76,73 @@ def test_check_shaping_regression_with_variations(check):
         assert_PASS(check(font, config=config), "Oswald: A=0+453|V=1+505")
 
 
+@check_id("shaping/regression")
+def test_check_shaping_regression_per_location(check):
+    """Check that tests run at several locations are reported per location."""
+
+    shaping_test = {
+        "configuration": {},
+        "tests": [
+            {
+                "input": "AV",
+                "locations": [{"wght": 400}, {"wght": 700}],
+                "expectations": [
+                    {"variations": {"wght": 400}, "expectation": "A=0+1134|V=1+1220"},
+                    {"variations": {"wght": 700}, "expectation": "A=0+1134|V=1+1220"},
+                ],
+            },
+        ],
+    }
+
+    with tempfile.TemporaryDirectory() as tmp_gf_dir:
+        json.dump(
+            shaping_test,
+            open(os.path.join(tmp_gf_dir, "test.json"), "w", encoding="utf-8"),
+        )
+
+        config = {"shaping": {"test_directory": tmp_gf_dir}}
+
+        font = TEST_FILE("cabinvf/Cabin[wdth,wght].ttf")
+        msg = assert_results_contain(
+            check(font, config=config),
+            FAIL,
+            "shaping-regression",
+            "Cabin is wider at wght=700",
+        )
+        assert "AV @ wght=700" in msg
+        assert "AV @ wght=400" not in msg
+
+
+@check_id("shaping/regression")
+def test_check_shaping_regression_harfbuzz_tests(check):
+    """Check that we can read HarfBuzz .tests files."""
+
+    with tempfile.TemporaryDirectory() as tmp_gf_dir:
+        with open(os.path.join(tmp_gf_dir, "kern.tests"), "w", encoding="utf-8") as f:
+            f.write("# Kerning\n")
+            f.write("fonts/Nunito-Regular.ttf;;U+0041,U+0056;[A=0+664|V=1+691]\n")
+            f.write("fonts/Nunito-Regular.ttf;--features=-kern;U+0041,U+0056;[A=0+727|V=1+691]\n")
+
+        config = {"shaping": {"test_directory": tmp_gf_dir}}
+
+        font = TEST_FILE("nunito/Nunito-Regular.ttf")
+        assert_PASS(check(font, config=config), "Nunito matches its HarfBuzz tests")
+
+        font = TEST_FILE("slabo/Slabo13px.ttf")
+        assert_PASS(check(font, config=config), "Tests for Nunito don't apply to Slabo")
+
+        with open(os.path.join(tmp_gf_dir, "kern.tests"), "a", encoding="utf-8") as f:
+            f.write("fonts/Nunito-Regular.ttf;--no-clusters;U+0041,U+0056;[A+600|V+691]\n")
+        font = TEST_FILE("nunito/Nunito-Regular.ttf")
+        msg = assert_results_contain(
+            check(font, config=config),
+            FAIL,
+            "shaping-regression",
+            "Nunito: A+664|V+691",
+        )
+        assert "A+664|V+691" in msg
+
+
 @check_id("shaping/forbidden")
 def test_check_shaping_forbidden(check):
     """Check that we can test for forbidden glyphs in output."""
@@ -105,7 +172,34 @@ def test_check_shaping_forbidden(check):
         )
 
 
-@pytest.mark.skip("Check not ported yet")
+@check_id("shaping/forbidden")
+def test_check_shaping_forbidden_text_corpus(check):
+    """Check that we can read tests from text corpora."""
+
+    with tempfile.TemporaryDirectory() as tmp_gf_dir:
+        with open(os.path.join(tmp_gf_dir, "corpus.txt"), "w", encoding="utf-8") as f:
+            f.write("# A comment\n\nAV --features=-kern\n日\n")
+
+        config = {
+            "shaping": {
+                "test_directory": tmp_gf_dir,
+                "configuration": {"forbidden_glyphs": [".notdef"]},
+            }
+        }
+
+        font = TEST_FILE("nunito/Nunito-Regular.ttf")
+        assert_results_contain(
+            check(font, config=config),
+            FAIL,
+            "shaping-forbidden",
+            "Nunito has no CJK",
+        )
+
+        with open(os.path.join(tmp_gf_dir, "corpus.txt"), "w", encoding="utf-8") as f:
+            f.write("AV --features=-kern\n")
+        assert_PASS(check(font, config=config), "Nunito has A and V")
+
+
 @check_id("shaping/collides")
 def test_check_shaping_collides(check):
     """Check that we can test for colliding glyphs in output."""
@@ -134,6 +228,13 @@ def test_check_shaping_collides(check):
             "ïï collides in Nunito",
         )
 
+        shaping_test["configuration"]["allowedcollisions"] = ["idieresis/idieresis"]
+        json.dump(
+            shaping_test,
+            open(os.path.join(tmp_gf_dir, "test.json"), "w", encoding="utf-8"),
+        )
+        assert_PASS(check(font, config=config), "idieresis/idieresis is allowed")
+
 
 @check_id("dotted_circle")
 def test_check_dotted_circle(check):
@@ -2,10 +2,12 @@ import os
 import io
 from unittest.mock import patch, MagicMock
 
+from fontTools.pens.boundsPen import BoundsPen
 from fontTools.ttLib import TTFont
 import pytest
 import requests
 
+from fontbakery.constants import PlatformID, WindowsEncodingID, WindowsLanguageID
 from fontbakery.status import INFO, WARN, FAIL, SKIP, ERROR
 from fontbakery.codetesting import (
     assert_PASS,
@@ -19,6 +21,11 @@ from fontbakery.utils import glyph_has_ink
 from conftest import check_id
 
 
+@pytest.fixture
+def vf_ttFont():
+    return TTFont(TEST_FILE("varfont/Oswald-VF.ttf"))
+
+
 @pytest.fixture
 def montserrat_ttFonts():
     paths = [
@@ -607,6 +614,36 @@ def test_check_unreachable_glyphs(check):
     assert_PASS(check(ttFont))
 
 
+@check_id("gpos_unattached_mark_classes")
+def test_check_gpos_unattached_mark_classes(check):
+    """Check that every GPOS mark class is attached to some glyph"""
+    ttFont = TTFont(TEST_FILE("cabinvf/Cabin[wdth,wght].ttf"))
+    assert_PASS(check(ttFont))
+
+    # Take away every base's anchor for the first class of the mark-to-base lookup
+    mark_to_base = [
+        lookup
+        for lookup in ttFont["GPOS"].table.LookupList.Lookup
+        if lookup.LookupType == 4
+    ][0]
+    for record in mark_to_base.SubTable[0].BaseArray.BaseRecord:
+        record.BaseAnchor[0] = None
+    message = assert_results_contain(check(ttFont), WARN, "unattached-mark-classes")
+    assert "acutecomb" in message
+
+
+@check_id("gpos_kerning_unreachable_glyphs")
+def test_check_gpos_kerning_unreachable_glyphs(check):
+    """Check that kerning pairs only involve reachable glyphs"""
+    font = TEST_FILE("mada/Mada-Regular.ttf")
+    assert_PASS(check(font))
+
+    font = TEST_FILE("montserrat/Montserrat-Regular.ttf")
+    message = assert_results_contain(check(font), WARN, "kerning-unreachable-glyphs")
+    assert "Tdieresis.ss01" in message
+    assert "f.orig" in message
+
+
 @check_id("soft_hyphen")
 def test_check_soft_hyphen(montserrat_ttFonts, check):
     """Check glyphs contain the recommended contour count"""
@@ -811,6 +848,11 @@ def test_check_math_signs_width(check):
     font = TEST_FILE("montserrat/Montserrat-Regular.ttf")
     assert_results_contain(check(font), WARN, "width-outliers")
 
+    # Widths are compared at each named instance of a variable font.
+    font = TEST_FILE("cabinvf/Cabin[wdth,wght].ttf")
+    msg = assert_results_contain(check(font), WARN, "width-outliers")
+    assert "The most common width at Regular is 884" in msg
+
 
 @check_id("linegaps")
 def test_check_linegaps(check):
@@ -886,7 +928,6 @@ def test_check_alt_caron(check):
     assert_PASS(check(ttFont))
 
 
-@pytest.mark.skip(reason="Check not yet implemented")
 @check_id("caps_vertically_centered")
 def test_check_caps_vertically_centered(check):
     """Check if uppercase glyphs are vertically centered."""
@@ -897,6 +938,22 @@ def test_check_caps_vertically_centered(check):
     ttFont = TTFont(TEST_FILE("cjk/SourceHanSans-Regular.otf"))
     assert_SKIP(check(ttFont))
 
+    # The bounds of CFF outlines are measured too
+    ttFont = TTFont(TEST_FILE("rokkitt/Rokkitt-Regular.otf"))
+    assert_results_contain(check(ttFont), WARN, "vertical-metrics-not-centered")
+
+    # Moving the descender to match the space above the caps centers them
+    glyphset = ttFont.getGlyphSet()
+    cmap = ttFont.getBestCmap()
+    cap_heights = []
+    for c in "ABCDEHIMOSTX":
+        pen = BoundsPen(glyphset)
+        glyphset[cmap[ord(c)]].draw(pen)
+        cap_heights.append(pen.bounds[3])
+    top_margin = ttFont["head"].unitsPerEm - sum(cap_heights) / len(cap_heights)
+    ttFont["hhea"].descent = -round(top_margin)
+    assert_PASS(check(ttFont))
+
     # FIXME: review this test-case
     # ttFont = TTFont(TEST_FILE("cairo/CairoPlay-Italic.leftslanted.ttf"))
     # assert_results_contain(check(ttFont), WARN, "vertical-metrics-not-centered")
@@ -960,7 +1017,6 @@ def test_check_gsub_smallcaps_before_ligatures(check):
     assert_results_contain(check(ttFont), FAIL, "feature-ordering")
 
 
-@pytest.mark.skip("Check not ported yet.")
 @check_id("varfont/bold_wght_coord")
 def test_check_varfont_bold_wght_coord(check):
     """The variable font 'wght' (Weight) axis coordinate
@@ -968,7 +1024,7 @@ def test_check_varfont_bold_wght_coord(check):
 
     # Our reference varfont CabinVFBeta.ttf
     # has a good Bold:wght coordinate
-    ttFont = TTFont("data/test/cabinvfbeta/CabinVFBeta.ttf")
+    ttFont = TTFont(TEST_FILE("cabinvfbeta/CabinVFBeta.ttf"))
     assert_PASS(check(ttFont), "with a good Bold:wght coordinate...")
 
     # We then change the value to ensure the problem is properly detected by the check:
@@ -978,13 +1034,12 @@ def test_check_varfont_bold_wght_coord(check):
     )
 
     # Check we skip when we don't have a 700 weight.
-    ttFont = TTFont("data/test/cabinvfbeta/CabinVFBeta.ttf")
+    ttFont = TTFont(TEST_FILE("cabinvfbeta/CabinVFBeta.ttf"))
     del ttFont["fvar"].instances[3]
     ttFont["fvar"].axes[0].maxValue = 600
     assert_results_contain(check(ttFont), SKIP, "no-bold-weight")
 
 
-@pytest.mark.skip("Check not ported yet.")
 @check_id("varfont/duplicate_instance_names")
 def test_check_varfont_duplicate_instance_names(check, vf_ttFont):
     assert_PASS(
@@ -1019,3 +1074,45 @@ def test_check_varfont_duplicate_instance_names(check, vf_ttFont):
     vf_ttFont2["fvar"].instances[2].subfamilyNameID = name_id
     msg = assert_results_contain(check(vf_ttFont2), FAIL, "name-record-not-found")
     assert f" and nameID {name_id} was not found." in msg
+
+
+@check_id("inconsistencies_between_fvar_STAT")
+def test_check_inconsistencies_between_fvar_STAT(check):
+    """Checking if STAT entries matches fvar and vice versa."""
+    ttFont = TTFont(TEST_FILE("cabinvf/Cabin[wdth,wght].ttf"))
+    assert_PASS(check(ttFont))
+
+    # Remove a STAT axis value which an fvar instance relies on
+    axis_values = ttFont["STAT"].table.AxisValueArray.AxisValue
+    ttFont["STAT"].table.AxisValueArray.AxisValue = [
+        value
+        for value in axis_values
+        if not (value.Format in (1, 3) and value.Value == 700.0)
+    ]
+    ttFont["STAT"].table.AxisValueCount = len(
+        ttFont["STAT"].table.AxisValueArray.AxisValue
+    )
+    assert_results_contain(check(ttFont), FAIL, "missing-fvar-instance-axis-value")
+
+    # Point an instance at a name record which doesn't exist
+    ttFont = TTFont(TEST_FILE("cabinvf/Cabin[wdth,wght].ttf"))
+    ttFont["fvar"].instances[0].subfamilyNameID = 999
+    assert_results_contain(check(ttFont), FAIL, "missing-name-id")
+
+    del ttFont["STAT"]
+    assert_results_contain(check(ttFont), FAIL, "missing-stat-table")
+
+
+@check_id("table_roundtrip")
+def test_check_table_roundtrip(check):
+    """Checking font tables survive a decompile/compile round trip."""
+    from fontTools.ttLib.tables.DefaultTable import DefaultTable
+
+    ttFont = TTFont(TEST_FILE("mada/Mada-Regular.ttf"))
+    assert_PASS(check(ttFont))
+
+    # A GDEF table which is too short to contain a header
+    bad_gdef = DefaultTable("GDEF")
+    bad_gdef.data = b"\x00\x01"
+    ttFont["GDEF"] = bad_gdef
+    assert_results_contain(check(ttFont), FAIL, "unparseable-table")
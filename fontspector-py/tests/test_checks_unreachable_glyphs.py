@@ -1,3 +1,8 @@
+import io
+
+from fontTools.feaLib.builder import addOpenTypeFeaturesFromString
+from fontTools.fontBuilder import FontBuilder
+from fontTools.pens.ttGlyphPen import TTGlyphPen
 from fontTools.ttLib import TTFont
 
 from conftest import check_id
@@ -9,6 +14,32 @@ from fontbakery.codetesting import (
 )
 
 
+def _font_with_features(features):
+    """A font with A and B encoded, unencoded alternates, and the given features"""
+    glyph_order = [".notdef", "A", "B", "A.alt", "B.alt"]
+    pen = TTGlyphPen(None)
+    pen.moveTo((0, 0))
+    pen.lineTo((0, 500))
+    pen.lineTo((500, 500))
+    pen.closePath()
+    glyph = pen.glyph()
+    builder = FontBuilder(1000, isTTF=True)
+    builder.setupGlyphOrder(glyph_order)
+    builder.setupCharacterMap({ord("A"): "A", ord("B"): "B"})
+    builder.setupGlyf({name: glyph for name in glyph_order})
+    builder.setupHorizontalMetrics({name: (600, 0) for name in glyph_order})
+    builder.setupHorizontalHeader(ascent=800, descent=-200)
+    builder.setupNameTable({"familyName": "Test", "styleName": "Regular"})
+    builder.setupOS2()
+    builder.setupPost()
+    addOpenTypeFeaturesFromString(builder.font, features)
+    # Round-trip so the check sees the compiled font
+    buffer = io.BytesIO()
+    builder.save(buffer)
+    buffer.seek(0)
+    return TTFont(buffer)
+
+
 @check_id("unreachable_glyphs")
 def test_check_unreachable_glyphs(check):
     """Check font contains no unreachable glyphs."""
@@ -59,3 +90,27 @@ def test_check_unreachable_glyphs(check):
         "breve.rcap",
     ]:
         assert glyph not in message
+
+    # A glyph substituted only by a lookup which a contextual lookup calls is reachable
+    ttFont = _font_with_features(
+        """
+        lookup A_ALT { sub A by A.alt; } A_ALT;
+        lookup B_ALT { sub B by B.alt; } B_ALT;
+        feature calt {
+            sub A' lookup A_ALT B;
+            sub A B' lookup B_ALT;
+        } calt;
+        """
+    )
+    assert_PASS(check(ttFont))
+
+    # A glyph substituted only by a lookup no feature uses is not
+    ttFont = _font_with_features(
+        """
+        lookup B_ALT { sub B by B.alt; } B_ALT;
+        feature calt { sub A' B by A.alt; } calt;
+        """
+    )
+    message = assert_results_contain(check(ttFont), WARN, "unreachable-glyphs")
+    assert "B.alt" in message
+    assert "A.alt" not in message
@@ -0,0 +1,759 @@
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+#![deny(missing_docs, clippy::missing_docs_in_private_items)]
+
+//! # fontspector-wasmplugin
+//!
+//! This crate loads checks which have been compiled to WebAssembly components,
+//! and registers them with a fontspector [Registry] just like native checks.
+//! Unlike native plugins, WebAssembly plugins are sandboxed and portable:
+//! the same `.wasm` file runs on every platform, and inside fontspector-web.
+//!
+//! Plugins are run by an embedded interpreter. They may not import any
+//! functions, so they cannot touch the filesystem or the network; they can
+//! only compute on the data they are handed. Each run is limited in the amount
+//! of memory it may use and the number of instructions it may execute.
+//!
+//! ## The plugin interface
+//!
+//! A plugin is a WebAssembly component implementing the `plugin` world in
+//! this crate's `wit/plugin.wit`, which is also available as [WIT]. It exports
+//! two functions:
+//!
+//! * `describe`, which returns the plugin's checks, file types and profiles.
+//!   Each check says which file type it applies to; profiles take the same
+//!   form as profile TOML files.
+//! * `run`, which runs the check with the given ID on a font's binary data,
+//!   given its filename and the check's configuration as a JSON object, and
+//!   returns a list of statuses. An empty list is a pass.
+//!
+//! The embedded interpreter runs core WebAssembly, so fontspector implements
+//! the component's canonical ABI itself. It supports components containing a
+//! single core module using UTF-8 strings, which is what `wit-bindgen` and
+//! `wasm-tools component new` produce for a plugin with no imports.
+//!
+//! Each call to `run` happens in a freshly instantiated module, so plugins
+//! need not free anything they allocate, and post-return functions are not
+//! called.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use fontspector_checkapi::{prelude::*, Plugin, PluginError, PluginInfo};
+use serde_json::json;
+use wasmi::{Config, Engine, Linker, Memory, Module, Store, StoreLimits, StoreLimitsBuilder};
+use wasmparser::{
+    CanonicalFunction, CanonicalOption, ComponentExternalKind, ComponentTypeRef, Encoding, Parser,
+    Payload, Validator,
+};
+
+/// The WIT definition of the interface a plugin component implements
+pub const WIT: &str = include_str!("../wit/plugin.wit");
+
+/// The number of instructions (roughly) a plugin may execute in a single call
+pub const FUEL_PER_CALL: u64 = 2_000_000_000;
+
+/// The maximum amount of linear memory, in bytes, a plugin may use
+pub const MAX_MEMORY: usize = 1024 * 1024 * 1024;
+
+/// The WebAssembly engine shared by all plugins
+fn engine() -> &'static Engine {
+    static ENGINE: OnceLock<Engine> = OnceLock::new();
+    ENGINE.get_or_init(|| {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        Engine::new(&config)
+    })
+}
+
+/// Compiled plugin modules, by plugin name
+///
+/// All plugin checks share a single implementation function, which finds
+/// the module to run here using the plugin name in the check's metadata.
+/// Modules are shared so that a check can release the lock before running.
+fn modules() -> &'static RwLock<HashMap<String, Arc<Module>>> {
+    static MODULES: OnceLock<RwLock<HashMap<String, Arc<Module>>>> = OnceLock::new();
+    MODULES.get_or_init(Default::default)
+}
+
+/// Validate a plugin component and extract its core module
+///
+/// The component must import nothing but types, export the functions of
+/// the plugin world, and lift them from a single core module using UTF-8
+/// strings.
+fn core_module(wasm: &[u8]) -> Result<&[u8], String> {
+    Validator::new()
+        .validate_all(wasm)
+        .map_err(|e| format!("invalid WebAssembly: {}", e))?;
+    let mut module = None;
+    let mut exports = vec![];
+    let mut depth = 0;
+    for payload in Parser::new(0).parse_all(wasm) {
+        let payload = payload.map_err(|e| e.to_string())?;
+        match payload {
+            Payload::Version { encoding, .. } => {
+                if depth == 0 && encoding != Encoding::Component {
+                    return Err(
+                        "plugin is a core module, not a component; see the plugin interface"
+                            .to_string(),
+                    );
+                }
+                depth += 1;
+            }
+            Payload::End(_) => depth -= 1,
+            Payload::ComponentSection { .. } => {
+                return Err("plugin components may not contain nested components".to_string())
+            }
+            Payload::ModuleSection {
+                unchecked_range, ..
+            } => {
+                if module.is_some() {
+                    return Err("plugin component contains more than one core module".to_string());
+                }
+                module = wasm.get(unchecked_range.start as usize..unchecked_range.end as usize);
+            }
+            Payload::ComponentImportSection(imports) => {
+                for import in imports {
+                    let import = import.map_err(|e| e.to_string())?;
+                    if !matches!(import.ty, ComponentTypeRef::Type(_)) {
+                        return Err(format!(
+                            "plugin imports {}, but plugins may not import anything",
+                            import.name.name
+                        ));
+                    }
+                }
+            }
+            Payload::ComponentCanonicalSection(functions) => {
+                for function in functions {
+                    if let CanonicalFunction::Lift { options, .. } =
+                        function.map_err(|e| e.to_string())?
+                    {
+                        if options.iter().any(|option| {
+                            matches!(
+                                option,
+                                CanonicalOption::UTF16 | CanonicalOption::CompactUTF16
+                            )
+                        }) {
+                            return Err("plugins must use UTF-8 strings".to_string());
+                        }
+                    }
+                }
+            }
+            Payload::ComponentExportSection(section) => {
+                for export in section {
+                    let export = export.map_err(|e| e.to_string())?;
+                    if export.kind == ComponentExternalKind::Func {
+                        exports.push(export.name.name.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    for function in ["describe", "run"] {
+        if !exports.iter().any(|e| e == function) {
+            return Err(format!("plugin does not export `{}`", function));
+        }
+    }
+    module.ok_or_else(|| "plugin component contains no core module".to_string())
+}
+
+/// A plugin's description of itself, as returned by `describe`
+struct PluginDescription {
+    /// The checks the plugin provides
+    checks: Vec<CheckDescription>,
+    /// The file types the plugin provides, as names and glob patterns
+    file_types: Vec<(String, String)>,
+    /// The profiles the plugin provides, as names and TOML definitions
+    profiles: Vec<(String, String)>,
+}
+
+/// A plugin's description of one of its checks
+struct CheckDescription {
+    /// The check ID
+    id: String,
+    /// A short title for the check
+    title: String,
+    /// Why the check exists
+    rationale: String,
+    /// URLs of the issues which proposed the check
+    proposal: Vec<String>,
+    /// The file type the check applies to
+    applies_to: String,
+    /// Tags used to select the check
+    tags: Vec<String>,
+    /// The result codes the check can emit, with their default severities
+    codes: Vec<(String, StatusCode)>,
+    /// Whether the check is experimental
+    experimental: bool,
+}
+
+/// A view onto a plugin's linear memory, for lifting values returned by it
+///
+/// Offsets and sizes follow the canonical ABI's layout of the types in the
+/// plugin world. Every read is bounds-checked, so a misbehaving plugin
+/// produces an error rather than a panic.
+struct Lifter<'a> {
+    /// The plugin's linear memory
+    memory: &'a [u8],
+}
+
+impl Lifter<'_> {
+    /// Read some bytes from memory
+    fn bytes(&self, ptr: u32, len: u32) -> Result<&[u8], String> {
+        let start = ptr as usize;
+        start
+            .checked_add(len as usize)
+            .and_then(|end| self.memory.get(start..end))
+            .ok_or_else(|| "plugin returned an out-of-bounds pointer".to_string())
+    }
+
+    /// Read a byte from memory
+    fn u8(&self, ptr: u32) -> Result<u8, String> {
+        Ok(self.bytes(ptr, 1)?[0])
+    }
+
+    /// Read a little-endian 32-bit integer from memory
+    fn u32(&self, ptr: u32) -> Result<u32, String> {
+        let bytes = self.bytes(ptr, 4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Lift a `bool`
+    fn bool(&self, ptr: u32) -> Result<bool, String> {
+        Ok(self.u8(ptr)? != 0)
+    }
+
+    /// Lift a `string`, stored as a pointer and a length
+    fn string(&self, ptr: u32) -> Result<String, String> {
+        let bytes = self.bytes(self.u32(ptr)?, self.u32(ptr + 4)?)?;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|_| "plugin returned a string which is not UTF-8".to_string())
+    }
+
+    /// Lift an `option<string>`, stored as a discriminant and a string
+    fn option_string(&self, ptr: u32) -> Result<Option<String>, String> {
+        match self.u8(ptr)? {
+            0 => Ok(None),
+            1 => self.string(ptr + 4).map(Some),
+            _ => Err("plugin returned an invalid option".to_string()),
+        }
+    }
+
+    /// Lift a `list<T>` whose elements are `size` bytes long
+    fn list<T>(
+        &self,
+        ptr: u32,
+        size: u32,
+        lift: impl Fn(&Self, u32) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        let start = self.u32(ptr)?;
+        let len = self.u32(ptr + 4)?;
+        if start % 4 != 0 {
+            return Err("plugin returned a misaligned list".to_string());
+        }
+        let total = len
+            .checked_mul(size)
+            .ok_or_else(|| "plugin returned an impossibly long list".to_string())?;
+        self.bytes(start, total)?;
+        (0..len).map(|i| lift(self, start + i * size)).collect()
+    }
+
+    /// Lift a `list<string>`
+    fn strings(&self, ptr: u32) -> Result<Vec<String>, String> {
+        self.list(ptr, 8, Self::string)
+    }
+
+    /// Lift a `severity`
+    fn severity(&self, ptr: u32) -> Result<StatusCode, String> {
+        match self.u8(ptr)? {
+            0 => Ok(StatusCode::Skip),
+            1 => Ok(StatusCode::Pass),
+            2 => Ok(StatusCode::Info),
+            3 => Ok(StatusCode::Warn),
+            4 => Ok(StatusCode::Fail),
+            5 => Ok(StatusCode::Error),
+            _ => Err("plugin returned an invalid severity".to_string()),
+        }
+    }
+
+    /// Lift a `description`
+    fn description(&self, ptr: u32) -> Result<PluginDescription, String> {
+        Ok(PluginDescription {
+            checks: self.list(ptr, 60, Self::check_description)?,
+            file_types: self.list(ptr + 8, 16, Self::string_pair)?,
+            profiles: self.list(ptr + 16, 16, Self::string_pair)?,
+        })
+    }
+
+    /// Lift a `check-description`
+    fn check_description(&self, ptr: u32) -> Result<CheckDescription, String> {
+        Ok(CheckDescription {
+            id: self.string(ptr)?,
+            title: self.string(ptr + 8)?,
+            rationale: self.string(ptr + 16)?,
+            proposal: self.strings(ptr + 24)?,
+            applies_to: self.string(ptr + 32)?,
+            tags: self.strings(ptr + 40)?,
+            codes: self.list(ptr + 48, 12, |l, p| Ok((l.string(p)?, l.severity(p + 8)?)))?,
+            experimental: self.bool(ptr + 56)?,
+        })
+    }
+
+    /// Lift a record of two strings, i.e. a `file-type` or a `profile`
+    fn string_pair(&self, ptr: u32) -> Result<(String, String), String> {
+        Ok((self.string(ptr)?, self.string(ptr + 8)?))
+    }
+
+    /// Lift a `status`
+    fn status(&self, ptr: u32) -> Result<Status, String> {
+        Ok(Status {
+            severity: self.severity(ptr)?,
+            code: self.option_string(ptr + 4)?,
+            message: self.option_string(ptr + 16)?,
+            metadata: None,
+            overridden: None,
+        })
+    }
+}
+
+/// A running instance of a plugin module
+struct PluginInstance {
+    /// The store holding the instance's state
+    store: Store<StoreLimits>,
+    /// The instance itself
+    instance: wasmi::Instance,
+    /// The instance's linear memory
+    memory: Memory,
+}
+
+impl PluginInstance {
+    /// Instantiate a module in a fresh, resource-limited sandbox
+    fn new(module: &Module) -> Result<Self, String> {
+        let limits = StoreLimitsBuilder::new().memory_size(MAX_MEMORY).build();
+        let mut store = Store::new(engine(), limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(FUEL_PER_CALL).map_err(|e| e.to_string())?;
+        // No host functions are linked, so a plugin can only compute on what we give it
+        let linker = Linker::new(engine());
+        let instance = linker
+            .instantiate(&mut store, module)
+            .and_then(|pre| pre.start(&mut store))
+            .map_err(|e| format!("could not instantiate plugin: {}", e))?;
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or_else(|| "plugin does not export its memory".to_string())?;
+        Ok(PluginInstance {
+            store,
+            instance,
+            memory,
+        })
+    }
+
+    /// Lower a string or byte list into the plugin's memory, returning its pointer and length
+    fn lower(&mut self, bytes: &[u8]) -> Result<(i32, i32), String> {
+        let realloc = self
+            .instance
+            .get_typed_func::<(i32, i32, i32, i32), i32>(&self.store, "cabi_realloc")
+            .map_err(|e| format!("plugin does not export cabi_realloc: {}", e))?;
+        let len = i32::try_from(bytes.len()).map_err(|_| "argument too large".to_string())?;
+        let ptr = realloc
+            .call(&mut self.store, (0, 0, 1, len))
+            .map_err(|e| format!("cabi_realloc failed: {}", e))?;
+        self.memory
+            .write(&mut self.store, ptr as u32 as usize, bytes)
+            .map_err(|e| format!("could not write to plugin memory: {}", e))?;
+        Ok((ptr, len))
+    }
+
+    /// A view onto the plugin's memory
+    fn lifter(&self) -> Lifter<'_> {
+        Lifter {
+            memory: self.memory.data(&self.store),
+        }
+    }
+
+    /// Ask the plugin to describe itself
+    fn describe(&mut self) -> Result<PluginDescription, String> {
+        let describe = self
+            .instance
+            .get_typed_func::<(), i32>(&self.store, "describe")
+            .map_err(|e| format!("plugin does not export describe: {}", e))?;
+        let ptr = describe
+            .call(&mut self.store, ())
+            .map_err(|e| format!("describe failed: {}", e))?;
+        self.lifter().description(ptr as u32)
+    }
+
+    /// Run a check on a font
+    fn run(
+        &mut self,
+        check: &str,
+        filename: &str,
+        font: &[u8],
+        configuration: &str,
+    ) -> Result<Vec<Status>, String> {
+        let (check_ptr, check_len) = self.lower(check.as_bytes())?;
+        let (filename_ptr, filename_len) = self.lower(filename.as_bytes())?;
+        let (font_ptr, font_len) = self.lower(font)?;
+        let (config_ptr, config_len) = self.lower(configuration.as_bytes())?;
+        let run = self
+            .instance
+            .get_typed_func::<(i32, i32, i32, i32, i32, i32, i32, i32), i32>(&self.store, "run")
+            .map_err(|e| format!("plugin does not export run: {}", e))?;
+        let ptr = run
+            .call(
+                &mut self.store,
+                (
+                    check_ptr,
+                    check_len,
+                    filename_ptr,
+                    filename_len,
+                    font_ptr,
+                    font_len,
+                    config_ptr,
+                    config_len,
+                ),
+            )
+            .map_err(|e| format!("check failed to run: {}", e))?;
+        self.lifter().list(ptr as u32, 28, Lifter::status)
+    }
+}
+
+/// A WebAssembly plugin
+///
+/// Create one with [WasmPlugin::new] and register it like any other [Plugin].
+pub struct WasmPlugin {
+    /// The name of the plugin, usually its filename
+    name: String,
+    /// What the plugin provides
+    description: PluginDescription,
+}
+
+impl WasmPlugin {
+    /// Compile a plugin from its WebAssembly component and ask it to describe itself
+    pub fn new(name: &str, wasm: &[u8]) -> Result<Self, PluginError> {
+        let module = core_module(wasm)
+            .and_then(|core| Module::new(engine(), core).map_err(|e| e.to_string()))
+            .map_err(PluginError::Load)?;
+        let description = PluginInstance::new(&module)
+            .and_then(|mut instance| instance.describe())
+            .map_err(PluginError::Load)?;
+        modules()
+            .write()
+            .map_err(|e| PluginError::Load(e.to_string()))?
+            .insert(name.to_string(), Arc::new(module));
+        Ok(WasmPlugin {
+            name: name.to_string(),
+            description,
+        })
+    }
+
+    /// A summary of what the plugin provides
+    pub fn info(&self) -> PluginInfo {
+        PluginInfo {
+            path: self.name.clone(),
+            checkapi_version: None,
+            checks: self
+                .description
+                .checks
+                .iter()
+                .map(|c| c.id.clone())
+                .collect(),
+            profiles: self
+                .description
+                .profiles
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect(),
+            filetypes: self
+                .description
+                .file_types
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect(),
+        }
+    }
+}
+
+/// Leak a list of strings, so that they can live in a [Check]
+fn leak_strings(strings: &[String]) -> &'static [&'static str] {
+    strings
+        .iter()
+        .map(|s| s.clone().leak() as &'static str)
+        .collect::<Vec<&'static str>>()
+        .leak()
+}
+
+impl Plugin for WasmPlugin {
+    fn register(&self, cr: &mut Registry) -> Result<(), String> {
+        for (name, pattern) in self.description.file_types.iter() {
+            cr.register_filetype(name, FileType::new(pattern.clone().leak()));
+        }
+        for check in self.description.checks.iter() {
+            let codes: &'static [ResultCode<'static>] = check
+                .codes
+                .iter()
+                .map(|(code, severity)| ResultCode {
+                    code: code.clone().leak(),
+                    severity: *severity,
+                })
+                .collect::<Vec<_>>()
+                .leak();
+            let metadata = json!({
+                "plugin": self.name,
+                "check": check.id,
+            });
+            log::info!("Registered WebAssembly check: {}", check.id);
+            cr.register_check(Check {
+                id: check.id.clone().leak(),
+                title: check.title.clone().leak(),
+                rationale: check.rationale.clone().leak(),
+                proposal: leak_strings(&check.proposal),
+                hotfix: None,
+                fix_source: None,
+                applies_to: check.applies_to.clone().leak(),
+                flags: CheckFlags {
+                    experimental: check.experimental,
+                    location_sensitive: false,
+                },
+                tags: leak_strings(&check.tags),
+                codes,
+                implementation: CheckImplementation::CheckOne(&wasm_checkrunner),
+                _metadata: Some(metadata.to_string().leak()),
+            });
+        }
+        for (name, definition) in self.description.profiles.iter() {
+            let profile = Profile::from_toml(definition)
+                .map_err(|e| format!("Could not parse profile {}: {}", name, e))?;
+            cr.register_profile(name, profile)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run a check from a WebAssembly plugin
+///
+/// The plugin and check to run are found in the check's metadata.
+fn wasm_checkrunner(t: &Testable, context: &Context) -> CheckFnResult {
+    let plugin = context
+        .check_metadata
+        .get("plugin")
+        .and_then(|p| p.as_str())
+        .ok_or_else(|| CheckError::Error("No plugin specified".to_string()))?;
+    let check = context
+        .check_metadata
+        .get("check")
+        .and_then(|c| c.as_str())
+        .ok_or_else(|| CheckError::Error("No check specified".to_string()))?;
+    let module = modules()
+        .read()
+        .map_err(|e| CheckError::Error(e.to_string()))?
+        .get(plugin)
+        .cloned()
+        .ok_or_else(|| CheckError::Error(format!("Plugin {} is not loaded", plugin)))?;
+    let configuration = serde_json::to_string(&context.configuration)?;
+    let statuses = PluginInstance::new(&module)
+        .and_then(|mut instance| {
+            instance.run(
+                check,
+                &t.filename.to_string_lossy(),
+                &t.contents,
+                &configuration,
+            )
+        })
+        .map_err(|e| CheckError::Error(format!("Plugin {}: {}", plugin, e)))?;
+    return_result(statuses)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, run_check},
+        TEST_FILE,
+    };
+    use wit_component::{embed_component_metadata, ComponentEncoder, StringEncoding};
+    use wit_parser::Resolve;
+
+    use super::*;
+
+    /// Lays out canonical ABI values in a data segment starting at address 0
+    #[derive(Default)]
+    struct Data(Vec<u8>);
+
+    impl Data {
+        /// Append a value, aligned to four bytes, returning its address
+        fn push(&mut self, bytes: &[u8]) -> u32 {
+            while !self.0.len().is_multiple_of(4) {
+                self.0.push(0);
+            }
+            let ptr = self.0.len() as u32;
+            self.0.extend_from_slice(bytes);
+            ptr
+        }
+
+        /// Append a string's contents, returning its lowered pointer and length
+        fn string(&mut self, s: &str) -> Vec<u8> {
+            let ptr = self.push(s.as_bytes());
+            [ptr.to_le_bytes(), (s.len() as u32).to_le_bytes()].concat()
+        }
+
+        /// Append a list's elements, returning its lowered pointer and length
+        fn list(&mut self, elements: &[Vec<u8>]) -> Vec<u8> {
+            let ptr = self.push(&elements.concat());
+            [ptr.to_le_bytes(), (elements.len() as u32).to_le_bytes()].concat()
+        }
+
+        /// The data as a WAT string literal
+        fn wat(&self) -> String {
+            self.0.iter().map(|b| format!("\\{:02x}", b)).collect()
+        }
+    }
+
+    /// Build a component for the plugin world from a core module in WAT
+    fn component(wat: &str) -> Vec<u8> {
+        let mut resolve = Resolve::default();
+        let package = resolve.push_str("plugin.wit", WIT).unwrap();
+        let world = resolve.select_world(&[package], None).unwrap();
+        let mut module = wat::parse_str(wat).unwrap();
+        embed_component_metadata(&mut module, &resolve, world, StringEncoding::UTF8, false)
+            .unwrap();
+        ComponentEncoder::default()
+            .module(&module)
+            .unwrap()
+            .validate(true)
+            .encode()
+            .unwrap()
+    }
+
+    /// A plugin which fails fonts smaller than 1000 bytes
+    fn test_plugin() -> Vec<u8> {
+        let mut data = Data::default();
+        let mut check = [
+            data.string("wasmtest/not_tiny"),
+            data.string("Font is not tiny"),
+            data.string("Tiny fonts are suspicious"),
+            data.list(&[]),
+            data.string("TTF"),
+            data.list(&[]),
+        ]
+        .concat();
+        let code = [data.string("too-small"), vec![4, 0, 0, 0]].concat();
+        check.extend(data.list(&[code]));
+        check.extend([0, 0, 0, 0]);
+        let profile = [
+            data.string("wasmtest"),
+            data.string("[sections]\nWASM = [\"wasmtest/not_tiny\"]\n"),
+        ]
+        .concat();
+        let description = [data.list(&[check]), data.list(&[]), data.list(&[profile])].concat();
+        let description = data.push(&description);
+        let too_small = [
+            vec![4, 0, 0, 0, 1, 0, 0, 0],
+            data.string("too-small"),
+            vec![1, 0, 0, 0],
+            data.string("Font is too small"),
+        ]
+        .concat();
+        let too_small = data.list(&[too_small]);
+        let too_small = data.push(&too_small);
+        let pass = data.list(&[]);
+        let pass = data.push(&pass);
+        component(&format!(
+            r#"
+            (module
+              (memory (export "memory") 1)
+              (data (i32.const 0) "{data}")
+              (global $next (mut i32) (i32.const 4096))
+              (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32)
+                (local $ptr i32)
+                (local.set $ptr (global.get $next))
+                (global.set $next (i32.add (global.get $next) (local.get 3)))
+                (drop (memory.grow (i32.add (i32.shr_u (local.get 3) (i32.const 16)) (i32.const 1))))
+                (local.get $ptr))
+              (func (export "describe") (result i32)
+                (i32.const {description}))
+              (func (export "run")
+                (param i32 i32 i32 i32 i32 i32 i32 i32) (result i32)
+                ;; Parameter 5 is the length of the font
+                (if (result i32) (i32.lt_u (local.get 5) (i32.const 1000))
+                  (then (i32.const {too_small}))
+                  (else (i32.const {pass})))))
+            "#,
+            data = data.wat(),
+        ))
+    }
+
+    #[test]
+    fn test_wasm_plugin() {
+        let plugin = WasmPlugin::new("wasmtest.wasm", &test_plugin()).unwrap();
+        assert_eq!(plugin.info().checks, vec!["wasmtest/not_tiny"]);
+        assert_eq!(plugin.info().profiles, vec!["wasmtest"]);
+
+        let mut registry = Registry::new();
+        plugin.register(&mut registry).unwrap();
+        assert!(registry.get_profile("wasmtest").is_some());
+        let check = registry.checks.get("wasmtest/not_tiny").unwrap().clone();
+        assert_eq!(check.applies_to, "TTF");
+        assert_eq!(check.codes.len(), 1);
+
+        assert_pass(run_check(
+            check.clone(),
+            TEST_FILE!("nunito/Nunito-Regular.ttf"),
+        ));
+        assert_results_contain(
+            run_check(check, Testable::new_with_contents("Tiny.ttf", vec![0; 10])),
+            StatusCode::Fail,
+            Some("too-small".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_plugins_must_be_components() {
+        let wasm = wat::parse_str(
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "describe") (result i32) (i32.const 0)))"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            WasmPlugin::new("core.wasm", &wasm),
+            Err(PluginError::Load(e)) if e.contains("not a component")
+        ));
+    }
+
+    #[test]
+    fn test_plugins_get_no_imports() {
+        let mut resolve = Resolve::default();
+        let package = resolve
+            .push_str(
+                "sneaky.wit",
+                &WIT.replace("world plugin {", "world plugin {\n  import open: func();"),
+            )
+            .unwrap();
+        let world = resolve.select_world(&[package], None).unwrap();
+        let mut module = wat::parse_str(
+            r#"(module
+                (import "$root" "open" (func))
+                (memory (export "memory") 1)
+                (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32) (i32.const 0))
+                (func (export "describe") (result i32) (i32.const 0))
+                (func (export "run") (param i32 i32 i32 i32 i32 i32 i32 i32) (result i32)
+                  (i32.const 0)))"#,
+        )
+        .unwrap();
+        embed_component_metadata(&mut module, &resolve, world, StringEncoding::UTF8, false)
+            .unwrap();
+        let wasm = ComponentEncoder::default()
+            .module(&module)
+            .unwrap()
+            .encode()
+            .unwrap();
+        assert!(matches!(
+            WasmPlugin::new("sneaky.wasm", &wasm),
+            Err(PluginError::Load(e)) if e.contains("may not import")
+        ));
+    }
+}
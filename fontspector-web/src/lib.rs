@@ -2,11 +2,22 @@ use js_sys::{Reflect, Uint8Array};
 use wasm_bindgen::prelude::*;
 extern crate console_error_panic_hook;
 use fontspector_checkapi::{
-    Check, CheckResult, Context, Plugin, Registry, Testable, TestableCollection, TestableType,
+    parse_waivers, Check, CheckResult, CheckSelection, Context, Override, Plugin, Registry,
+    Testable, TestableCollection, TestableType, WAIVERS_FILENAME,
 };
+use fontspector_wasmplugin::WasmPlugin;
+#[cfg(feature = "adobe")]
+use profile_adobe::Adobe;
+#[cfg(feature = "designspace")]
+use profile_designspace::Designspace;
+use profile_fontwerk::Fontwerk;
 use profile_googlefonts::GoogleFonts;
 use profile_iso15008::Iso15008;
+#[cfg(feature = "microsoft")]
+use profile_microsoft::Microsoft;
+use profile_notofonts::NotoFonts;
 use profile_opentype::OpenType;
+use profile_typenetwork::TypeNetwork;
 use profile_universal::Universal;
 
 #[wasm_bindgen]
@@ -15,7 +26,7 @@ pub fn version() -> String {
 }
 
 #[wasm_bindgen]
-pub fn check_fonts(fonts: &JsValue, profile: &str) -> Result<String, JsValue> {
+pub fn check_fonts(fonts: &JsValue, profile: &str, plugins: &JsValue) -> Result<String, JsValue> {
     console_error_panic_hook::set_once();
 
     let mut registry = Registry::new();
@@ -31,7 +42,39 @@ pub fn check_fonts(fonts: &JsValue, profile: &str) -> Result<String, JsValue> {
     Iso15008
         .register(&mut registry)
         .expect("Couldn't register iso15008 profile, fontspector bug");
-    let testables: Vec<Testable> = Reflect::own_keys(fonts)?
+    Fontwerk
+        .register(&mut registry)
+        .expect("Couldn't register fontwerk profile, fontspector bug");
+    NotoFonts
+        .register(&mut registry)
+        .expect("Couldn't register notofonts profile, fontspector bug");
+    TypeNetwork
+        .register(&mut registry)
+        .expect("Couldn't register typenetwork profile, fontspector bug");
+    #[cfg(feature = "microsoft")]
+    Microsoft
+        .register(&mut registry)
+        .expect("Couldn't register microsoft profile, fontspector bug");
+    #[cfg(feature = "adobe")]
+    Adobe
+        .register(&mut registry)
+        .expect("Couldn't register adobe profile, fontspector bug");
+    #[cfg(feature = "designspace")]
+    Designspace
+        .register(&mut registry)
+        .expect("Couldn't register designspace profile, fontspector bug");
+    // WebAssembly plugins, as a map of filename to contents
+    if !plugins.is_undefined() && !plugins.is_null() {
+        for name in Reflect::own_keys(plugins)?.into_iter() {
+            let wasm = Uint8Array::new(&Reflect::get(plugins, &name)?).to_vec();
+            let name = name.as_string().unwrap_or_default();
+            WasmPlugin::new(&name, &wasm)
+                .map_err(|e| e.to_string())?
+                .register(&mut registry)
+                .map_err(|e| format!("Could not register plugin {}: {}", name, e))?;
+        }
+    }
+    let mut testables: Vec<Testable> = Reflect::own_keys(fonts)?
         .into_iter()
         .map(|filename| {
             let file: JsValue = Reflect::get(fonts, &filename).unwrap();
@@ -44,6 +87,19 @@ pub fn check_fonts(fonts: &JsValue, profile: &str) -> Result<String, JsValue> {
             }
         })
         .collect();
+    // A waivers file may be dropped in alongside the fonts
+    let mut overrides = vec![];
+    if let Some(index) = testables
+        .iter()
+        .position(|t| t.filename.as_os_str() == WAIVERS_FILENAME)
+    {
+        let waivers = testables.remove(index);
+        overrides = parse_waivers(&String::from_utf8_lossy(&waivers.contents))
+            .map_err(|e| format!("Could not parse {}: {}", WAIVERS_FILENAME, e))?
+            .into_iter()
+            .map(Override::from)
+            .collect();
+    }
     let collection = TestableCollection::from_testables(testables, None);
 
     let profile = registry
@@ -56,13 +112,15 @@ pub fn check_fonts(fonts: &JsValue, profile: &str) -> Result<String, JsValue> {
         check_metadata: serde_json::Value::Null,
         full_lists: false,
         cache: Default::default(),
-        overrides: vec![],
+        overrides,
+        today: chrono::Utc::now().date_naive(),
+        sweep_location: None,
     };
     let all_testables: Vec<TestableType> = collection.collection_and_files().collect();
 
+    let selection = CheckSelection::default();
     let checkorder: Vec<(String, &TestableType, &Check, Context)> = profile.check_order(
-        &None,
-        &None,
+        &selection,
         &registry,
         context,
         serde_json::Map::new(),
@@ -16,6 +16,11 @@ import {
 let hbjs = window["hbjs"];
 
 let fonts: Record<string, FontInfo> = {};
+// WebAssembly check plugins dropped alongside the fonts
+let plugins: Record<string, Uint8Array> = {};
+// A waivers file dropped alongside the fonts
+const WAIVERS_FILENAME = ".fontspector-waivers.toml";
+let waivers: Uint8Array | null = null;
 
 declare var CmarkGFM: any;
 const tinysort = require("tinysort");
@@ -24,6 +29,7 @@ const SORT_RESULT: Record<StatusCode, string> = {
   FAIL: "aa",
   WARN: "bb",
   INFO: "cc",
+  WAIVED: "cd",
   ERROR: "dd",
   PASS: "ee",
   SKIP: "zz",
@@ -220,11 +226,12 @@ const PROFILES: Record<string, string> = {
   universal: "Universal (community best practices)",
   googlefonts: "Google Fonts",
   iso15008: "ISO 15008 (in-car accessibility)",
-  // adobefonts: "Adobe Fonts",
+  fontwerk: "Fontwerk",
+  notofonts: "Noto Fonts",
+  typenetwork: "Type Network",
+  adobefonts: "Adobe Fonts",
   // fontbureau: "Font Bureau",
-  // typenetwork: "Type Network",
-  // fontwerk: "Fontwerk",
-  // microsoft: "Microsoft",
+  microsoft: "Microsoft",
 };
 
 function addProfile(profilename: string, col: number) {
@@ -335,6 +342,14 @@ $(function () {
       const reader = new FileReader();
       reader.addEventListener("loadend", function (event) {
         var filedata = new Uint8Array(event.target.result as ArrayBuffer);
+        if (file.name.endsWith(".wasm")) {
+          plugins[file.name] = filedata;
+          return;
+        }
+        if (file.name == WAIVERS_FILENAME) {
+          waivers = filedata;
+          return;
+        }
         var blob = hbjs.createBlob(filedata);
         var face = hbjs.createFace(blob, 0);
         var font = hbjs.createFont(face);
@@ -366,8 +381,11 @@ $(function () {
     for (var filename of Object.keys(fonts)) {
       files[filename] = fonts[filename].file;
     }
+    if (waivers) {
+      files[WAIVERS_FILENAME] = waivers;
+    }
     console.log(files);
-    fbWorker.postMessage({ profile, files, loglevels, fulllists });
+    fbWorker.postMessage({ profile, files, plugins, loglevels, fulllists });
   });
   $("#listchecksbtn").click(function () {
     fbWorker.postMessage({ id: "listchecks" });
@@ -7,7 +7,14 @@ export type FontInfo = {
   font: any;
 }
 
-export type StatusCode = "FAIL" | "WARN" | "INFO" | "ERROR" | "PASS" | "SKIP";
+export type StatusCode =
+  | "FAIL"
+  | "WARN"
+  | "WAIVED"
+  | "INFO"
+  | "ERROR"
+  | "PASS"
+  | "SKIP";
 
 
 interface ErrorMessage {
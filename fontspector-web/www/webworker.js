@@ -21,7 +21,7 @@ async function init() {
 
   self.onmessage = async (event) => {
     // make sure loading is done
-    const { id, files, profile, loglevels, fulllists } = event.data;
+    const { id, files, profile, plugins, loglevels, fulllists } = event.data;
     self.profile = profile;
     if (id == "justload") {
       return;
@@ -49,7 +49,7 @@ async function init() {
     self.fulllists = fulllists;
     self.exclude_checks = EXCLUDE_CHECKS;
     try {
-      const results = JSON.parse(wasm.check_fonts(files, profile));
+      const results = JSON.parse(wasm.check_fonts(files, profile, plugins));
       self.postMessage(results);
     } catch (error) {
       self.postMessage({ error: error.message, id });
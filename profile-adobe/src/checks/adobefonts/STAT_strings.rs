@@ -15,7 +15,8 @@ use skrifa::raw::{tables::stat::AxisValue, TableProvider};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/2863",
-    title = "Check correctness of STAT table strings"
+    title = "Check correctness of STAT table strings",
+    tags = ["naming"]
 )]
 fn STAT_strings(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -11,7 +11,8 @@ use skrifa::{raw::TableProvider, string::StringId};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3714",
-    title = "Font has a good nameID 1, Windows/Unicode/US-English `name` table record?"
+    title = "Font has a good nameID 1, Windows/Unicode/US-English `name` table record?",
+    tags = ["naming"]
 )]
 fn nameid_1_win_english(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -37,4 +37,5 @@ impl fontspector_checkapi::Plugin for Adobe {
     }
 }
 
-pluginator::plugin_implementation!(fontspector_checkapi::Plugin, Adobe);
+#[cfg(all(feature = "plugin", not(target_family = "wasm")))]
+fontspector_checkapi::declare_plugin!(Adobe);
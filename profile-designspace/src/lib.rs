@@ -1,6 +1,6 @@
 use fontspector_checkapi::{prelude::*, skip};
 
-struct Designspace;
+pub struct Designspace;
 
 fn direction(contour: &norad::Contour) -> bool {
     let mut total = 0.0;
@@ -16,7 +16,8 @@ fn direction(contour: &norad::Contour) -> bool {
     rationale = "Make sure the paths have the same direction across all masters.",
     proposal = "chat",
     title = "Check path direction.",
-    applies_to = "DESIGNSPACE"
+    applies_to = "DESIGNSPACE",
+    tags = ["outline"]
 )]
 fn path_direction(t: &Testable, _context: &Context) -> CheckFnResult {
     let ds_contents = std::str::from_utf8(&t.contents)
@@ -93,5 +94,5 @@ impl fontspector_checkapi::Plugin for Designspace {
     }
 }
 
-#[cfg(not(target_family = "wasm"))]
-pluginator::plugin_implementation!(fontspector_checkapi::Plugin, Designspace);
+#[cfg(all(feature = "plugin", not(target_family = "wasm")))]
+fontspector_checkapi::declare_plugin!(Designspace);
@@ -0,0 +1,6 @@
+mod names_match_default_fvar;
+pub use names_match_default_fvar::names_match_default_fvar;
+mod style_linking;
+pub use style_linking::style_linking;
+mod vendor_id;
+pub use vendor_id::vendor_id;
@@ -0,0 +1,105 @@
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+use skrifa::MetadataProvider;
+
+#[check(
+    id = "fontwerk/names_match_default_fvar",
+    rationale = "
+        Check if the font names match default fvar instance name.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/3698",
+    title = "Checking if names match default fvar instance name.",
+    tags = ["naming", "variable"],
+    codes = {
+        "not-variable": SKIP,
+        "missing-default-name-id": FAIL,
+        "missing-name-id": FAIL,
+        "bad-name": FAIL,
+    }
+)]
+fn names_match_default_fvar(t: &Testable, _context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    skip!(
+        !f.is_variable_font(),
+        "not-variable",
+        "Font is not a variable font"
+    );
+    let font = f.font();
+    let axes = font.axes();
+    let Some(default_name_id) = font
+        .named_instances()
+        .iter()
+        .find(|instance| {
+            axes.iter()
+                .zip(instance.user_coords())
+                .all(|(axis, coord)| axis.default_value() == coord)
+        })
+        .map(|instance| instance.subfamily_name_id())
+    else {
+        return Ok(Status::just_one_fail(
+            "missing-default-name-id",
+            "fvar is missing a default instance name ID.",
+        ));
+    };
+    let Some(subfamily_name) = font
+        .localized_strings(default_name_id)
+        .english_or_first()
+        .map(|s| s.chars().collect::<String>())
+    else {
+        return Ok(Status::just_one_fail(
+            "missing-name-id",
+            &format!(
+                "Name ID {} stored in fvar instance is missing in name table.",
+                default_name_id.to_u16()
+            ),
+        ));
+    };
+    let family_name = f.best_familyname().unwrap_or_default();
+    let font_name = format!(
+        "{} {}",
+        family_name,
+        f.best_subfamilyname().unwrap_or_default()
+    );
+    let default_name = format!("{} {}", family_name, subfamily_name);
+    Ok(if font_name != default_name {
+        Status::just_one_fail(
+            "bad-name",
+            &format!(
+                "Name {} does not match fvar default name {}",
+                font_name, default_name
+            ),
+        )
+    } else {
+        Status::just_one_pass()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, run_check, set_name_entry},
+        StatusCode, TEST_FILE,
+    };
+    use skrifa::string::StringId;
+
+    #[test]
+    fn test_names_match_default_fvar() {
+        let mut testable = TEST_FILE!("varfont/Oswald-VF.ttf");
+        assert_pass(run_check(names_match_default_fvar, testable.clone()));
+
+        set_name_entry(
+            &mut testable,
+            3,
+            1,
+            0x409,
+            StringId::SUBFAMILY_NAME,
+            "Light".to_string(),
+        );
+        assert_results_contain(
+            run_check(names_match_default_fvar, testable),
+            StatusCode::Fail,
+            Some("bad-name".to_string()),
+        );
+    }
+}
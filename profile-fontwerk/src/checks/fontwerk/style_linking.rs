@@ -0,0 +1,102 @@
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
+use skrifa::{
+    raw::{
+        tables::{head::MacStyle, os2::SelectionFlags},
+        TableProvider,
+    },
+    string::StringId,
+};
+
+#[check(
+    id = "fontwerk/style_linking",
+    rationale = "
+        Look for possible style linking issues.
+    ",
+    proposal = "https://github.com/googlefonts/noto-fonts/issues/2269",
+    title = "Checking style linking entries.",
+    codes = {
+        "style-linking-issue": FAIL,
+    }
+)]
+fn style_linking(t: &Testable, _context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let style = f.style().unwrap_or_default();
+    let is_bold = style == "Bold" || style == "BoldItalic";
+    let is_italic = style.ends_with("Italic");
+    let fs_selection = f.get_os2_fsselection()?;
+    let mac_style = f.font().head()?.mac_style();
+    let subfamily_is_regular = f
+        .get_name_entry_strings(StringId::SUBFAMILY_NAME)
+        .any(|name| name == "Regular");
+
+    let mut problems = vec![];
+    if is_bold {
+        if !fs_selection.contains(SelectionFlags::BOLD) {
+            problems.push("OS/2 fsSelection flag should be (most likely) 'Bold'.".to_string());
+        }
+        if !mac_style.contains(MacStyle::BOLD) {
+            problems.push("head macStyle flag should be (most likely) 'Bold'.".to_string());
+        }
+        if subfamily_is_regular {
+            problems.push(format!(
+                "name ID {} (subfamily) should not be set to 'Regular'.",
+                StringId::SUBFAMILY_NAME.to_u16()
+            ));
+        }
+    }
+    if is_italic {
+        if !fs_selection.contains(SelectionFlags::ITALIC) {
+            problems.push("OS/2 fsSelection flag should be (most likely) 'Italic'.".to_string());
+        }
+        if !mac_style.contains(MacStyle::ITALIC) {
+            problems.push("head macStyle flag should be (most likely) 'Italic'.".to_string());
+        }
+        if subfamily_is_regular {
+            problems.push(format!(
+                "name ID {} (subfamily) should not be set to 'Regular'.",
+                StringId::SUBFAMILY_NAME.to_u16()
+            ));
+        }
+    }
+    return_result(
+        problems
+            .into_iter()
+            .map(|message| Status::fail("style-linking-issue", &message))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, run_check},
+        StatusCode, TEST_FILE,
+    };
+
+    #[test]
+    fn test_style_linking() {
+        assert_pass(run_check(
+            style_linking,
+            TEST_FILE!("montserrat/Montserrat-Regular.ttf"),
+        ));
+        assert_pass(run_check(
+            style_linking,
+            TEST_FILE!("montserrat/Montserrat-Bold.ttf"),
+        ));
+        assert_pass(run_check(
+            style_linking,
+            TEST_FILE!("montserrat/Montserrat-BoldItalic.ttf"),
+        ));
+
+        // A regular font masquerading as a bold one
+        let mut testable = TEST_FILE!("montserrat/Montserrat-Regular.ttf");
+        testable.filename = "Montserrat-Bold.ttf".into();
+        assert_results_contain(
+            run_check(style_linking, testable),
+            StatusCode::Fail,
+            Some("style-linking-issue".to_string()),
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
+use skrifa::raw::TableProvider;
+
+const SUGGESTED_VENDOR_ID: &str = "WERK";
+
+#[check(
+    id = "fontwerk/vendor_id",
+    rationale = "
+        Vendor ID must be WERK for Fontwerk fonts.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/3579",
+    title = "Checking OS/2 achVendID.",
+    codes = {
+        "bad-vendor-id": FAIL,
+    }
+)]
+fn vendor_id(t: &Testable, _context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let vendor = f.font().os2()?.ach_vend_id().to_string();
+    Ok(if vendor != SUGGESTED_VENDOR_ID {
+        Status::just_one_fail(
+            "bad-vendor-id",
+            &format!(
+                "OS/2 VendorID is '{}', but should be '{}'.",
+                vendor, SUGGESTED_VENDOR_ID
+            ),
+        )
+    } else {
+        Status::just_one_pass()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, replace_table, run_check},
+        StatusCode, TEST_FILE,
+    };
+    use skrifa::{FontRef, Tag};
+    use write_fonts::{from_obj::FromTableRef, tables::os2::Os2};
+
+    #[test]
+    fn test_vendor_id() {
+        let mut testable = TEST_FILE!("montserrat/Montserrat-Regular.ttf");
+        assert_results_contain(
+            run_check(vendor_id, testable.clone()),
+            StatusCode::Fail,
+            Some("bad-vendor-id".to_string()),
+        );
+
+        let font = FontRef::new(&testable.contents).unwrap();
+        let mut os2 = Os2::from_table_ref(&font.os2().unwrap());
+        os2.ach_vend_id = Tag::new(b"WERK");
+        replace_table(&mut testable, &os2);
+        assert_pass(run_check(vendor_id, testable));
+    }
+}
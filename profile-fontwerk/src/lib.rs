@@ -0,0 +1,39 @@
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+mod checks;
+
+use fontspector_checkapi::{ProfileBuilder, Registry};
+use serde_json::json;
+use std::collections::HashMap;
+
+pub struct Fontwerk;
+impl fontspector_checkapi::Plugin for Fontwerk {
+    fn register(&self, cr: &mut Registry) -> Result<(), String> {
+        let builder = ProfileBuilder::new()
+            .include_profile("googlefonts")
+            .add_section("Fontwerk Checks")
+            .add_and_register_check(checks::fontwerk::names_match_default_fvar)
+            .add_and_register_check(checks::fontwerk::style_linking)
+            .add_and_register_check(checks::fontwerk::vendor_id)
+            .exclude_check("googlefonts/canonical_filename")
+            .exclude_check("googlefonts/family/italics_have_roman_counterparts")
+            .exclude_check("googlefonts/font_copyright")
+            .exclude_check("googlefonts/fstype")
+            .exclude_check("googlefonts/gasp")
+            .exclude_check("googlefonts/metadata/includes_production_subsets")
+            .exclude_check("googlefonts/meta/script_lang_tags")
+            .exclude_check("googlefonts/name/description_max_length")
+            .exclude_check("googlefonts/name/line_breaks")
+            .exclude_check("googlefonts/production_glyphs_similarity")
+            .exclude_check("googlefonts/vendor_id")
+            .exclude_check("googlefonts/version_bump")
+            .exclude_check("fontdata_namecheck")
+            .with_configuration_defaults(
+                "file_size",
+                HashMap::from([
+                    ("WARN_SIZE".to_string(), json!(1048576)), // 1Mb
+                    ("FAIL_SIZE".to_string(), json!(9437184)), // 9Mb
+                ]),
+            );
+        builder.build("fontwerk", cr)
+    }
+}
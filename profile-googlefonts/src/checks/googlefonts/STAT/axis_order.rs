@@ -15,7 +15,8 @@ use skrifa::raw::TableProvider;
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3049",
     title = "Check axis ordering on the STAT table.",
-    implementation = "all"
+    implementation = "all",
+    tags = ["variable"]
 )]
 fn axis_order(c: &TestableCollection, _context: &Context) -> CheckFnResult {
     let fonts = TTF.from_collection(c);
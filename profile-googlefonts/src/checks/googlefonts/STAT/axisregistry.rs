@@ -80,7 +80,8 @@ fn process_axis(
     proposal = "https://github.com/fonttools/fontbakery/issues/3022",
     title = "
     Validate STAT particle names and values match the fallback names in GFAxisRegistry.
-    "
+    ",
+    tags = ["variable"]
 )]
 fn axisregistry(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -113,7 +113,8 @@ fn stat_axis_values(f: &FontRef) -> Result<HashMap<(String, String), SimpleAxisV
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3800",
-    title = "Check a font's STAT table contains compulsory Axis Values."
+    title = "Check a font's STAT table contains compulsory Axis Values.",
+    tags = ["variable"]
 )]
 fn compulsory_axis_values(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
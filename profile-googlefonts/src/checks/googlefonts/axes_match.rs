@@ -15,7 +15,8 @@ use crate::{
     ",
     proposal = "None",
     title = "Check if the axes match between the font and the Google Fonts version.",
-    implementation = "all"
+    implementation = "all",
+    tags = ["network", "variable"]
 )]
 fn axes_match(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
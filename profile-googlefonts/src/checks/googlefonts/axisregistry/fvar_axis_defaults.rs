@@ -30,7 +30,8 @@ use google_fonts_axisregistry::AxisRegistry;
     proposal = "https://github.com/fonttools/fontbakery/issues/3141",
     title = "
     Validate defaults on fvar table match registered fallback names in GFAxisRegistry.
-    "
+    ",
+    tags = ["variable"]
 )]
 fn fvar_axis_defaults(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -16,7 +16,8 @@ use crate::network_conditions::is_listed_on_google_fonts;
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/2797",
-    title = "Check font follows the Google Fonts CJK vertical metric schema"
+    title = "Check font follows the Google Fonts CJK vertical metric schema",
+    tags = ["network"]
 )]
 fn cjk_vertical_metrics(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -11,7 +11,8 @@ use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3244",
     title = "Check if the vertical metrics of a CJK family are similar to the same
-family hosted on Google Fonts."
+family hosted on Google Fonts.",
+    tags = ["network"]
 )]
 fn cjk_vertical_metrics_regressions(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -16,7 +16,8 @@ use crate::network_conditions::get_url;
     proposal = "https://github.com/fonttools/fontbakery/issues/4110",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
     title = "Does DESCRIPTION file contain broken links?",
-    applies_to = "DESC"
+    applies_to = "DESC",
+    tags = ["network"]
 )]
 fn broken_links(desc: &Testable, context: &Context) -> CheckFnResult {
     let mut problems = vec![];
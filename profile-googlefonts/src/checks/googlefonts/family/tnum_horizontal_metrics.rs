@@ -1,6 +1,5 @@
 use fontspector_checkapi::{prelude::*, FileTypeConvert};
 use hashbrown::{HashMap, HashSet};
-use skrifa::raw::TableProvider;
 
 #[check(
     id = "googlefonts/family/tnum_horizontal_metrics",
@@ -26,19 +25,27 @@ fn tnum_horizontal_metrics(c: &TestableCollection, context: &Context) -> CheckFn
             message: "No sibling fonts found".to_string(),
         });
     }
-    let mut tnum_widths: HashMap<u16, HashSet<String>> = HashMap::new();
+    let mut tnum_widths: HashMap<i32, HashSet<String>> = HashMap::new();
     for font in fonts {
-        let hmtx = font.font().hmtx()?;
-        for (tnum_glyph_id, tnum_glyph_name) in font
+        let tnum_glyphs = font
             .all_glyphs()
             .flat_map(|g| font.glyph_name_for_id(g).map(|name| (g, name)))
             .filter(|(_, s)| s.ends_with(".tnum"))
-        {
-            if let Some(width) = hmtx.advance(tnum_glyph_id) {
-                tnum_widths
-                    .entry(width)
-                    .or_insert_with(HashSet::new)
-                    .insert(tnum_glyph_name.to_string());
+            .collect::<Vec<_>>();
+        // Tabular figures must keep their width at every instance of a variable font
+        for (instance, location) in font.instance_locations() {
+            for (glyph_id, glyph_name) in tnum_glyphs.iter() {
+                if let Some(width) = font.advance_width(*glyph_id, &location) {
+                    let glyph_name = if font.is_variable_font() {
+                        format!("{} ({})", glyph_name, instance)
+                    } else {
+                        glyph_name.to_string()
+                    };
+                    tnum_widths
+                        .entry(width.round() as i32)
+                        .or_default()
+                        .insert(glyph_name);
+                }
             }
         }
     }
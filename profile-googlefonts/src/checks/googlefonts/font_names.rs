@@ -28,7 +28,8 @@ const NAME_IDS: [(StringId, &str); 6] = [
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3800",
-    title = "Check font names are correct"
+    title = "Check font names are correct",
+    tags = ["naming"]
 )]
 fn font_names(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
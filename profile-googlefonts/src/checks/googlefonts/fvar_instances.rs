@@ -18,7 +18,8 @@ use crate::utils::build_expected_font;
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3800",
-    title = "Check variable font instances"
+    title = "Check variable font instances",
+    tags = ["variable"]
 )]
 fn fvar_instances(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
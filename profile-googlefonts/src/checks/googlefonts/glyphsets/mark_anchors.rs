@@ -0,0 +1,134 @@
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
+use google_fonts_glyphsets::{get_glyphset_coverage, languages_per_glyphset};
+use google_fonts_languages::LANGUAGES;
+use hashbrown::HashMap;
+use itertools::Itertools;
+use rustybuzz::{ttf_parser, Face, UnicodeBuffer};
+use skrifa::{raw::tables::gdef::GlyphClassDef, GlyphId16};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+#[check(
+    id = "googlefonts/glyphsets/mark_anchors",
+    rationale = "
+        Languages written with combining marks rely on the GPOS table to place
+        each mark on the letter it follows. If a base glyph has no anchor for a
+        mark, the mark is left wherever its own outline happens to be, which
+        is rarely over (or under) the right letter.
+
+        This check uses a heuristic to determine which GF glyphsets a font
+        supports, and takes the letters of the languages in those glyphsets
+        which are written as a base letter followed by combining marks. Each
+        of these is shaped, and the marks in the result are checked to have
+        an anchor they can attach to on the base glyph (or on the mark before
+        them). Letters which the font composes into a single glyph need no
+        anchors and are skipped.
+    ",
+    title = "Check that bases have anchors for the marks used with them in supported languages",
+    location_sensitive = true
+)]
+fn mark_anchors(t: &Testable, context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let codepoints = f.codepoints(Some(context));
+    let model = f.gpos_model(context.location())?;
+    let mut face = Face::from_slice(&t.contents, 0)
+        .ok_or(CheckError::Error("Failed to load font file".to_string()))?;
+    face.set_variations(
+        &context
+            .location()
+            .iter()
+            .map(|setting| rustybuzz::Variation {
+                tag: ttf_parser::Tag::from_bytes(&setting.selector.to_be_bytes()),
+                value: setting.value,
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    // "base + mark" => languages
+    let mut missing: HashMap<String, Vec<String>> = HashMap::new();
+    for (glyphset, coverage) in get_glyphset_coverage(&codepoints).iter() {
+        if coverage.fraction <= 0.8 {
+            continue;
+        }
+        for language_code in languages_per_glyphset(glyphset)?.iter() {
+            let language_code: &str = language_code.as_ref();
+            let Some(language) = LANGUAGES.get(language_code) else {
+                continue;
+            };
+            let Some(exemplars) = language.exemplar_chars.as_ref() else {
+                continue;
+            };
+            let language_string = format!(
+                "{} ({})",
+                language_code,
+                language.name.as_deref().unwrap_or_default()
+            );
+            for exemplar in exemplars.base().split_whitespace() {
+                let decomposed = exemplar
+                    .trim_start_matches('{')
+                    .trim_end_matches('}')
+                    .nfd()
+                    .collect::<String>();
+                let mut chars = decomposed.chars();
+                let Some(base) = chars.next() else {
+                    continue;
+                };
+                let marks = chars.collect::<Vec<_>>();
+                // Only base letters with marks, and only where the font has all
+                // of the characters; missing characters are the tofu check's job.
+                if is_combining_mark(base)
+                    || marks.is_empty()
+                    || !marks.iter().copied().all(is_combining_mark)
+                    || !decomposed.chars().all(|c| codepoints.contains(&(c as u32)))
+                {
+                    continue;
+                }
+                let mut buffer = UnicodeBuffer::new();
+                buffer.push_str(&decomposed);
+                let output = rustybuzz::shape(&face, &[], buffer);
+                let glyphs = output
+                    .glyph_infos()
+                    .iter()
+                    .map(|info| GlyphId16::new(info.glyph_id as u16))
+                    .collect::<Vec<_>>();
+                let Some((shaped_base, shaped_marks)) = glyphs.split_first() else {
+                    continue;
+                };
+                let mut previous = *shaped_base;
+                for mark in shaped_marks {
+                    let is_mark = f.gdef_class(*mark) == GlyphClassDef::Mark
+                        || model.classes_of_mark(*mark).next().is_some();
+                    if is_mark
+                        && !model.can_attach(*mark, *shaped_base)
+                        && !model.can_attach(*mark, previous)
+                    {
+                        missing
+                            .entry(format!(
+                                "{} + {}",
+                                f.glyph_name_for_id_synthesise(*shaped_base),
+                                f.glyph_name_for_id_synthesise(*mark)
+                            ))
+                            .or_default()
+                            .push(language_string.clone());
+                    }
+                    previous = *mark;
+                }
+            }
+        }
+    }
+
+    if missing.is_empty() {
+        return Ok(Status::just_one_pass());
+    }
+    Ok(Status::just_one_warn(
+        "missing-mark-anchors",
+        &format!(
+            "The following marks have no anchor to attach to on the glyphs they are used with in supported languages:\n\n{}",
+            bullet_list(
+                context,
+                missing.iter().sorted().map(|(pair, languages)| {
+                    format!("{}: {}", pair, languages.iter().unique().join(", "))
+                })
+            )
+        ),
+    ))
+}
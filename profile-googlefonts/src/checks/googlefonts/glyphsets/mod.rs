@@ -1,2 +1,4 @@
+mod mark_anchors;
 mod shape_languages;
+pub use mark_anchors::mark_anchors;
 pub use shape_languages::shape_languages;
@@ -27,7 +27,8 @@ fn table_of_results(
         those glyphsets.
     ",
     proposal = "https://github.com/googlefonts/fontbakery/issues/4147",
-    title = "Shapes languages in all GF glyphsets."
+    title = "Shapes languages in all GF glyphsets.",
+    tags = ["slow"]
 )]
 fn shape_languages(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
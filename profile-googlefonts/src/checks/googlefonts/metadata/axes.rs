@@ -16,7 +16,8 @@ use crate::checks::googlefonts::metadata::family_proto;
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3010 and https://github.com/fonttools/fontbakery/issues/3022",
     title = "Validate METADATA.pb axes values.",
-    implementation = "all"
+    implementation = "all",
+    tags = ["variable"]
 )]
 fn axes(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mut problems = vec![];
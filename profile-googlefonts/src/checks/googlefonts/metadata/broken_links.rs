@@ -12,7 +12,8 @@ use reqwest::StatusCode;
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/2550 and https://github.com/fonttools/fontbakery/issues/4110",
     title = "Does METADATA.pb copyright field contain broken links?",
-    applies_to = "MDPB"
+    applies_to = "MDPB",
+    tags = ["network"]
 )]
 fn broken_links(c: &Testable, context: &Context) -> CheckFnResult {
     let mut problems = vec![];
@@ -14,7 +14,8 @@ use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3419",
     applies_to = "MDPB",
-    implementation = "all"
+    implementation = "all",
+    tags = ["slow"]
 )]
 fn can_render_samples(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
@@ -58,7 +58,8 @@ fn normalize_designer_name(designer: &str) -> String {
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3083",
     title = "METADATA.pb: Designers are listed correctly on the Google Fonts catalog?",
-    applies_to = "MDPB"
+    applies_to = "MDPB",
+    tags = ["network"]
 )]
 fn designer_profiles(c: &Testable, context: &Context) -> CheckFnResult {
     let msg = family_proto(c).map_err(|e| {
@@ -11,7 +11,8 @@ use crate::{checks::googlefonts::metadata::family_proto, network_conditions::pro
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/2989",
     title = "Check METADATA.pb includes production subsets.",
-    applies_to = "MDPB"
+    applies_to = "MDPB",
+    tags = ["network"]
 )]
 fn includes_production_subsets(c: &Testable, context: &Context) -> CheckFnResult {
     skip!(
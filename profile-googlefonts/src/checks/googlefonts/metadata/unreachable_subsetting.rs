@@ -1,6 +1,6 @@
 use std::sync::LazyLock;
 
-use fontspector_checkapi::{prelude::*, FileTypeConvert};
+use fontspector_checkapi::{prelude::*, FileTypeConvert, Reached};
 use google_fonts_subsets::{subsets_in_font, SUBSETS};
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
@@ -31,7 +31,8 @@ static CODEPOINTS_BY_SUBSET: LazyLock<HashMap<u32, HashSet<&'static str>>> = Laz
 
         Any encoded glyphs which are not by any of these subset definitions
         will not be served in the subsetted fonts, and so will be unreachable to
-        the end user.
+        the end user. The same goes for any unencoded glyphs which can only be
+        reached through substitutions starting from those codepoints.
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4097 and https://github.com/fonttools/fontbakery/pull/4273",
@@ -64,6 +65,24 @@ fn unreachable_subsetting(c: &TestableCollection, context: &Context) -> CheckFnR
         if unreachable.is_empty() {
             continue;
         }
+        let served = font_codepoints.difference(&unreachable).copied();
+        let served_closure = font.gsub_closure(served)?;
+        let closure = font.gsub_closure(font_codepoints.iter().copied())?;
+        let unserved_glyphs = closure
+            .glyphs()
+            .filter(|gid| {
+                !served_closure.contains(*gid)
+                    && !matches!(closure.reached(*gid), Some(Reached::Cmap(_)))
+            })
+            .sorted()
+            .map(|gid| {
+                format!(
+                    "{}: {}",
+                    font.glyph_name_for_id_synthesise(gid),
+                    closure.explain(&font, gid)
+                )
+            })
+            .collect::<Vec<_>>();
         let mut bullets = vec![];
 
         for codepoint in unreachable.into_iter().sorted() {
@@ -84,15 +103,19 @@ fn unreachable_subsetting(c: &TestableCollection, context: &Context) -> CheckFnR
             let name = format!("U+{:04X} {}", codepoint, name);
             bullets.push(format!("{}: {}", name, message));
         }
-        problems.push(Status::warn(
-            "unreachable-subsetting",
-            &format!(
-                "{}: The following codepoints supported by the font are not covered by any subsets defined in the font's metadata file, and will never be served. You can solve this by either manually adding additional subset declarations to METADATA.pb, or by editing the glyphset definitions.\n\n{}\n\nOr you can add the above codepoints to one of the subsets supported by the font: {}",
-                font.filename.to_string_lossy(),
-                bullet_list(context, bullets),
-                font_subsets.join(", ")
-            ),
-        ))
+        let mut message = format!(
+            "{}: The following codepoints supported by the font are not covered by any subsets defined in the font's metadata file, and will never be served. You can solve this by either manually adding additional subset declarations to METADATA.pb, or by editing the glyphset definitions.\n\n{}\n\nOr you can add the above codepoints to one of the subsets supported by the font: {}",
+            font.filename.to_string_lossy(),
+            bullet_list(context, bullets),
+            font_subsets.join(", ")
+        );
+        if !unserved_glyphs.is_empty() {
+            message.push_str(&format!(
+                "\n\nThe following unencoded glyphs are only reachable through those codepoints, and so will never be served either:\n\n{}",
+                bullet_list(context, unserved_glyphs)
+            ));
+        }
+        problems.push(Status::warn("unreachable-subsetting", &message))
     }
 
     return_result(problems)
@@ -16,7 +16,8 @@ use skrifa::raw::tables::name::NameId;
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Description strings in the name table must not exceed 200 characters."
+    title = "Description strings in the name table must not exceed 200 characters.",
+    tags = ["naming"]
 )]
 fn description_max_length(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
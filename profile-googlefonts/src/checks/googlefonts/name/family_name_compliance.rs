@@ -45,7 +45,8 @@ static ABBREVIATION_EXCEPTIONS: LazyLock<HashSet<String>> = LazyLock::new(|| {
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4049",
-    title = "Check family name for GF Guide compliance."
+    title = "Check family name for GF Guide compliance.",
+    tags = ["naming"]
 )]
 fn family_name_compliance(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -10,7 +10,8 @@ use skrifa::raw::types::NameId;
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Make sure family name does not begin with a digit."
+    title = "Make sure family name does not begin with a digit.",
+    tags = ["naming"]
 )]
 fn familyname_first_char(t: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(t);
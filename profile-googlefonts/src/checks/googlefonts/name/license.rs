@@ -32,7 +32,8 @@ use skrifa::string::StringId;
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
     title = "Check copyright namerecords match license file.",
-    implementation = "all"
+    implementation = "all",
+    tags = ["naming"]
 )]
 fn license(c: &TestableCollection, _context: &Context) -> CheckFnResult {
     let mut problems = vec![];
@@ -61,7 +61,8 @@ fn identify_license(name_string: &str) -> Option<&'static License> {
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4358",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "License URL matches License text on name table?"
+    title = "License URL matches License text on name table?",
+    tags = ["naming"]
 )]
 fn license_url(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -16,7 +16,8 @@ use skrifa::raw::TableProvider;
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Name table entries should not contain line-breaks."
+    title = "Name table entries should not contain line-breaks.",
+    tags = ["naming"]
 )]
 fn line_breaks(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -12,7 +12,8 @@ use skrifa::string::StringId;
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Font has all mandatory 'name' table entries?"
+    title = "Font has all mandatory 'name' table entries?",
+    tags = ["naming"]
 )]
 fn mandatory_entries(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -26,7 +26,8 @@ static RFN_RE: LazyLock<Regex> =
 
         This check ensures \"Reserved Font Name\" is not mentioned in the name table.
     ",
-    proposal = "https://github.com/fonttools/fontbakery/issues/1380"
+    proposal = "https://github.com/fonttools/fontbakery/issues/1380",
+    tags = ["naming"]
 )]
 fn rfn(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -19,7 +19,8 @@ static VALID_VERSION_RE: LazyLock<Regex> =
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Version format is correct in 'name' table?"
+    title = "Version format is correct in 'name' table?",
+    tags = ["naming"]
 )]
 fn version_format(t: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(t);
@@ -15,7 +15,8 @@ use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/2119",
-    title = "Check that variable fonts have an HVAR table."
+    title = "Check that variable fonts have an HVAR table.",
+    tags = ["variable"]
 )]
 fn has_HVAR(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -13,7 +13,8 @@ use crate::network_conditions::remote_styles;
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Version number has increased since previous release on Google Fonts?"
+    title = "Version number has increased since previous release on Google Fonts?",
+    tags = ["network"]
 )]
 fn version_bump(f: &Testable, context: &Context) -> CheckFnResult {
     let font = testfont!(f);
@@ -20,7 +20,8 @@ use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3762 and https://github.com/fonttools/fontbakery/pull/3921",
-    title = "Check font follows the Google Fonts vertical metric schema"
+    title = "Check font follows the Google Fonts vertical metric schema",
+    tags = ["network"]
 )]
 fn vertical_metrics(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
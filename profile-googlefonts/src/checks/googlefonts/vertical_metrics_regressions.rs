@@ -27,7 +27,8 @@ use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/1162",
     title = "Check if the vertical metrics of a family are similar to the same
-family hosted on Google Fonts."
+family hosted on Google Fonts.",
+    tags = ["network"]
 )]
 fn vertical_metrics_regressions(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert, DEFAULT_LOCATION};
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
 use serde::Serialize;
 use serde_json::Value;
 use skrifa::{outline::OutlinePen, raw::TableProvider, GlyphId, MetadataProvider};
@@ -84,7 +84,9 @@ impl OutlinePen for AlignmentMissPen<'_> {
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3088",
-    title = "Are there any misaligned on-curve points?"
+    title = "Are there any misaligned on-curve points?",
+    tags = ["outline"],
+    location_sensitive = true
 )]
 fn alignment_miss(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -124,7 +126,7 @@ fn alignment_miss(t: &Testable, context: &Context) -> CheckFnResult {
             glyph_name: &name,
             glyph_id: glyph,
         };
-        f.draw_glyph(glyph, &mut pen, DEFAULT_LOCATION)?;
+        f.draw_glyph(glyph, &mut pen, context.location())?;
         all_warnings.extend(pen.warnings);
         if all_warnings.len() > 100 {
             problems.push(Status::pass(
@@ -12,22 +12,24 @@ const COLINEAR_EPSILON: f64 = 0.1; // Radians
         This check looks for consecutive line segments which have the same angle. This
         normally happens if an outline point has been added by accident.
 
-        This check is not run for variable fonts, as they may legitimately have
-        colinear vectors.
+        Variable fonts may legitimately have colinear vectors at some locations, so
+        this check only runs on them when instances are swept (`--sweep-instances`).
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3088",
-    title = "Do any segments have colinear vectors?"
+    title = "Do any segments have colinear vectors?",
+    tags = ["outline"],
+    location_sensitive = true
 )]
 fn colinear_vectors(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut all_warnings = vec![];
     skip!(
-        f.is_variable_font(),
+        f.is_variable_font() && context.sweep_location.is_none(),
         "variable-font",
-        "This check produces too many false positives with variable fonts."
+        "This check produces too many false positives with variable fonts; run with --sweep-instances to check each of their instances."
     );
-    for (name, result) in name_and_bezglyph(&f) {
+    for (name, result) in name_and_bezglyph(&f, context.location()) {
         let pen = result?;
         for contour in pen.iter() {
             let segs = contour.segments().collect::<Vec<_>>();
@@ -17,13 +17,15 @@ use kurbo::{Rect, Shape};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/2056",
-    title = "Check the direction of the outermost contour in each glyph"
+    title = "Check the direction of the outermost contour in each glyph",
+    tags = ["outline"],
+    location_sensitive = true
 )]
 fn direction(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut problems = vec![];
     let mut all_warnings = vec![];
-    for (name, result) in name_and_bezglyph(&f) {
+    for (name, result) in name_and_bezglyph(&f, context.location()) {
         let pen = result?;
         let bounds: Vec<Rect> = pen.iter().map(|path| path.bounding_box()).collect();
         let mut is_within = vec![vec![]; bounds.len()];
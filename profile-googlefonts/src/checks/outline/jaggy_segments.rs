@@ -25,19 +25,21 @@ const JAG_ANGLE: f64 = 0.25; // Radians
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3064",
-    title = "Do outlines contain any jaggy segments?"
+    title = "Do outlines contain any jaggy segments?",
+    tags = ["outline"],
+    location_sensitive = true
 )]
 fn jaggy_segments(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     skip!(
-        f.is_variable_font(),
+        f.is_variable_font() && context.sweep_location.is_none(),
         "variable-font",
-        "This check produces too many false positives with variable fonts."
+        "This check produces too many false positives with variable fonts; run with --sweep-instances to check each of their instances."
     );
     let mut problems = vec![];
     let mut all_warnings = vec![];
 
-    for (name, result) in name_and_bezglyph(&f) {
+    for (name, result) in name_and_bezglyph(&f, context.location()) {
         let pen = result?;
         for path in pen.iter() {
             let segs = path.segments().collect::<Vec<_>>();
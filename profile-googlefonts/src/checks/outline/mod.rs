@@ -1,5 +1,5 @@
-use fontspector_checkapi::{pens::BezGlyph, CheckError, TestFont, DEFAULT_LOCATION};
-use skrifa::MetadataProvider;
+use fontspector_checkapi::{pens::BezGlyph, CheckError, TestFont};
+use skrifa::{setting::VariationSetting, MetadataProvider};
 use std::ops::Sub;
 
 mod alignment_miss;
@@ -26,6 +26,7 @@ where
 
 pub(crate) fn name_and_bezglyph<'a>(
     f: &'a TestFont,
+    location: &'a [VariationSetting],
 ) -> impl Iterator<Item = (String, Result<BezGlyph, CheckError>)> + 'a {
     let reverse_char_map = f
         .font()
@@ -39,7 +40,7 @@ pub(crate) fn name_and_bezglyph<'a>(
             name = format!("{} (U+{:04X})", name, cp);
         }
         let mut pen = BezGlyph::default();
-        let result = f.draw_glyph(glyph, &mut pen, DEFAULT_LOCATION);
+        let result = f.draw_glyph(glyph, &mut pen, location);
         (name, result.map(|_| pen))
     })
 }
@@ -18,12 +18,14 @@ use crate::checks::outline::name_and_bezglyph;
     
     ",
     proposal = "https://github.com/google/fonts/issues/7594#issuecomment-2401909084",
-    title = "Check there are no overlapping path segments"
+    title = "Check there are no overlapping path segments",
+    tags = ["slow", "outline"],
+    location_sensitive = true
 )]
 fn overlapping_path_segments(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut all_warnings = vec![];
-    for (name, result) in name_and_bezglyph(&f) {
+    for (name, result) in name_and_bezglyph(&f, context.location()) {
         let mut seen = HashSet::new();
         let pen = result?;
         for contour in pen.iter() {
@@ -15,15 +15,17 @@ use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3088",
-    title = "Do outlines contain any semi-vertical or semi-horizontal lines?"
+    title = "Do outlines contain any semi-vertical or semi-horizontal lines?",
+    tags = ["outline"],
+    location_sensitive = true
 )]
 fn semi_vertical(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut all_warnings = vec![];
     skip!(
-        f.is_variable_font(),
+        f.is_variable_font() && context.sweep_location.is_none(),
         "variable-font",
-        "This check produces too many false positives with variable fonts."
+        "This check produces too many false positives with variable fonts; run with --sweep-instances to check each of their instances."
     );
     skip!(
         f.is_italic()?,
@@ -31,7 +33,7 @@ fn semi_vertical(t: &Testable, context: &Context) -> CheckFnResult {
         "This check produces too many false positives with italic fonts."
     );
 
-    for (name, result) in name_and_bezglyph(&f) {
+    for (name, result) in name_and_bezglyph(&f, context.location()) {
         let pen = result?;
         for path in pen.iter() {
             for seg in path.segments() {
@@ -25,24 +25,27 @@ fn segment_is_short(pathseg: &PathSeg, total_length: f64, prev_was_line: bool) -
         This check looks for outline segments which seem particularly short (less
         than 0.6% of the overall path length).
 
-        This check is not run for variable fonts, as they may legitimately have
-        short segments. As this check is liable to generate significant numbers
-        of false positives, it will pass if there are more than
-        100 reported short segments.
+        Variable fonts may legitimately have short segments at some locations, so
+        this check only runs on them when instances are swept (`--sweep-instances`),
+        reporting the locations at which short segments are found. As this check is
+        liable to generate significant numbers of false positives, it will pass if
+        there are more than 100 reported short segments.
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3088",
-    title = "Are any segments inordinately short?"
+    title = "Are any segments inordinately short?",
+    tags = ["outline"],
+    location_sensitive = true
 )]
 fn short_segments(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut all_warnings = vec![];
     skip!(
-        f.is_variable_font(),
+        f.is_variable_font() && context.sweep_location.is_none(),
         "variable-font",
-        "This check produces too many false positives with variable fonts."
+        "This check produces too many false positives with variable fonts; run with --sweep-instances to check each of their instances."
     );
-    for (name, result) in name_and_bezglyph(&f) {
+    for (name, result) in name_and_bezglyph(&f, context.location()) {
         let pen = result?;
         for path in pen.iter() {
             let outline_length = path.perimeter(0.01);
@@ -0,0 +1,339 @@
+use super::{
+    schema::{CollidoscopeOptions, ShapingConfig, ShapingTest},
+    ShapingCheck,
+};
+use fontspector_checkapi::{pens::BezGlyph, prelude::*, testfont, FileTypeConvert, TestFont};
+use hashbrown::HashSet;
+use itertools::Itertools;
+use kurbo::{Affine, BezPath, ParamCurve, PathSeg, Point, Rect, Shape};
+use rustybuzz::{Face, GlyphBuffer};
+use skrifa::{raw::tables::gdef::GlyphClassDef, GlyphId, GlyphId16};
+
+#[check(
+    id = "shaping/collides",
+    rationale = "
+
+        Fonts with complex layout rules can benefit from regression tests to ensure
+        that the rules are behaving as designed. This checks runs a shaping test
+        suite and reports instances where the glyphs collide in unexpected ways.
+
+        Which glyphs are compared is set by the `collidoscope` entry of the
+        configuration or of an individual test: `bases`, `marks`, `adjacent_clusters`,
+        `faraway` and `cursive` switch kinds of glyph pairs on and off, and `area`
+        gives the percentage of the smaller glyph which must be covered before an
+        overlap counts. Pairs of glyphs which are expected to touch can be listed
+        as `glyph1/glyph2` in the `allowedcollisions` entry of the configuration.
+
+        Shaping test suites should be written by the font engineer and referenced
+        in the FontBakery configuration file. For more information about write
+        shaping test files and how to configure FontBakery to read the shaping
+        test suites, see https://simoncozens.github.io/tdd-for-otl/
+
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/3223",
+    title = "Check that no collisions are found while shaping",
+    tags = ["slow"]
+)]
+fn collides(t: &Testable, context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let model = f.gpos_model(&[])?;
+    let tester = CollidesTest {
+        font: &f,
+        cursive_exits: model
+            .cursive
+            .iter()
+            .filter(|anchors| anchors.exit.is_some())
+            .map(|anchors| (anchors.lookup, anchors.glyph))
+            .collect(),
+        cursive_entries: model
+            .cursive
+            .iter()
+            .filter(|anchors| anchors.entry.is_some())
+            .map(|anchors| (anchors.lookup, anchors.glyph))
+            .collect(),
+    };
+    let mut problems = vec![];
+    for (filename, fails) in tester.run(t, context)? {
+        let mut report = String::new();
+        for fail in fails {
+            report.push_str(&format!(
+                "{}{}{}: {}\n",
+                fail.test.input,
+                fail.test.note(),
+                fail.location,
+                fail.detail
+            ));
+        }
+        if !report.is_empty() {
+            problems.push(Status::fail(
+                "shaping-collides",
+                &format!(
+                    "{}: Collisions found while shaping:\n\n{}",
+                    filename, report
+                ),
+            ))
+        }
+    }
+    return_result(problems)
+}
+
+struct CollidesTest<'a> {
+    font: &'a TestFont<'a>,
+    /// (lookup, glyph) pairs with a cursive exit anchor
+    cursive_exits: HashSet<(u16, GlyphId16)>,
+    /// (lookup, glyph) pairs with a cursive entry anchor
+    cursive_entries: HashSet<(u16, GlyphId16)>,
+}
+
+/// A shaped glyph, with its outline moved to where it was positioned
+struct PositionedGlyph {
+    gid: GlyphId16,
+    name: String,
+    is_mark: bool,
+    /// Index of the glyph's cluster among the clusters of the buffer
+    cluster: usize,
+    paths: Vec<BezPath>,
+    bounds: Rect,
+}
+
+impl CollidesTest<'_> {
+    fn positioned_glyphs(
+        &self,
+        buffer: &GlyphBuffer,
+        location: &[(String, f32)],
+    ) -> Vec<PositionedGlyph> {
+        let clusters = buffer
+            .glyph_infos()
+            .iter()
+            .map(|info| info.cluster)
+            .sorted()
+            .dedup()
+            .collect::<Vec<_>>();
+        let mut cursor = (0.0, 0.0);
+        let mut glyphs = vec![];
+        for (info, position) in buffer.glyph_infos().iter().zip(buffer.glyph_positions()) {
+            let gid = GlyphId16::new(info.glyph_id as u16);
+            let origin = (
+                cursor.0 + position.x_offset as f64,
+                cursor.1 + position.y_offset as f64,
+            );
+            cursor.0 += position.x_advance as f64;
+            cursor.1 += position.y_advance as f64;
+            let mut pen = BezGlyph::default();
+            if self
+                .font
+                .draw_glyph(
+                    gid.into(),
+                    &mut pen,
+                    location.iter().map(|(tag, value)| (tag.as_str(), *value)),
+                )
+                .is_err()
+            {
+                continue;
+            }
+            let paths = pen
+                .iter()
+                .map(|path| Affine::translate(origin) * path.clone())
+                .filter(|path| !path.is_empty())
+                .collect::<Vec<_>>();
+            let Some(bounds) = paths
+                .iter()
+                .map(|path| path.bounding_box())
+                .reduce(|a, b| a.union(b))
+            else {
+                continue;
+            };
+            glyphs.push(PositionedGlyph {
+                gid,
+                name: self.font.glyph_name_for_id_synthesise(gid),
+                is_mark: self.font.gdef_class(GlyphId::from(gid)) == GlyphClassDef::Mark,
+                cluster: clusters.binary_search(&info.cluster).unwrap_or_default(),
+                paths,
+                bounds,
+            });
+        }
+        glyphs
+    }
+
+    /// Whether a collision between these two glyphs is one we are looking for
+    fn should_compare(
+        &self,
+        options: &CollidoscopeOptions,
+        first: &PositionedGlyph,
+        second: &PositionedGlyph,
+    ) -> bool {
+        let distance = first.cluster.abs_diff(second.cluster);
+        let category_wanted = match (first.is_mark, second.is_mark) {
+            (false, false) => options.bases && (options.cursive || !self.joined(first, second)),
+            (true, true) => options.marks,
+            // A mark sits on the base of its own cluster by design
+            _ => options.marks && distance != 0,
+        };
+        let distance_wanted = match distance {
+            0 => true,
+            1 => options.adjacent_clusters,
+            _ => options.faraway,
+        };
+        category_wanted && distance_wanted
+    }
+
+    /// Whether two glyphs are connected through a cursive attachment lookup
+    fn joined(&self, first: &PositionedGlyph, second: &PositionedGlyph) -> bool {
+        self.cursive_exits.iter().any(|(lookup, gid)| {
+            (*gid == first.gid && self.cursive_entries.contains(&(*lookup, second.gid)))
+                || (*gid == second.gid && self.cursive_entries.contains(&(*lookup, first.gid)))
+        })
+    }
+}
+
+/// Total area enclosed by a glyph's outline
+fn glyph_area(glyph: &PositionedGlyph) -> f64 {
+    glyph
+        .paths
+        .iter()
+        .map(|path| path.area())
+        .sum::<f64>()
+        .abs()
+}
+
+fn contains(glyph: &PositionedGlyph, point: Point) -> bool {
+    glyph
+        .paths
+        .iter()
+        .map(|path| path.winding(point))
+        .sum::<i32>()
+        != 0
+}
+
+/// Find a point where the outlines of two glyphs overlap
+fn collision_point(first: &PositionedGlyph, second: &PositionedGlyph) -> Option<Point> {
+    if first.bounds.intersect(second.bounds).area() <= 0.0 {
+        return None;
+    }
+    for second_path in second.paths.iter() {
+        let mut flattened = BezPath::new();
+        kurbo::flatten(second_path, 0.5, |el| flattened.push(el));
+        let lines = flattened
+            .segments()
+            .filter_map(|seg| match seg {
+                PathSeg::Line(line) => Some(line),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for first_path in first.paths.iter() {
+            if first_path
+                .bounding_box()
+                .intersect(second_path.bounding_box())
+                .area()
+                <= 0.0
+            {
+                continue;
+            }
+            for seg in first_path.segments() {
+                for line in lines.iter() {
+                    if let Some(hit) = seg.intersect_line(*line).first() {
+                        return Some(line.eval(hit.line_t));
+                    }
+                }
+            }
+        }
+    }
+    // No outlines cross, but one glyph may lie entirely inside the other
+    first
+        .paths
+        .iter()
+        .filter_map(|path| path.segments().next().map(|seg| seg.start()))
+        .find(|point| contains(second, *point))
+        .or_else(|| {
+            second
+                .paths
+                .iter()
+                .filter_map(|path| path.segments().next().map(|seg| seg.start()))
+                .find(|point| contains(first, *point))
+        })
+}
+
+/// Estimate the area covered by both glyphs, by sampling points over the overlap of their bounds
+fn overlap_area(first: &PositionedGlyph, second: &PositionedGlyph) -> f64 {
+    const SAMPLES: usize = 50;
+    let overlap = first.bounds.intersect(second.bounds);
+    let (step_x, step_y) = (
+        overlap.width() / SAMPLES as f64,
+        overlap.height() / SAMPLES as f64,
+    );
+    let inside = (0..SAMPLES)
+        .cartesian_product(0..SAMPLES)
+        .filter(|(i, j)| {
+            let point = Point::new(
+                overlap.x0 + (*i as f64 + 0.5) * step_x,
+                overlap.y0 + (*j as f64 + 0.5) * step_y,
+            );
+            contains(first, point) && contains(second, point)
+        })
+        .count();
+    inside as f64 * step_x * step_y
+}
+
+impl ShapingCheck for CollidesTest<'_> {
+    fn pass_fail(
+        &self,
+        test: &ShapingTest,
+        configuration: &ShapingConfig,
+        buffer: &GlyphBuffer,
+        _face: &Face,
+    ) -> Option<String> {
+        let options = test
+            .collidoscope
+            .clone()
+            .or(configuration.collidoscope.clone())
+            .unwrap_or_default();
+        let location = test
+            .options
+            .fill_from_defaults(configuration)
+            .variations
+            .unwrap_or_default()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let glyphs = self.positioned_glyphs(buffer, &location);
+        let mut collisions = vec![];
+        for (i, first) in glyphs.iter().enumerate() {
+            for second in glyphs.iter().skip(i + 1) {
+                if !self.should_compare(&options, first, second) {
+                    continue;
+                }
+                let pair = format!("{}/{}", first.name, second.name);
+                let reversed = format!("{}/{}", second.name, first.name);
+                if configuration.allowed_collisions.contains(&pair)
+                    || configuration.allowed_collisions.contains(&reversed)
+                {
+                    continue;
+                }
+                let Some(point) = collision_point(first, second) else {
+                    continue;
+                };
+                if options.area > 0.0 {
+                    let smaller = glyph_area(first).min(glyph_area(second));
+                    if smaller <= 0.0
+                        || overlap_area(first, second) * 100.0 / smaller < options.area as f64
+                    {
+                        continue;
+                    }
+                }
+                collisions.push(format!(
+                    "{} at ({}, {})",
+                    pair,
+                    point.x.round(),
+                    point.y.round()
+                ));
+            }
+        }
+        if collisions.is_empty() {
+            return None;
+        }
+        Some(collisions.join(", "))
+    }
+
+    fn applies(&self, configuration: &ShapingConfig, test: &ShapingTest) -> bool {
+        configuration.collidoscope.is_some() || test.collidoscope.is_some()
+    }
+}
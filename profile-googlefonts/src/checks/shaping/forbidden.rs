@@ -23,7 +23,8 @@ use rustybuzz::{Face, GlyphBuffer};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3223",
-    title = "Check that no forbidden glyphs are found while shaping"
+    title = "Check that no forbidden glyphs are found while shaping",
+    tags = ["slow"]
 )]
 fn forbidden(t: &Testable, context: &Context) -> CheckFnResult {
     let _f = testfont!(t); // We just use this to make sure it's a font
@@ -32,8 +33,8 @@ fn forbidden(t: &Testable, context: &Context) -> CheckFnResult {
         let mut report = String::new();
         for fail in fails {
             report.push_str(&format!(
-                "{} produced forbidden {}\n",
-                fail.test.input, fail.detail
+                "{}{} produced forbidden {}\n",
+                fail.test.input, fail.location, fail.detail
             ));
         }
         if !report.is_empty() {
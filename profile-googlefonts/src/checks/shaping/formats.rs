@@ -0,0 +1,185 @@
+//! Reading shaping tests from the formats we understand
+//!
+//! As well as fontbakery's JSON schema, tests can be given as text corpora
+//! (`.txt`), with one string per line followed by any options for it:
+//!
+//! ```text
+//! # Comments and blank lines are ignored
+//! AV
+//! سلام --script=arab --direction=rtl --features=-liga --variations=wght=700
+//! ```
+//!
+//! and as HarfBuzz test files (`.tests`), whose lines are
+//! `font;options;U+0041,U+0056;[expectation]`. Both use `hb-shape` style options.
+//! Tests in a HarfBuzz file only apply to the font they name.
+use std::{collections::HashMap, path::Path};
+
+use fontspector_checkapi::CheckError;
+use rustybuzz::SerializeFlags;
+
+use super::schema::{ShapingConfig, ShapingInput, ShapingTest};
+
+/// Read a file of shaping tests
+///
+/// Text corpora and HarfBuzz test files have no configuration of their own, so
+/// they use `configuration`.
+pub(crate) fn load_tests(
+    path: &Path,
+    configuration: &ShapingConfig,
+) -> Result<ShapingInput, CheckError> {
+    let contents = std::fs::read_to_string(path)?;
+    let tests = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("txt") => parse_text_corpus(&contents),
+        Some("tests") => parse_harfbuzz_tests(&contents),
+        _ => return Ok(serde_json::from_str(&contents)?),
+    };
+    Ok(ShapingInput {
+        configuration: configuration.clone(),
+        tests,
+    })
+}
+
+fn parse_text_corpus(contents: &str) -> Vec<ShapingTest> {
+    let mut tests = vec![];
+    for line in contents.lines() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (input, options) = match line.find(" --") {
+            Some(index) => (&line[..index], &line[index..]),
+            None => (line, ""),
+        };
+        let mut test = ShapingTest {
+            input: input.trim().to_string(),
+            ..Default::default()
+        };
+        if apply_options(&mut test, options.split_whitespace()) {
+            tests.push(test);
+        } else {
+            log::warn!("Skipping shaping test with unsupported options: {}", line);
+        }
+    }
+    tests
+}
+
+fn parse_harfbuzz_tests(contents: &str) -> Vec<ShapingTest> {
+    let mut tests = vec![];
+    for line in contents.lines() {
+        if line.trim().is_empty() || line.starts_with('#') || line.starts_with('@') {
+            continue;
+        }
+        let fields = line.split(';').collect::<Vec<_>>();
+        let [font, options, unicodes, expectation] = fields[..] else {
+            log::warn!("Skipping malformed HarfBuzz test: {}", line);
+            continue;
+        };
+        // The font may be followed by "@" and a checksum
+        let font = font.split('@').next().unwrap_or_default();
+        let font = Path::new(font)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let Some(input) = unicodes
+            .split(',')
+            .map(|unicode| {
+                u32::from_str_radix(unicode.trim().trim_start_matches("U+"), 16)
+                    .ok()
+                    .and_then(char::from_u32)
+            })
+            .collect::<Option<String>>()
+        else {
+            log::warn!("Skipping HarfBuzz test with bad codepoints: {}", line);
+            continue;
+        };
+        let expectation = expectation
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+        let mut test = ShapingTest {
+            input,
+            only: vec![font],
+            // "*" means the output isn't checked
+            expectation: (expectation != "*").then(|| expectation.to_string()),
+            serialize_flags: Some(SerializeFlags::default().bits()),
+            ..Default::default()
+        };
+        if apply_options(&mut test, options.split_whitespace()) {
+            tests.push(test);
+        } else {
+            log::warn!("Skipping HarfBuzz test with unsupported options: {}", line);
+        }
+    }
+    tests
+}
+
+/// Apply `hb-shape` style options to a test, returning false if any of them
+/// would change the shaping in ways we can't reproduce
+fn apply_options<'a>(test: &mut ShapingTest, options: impl Iterator<Item = &'a str>) -> bool {
+    let mut locations = vec![];
+    for option in options {
+        let (name, value) = option.split_once('=').unwrap_or((option, ""));
+        let flags = match name {
+            "--no-glyph-names" => SerializeFlags::NO_GLYPH_NAMES,
+            "--no-clusters" => SerializeFlags::NO_CLUSTERS,
+            "--no-positions" => SerializeFlags::NO_POSITIONS,
+            "--no-advances" => SerializeFlags::NO_ADVANCES,
+            "--ned" => SerializeFlags::NO_CLUSTERS | SerializeFlags::NO_ADVANCES,
+            "--show-flags" => SerializeFlags::GLYPH_FLAGS,
+            "--show-extents" => SerializeFlags::GLYPH_EXTENTS,
+            _ => SerializeFlags::empty(),
+        };
+        match name {
+            "--script" => test.options.script = Some(value.to_string()),
+            "--language" => test.options.language = Some(value.to_string()),
+            "--direction" => test.options.direction = Some(value.to_string()),
+            "--features" => test.options.features = Some(parse_features(value)),
+            "--variations" => match parse_variations(value) {
+                Some(variations) => locations.push(variations),
+                None => return false,
+            },
+            // We only have the one shaper, and its own font functions
+            "--shaper" | "--shapers" | "--font-funcs" => {}
+            _ if !flags.is_empty() => {
+                test.serialize_flags = Some(test.serialize_flags.unwrap_or_default() | flags.bits())
+            }
+            _ => return false,
+        }
+    }
+    // Giving several locations runs the test at each of them
+    if locations.len() == 1 {
+        test.options.variations = locations.pop();
+    } else {
+        test.locations = locations;
+    }
+    true
+}
+
+/// Parse features such as `liga,-kern,+ss01,aalt=0`
+///
+/// Feature ranges (`kern[3:5]`) are applied to the whole string.
+fn parse_features(value: &str) -> HashMap<String, bool> {
+    value
+        .split(',')
+        .filter(|feature| !feature.is_empty())
+        .map(|feature| {
+            let (feature, setting) = feature.split_once('=').unwrap_or((feature, "1"));
+            let feature = feature.split('[').next().unwrap_or_default();
+            let (tag, enabled) = match feature.strip_prefix('-') {
+                Some(tag) => (tag, false),
+                None => (feature.trim_start_matches('+'), setting != "0"),
+            };
+            (tag.to_string(), enabled)
+        })
+        .collect()
+}
+
+/// Parse variations such as `wght=700,wdth=75`
+fn parse_variations(value: &str) -> Option<HashMap<String, f32>> {
+    value
+        .split(',')
+        .map(|variation| {
+            let (tag, value) = variation.split_once('=')?;
+            Some((tag.to_string(), value.parse().ok()?))
+        })
+        .collect()
+}
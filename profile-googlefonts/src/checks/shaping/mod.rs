@@ -1,20 +1,61 @@
+mod collides;
 mod forbidden;
+mod formats;
+mod record;
 mod regression;
 pub(crate) mod schema;
-use std::str::FromStr;
+use std::{path::PathBuf, str::FromStr};
 
+pub use collides::collides;
 use fontspector_checkapi::{CheckError, Context, Testable};
 pub use forbidden::forbidden;
+pub use record::{record_expectations, ChangedExpectation};
 pub use regression::regression;
 
+use formats::load_tests;
 use rustybuzz::{ttf_parser, Face, GlyphBuffer, UnicodeBuffer};
-use schema::{ShapingConfig, ShapingInput, ShapingOptions, ShapingTest};
+use schema::{ShapingConfig, ShapingOptions, ShapingTest};
+use serde_json::{Map, Value};
 
 pub(crate) struct FailedCheck {
     test: ShapingTest,
+    /// Where the test was shaped, e.g. " @ wght=700"
+    location: String,
     detail: String,
 }
 
+/// The directory of shaping test files given in a configuration file
+pub fn shaping_test_directory(configuration: &Map<String, Value>) -> Option<&str> {
+    configuration
+        .get("shaping")
+        .and_then(|shaping| shaping.as_object())
+        .and_then(|shaping| shaping.get("test_directory"))
+        .and_then(|test_directory| test_directory.as_str())
+}
+
+/// The configuration for test files without one of their own (text corpora and
+/// HarfBuzz test files), given as `shaping.configuration` in a configuration file
+pub(crate) fn shaping_configuration(
+    configuration: &Map<String, Value>,
+) -> Result<ShapingConfig, CheckError> {
+    Ok(configuration
+        .get("shaping")
+        .and_then(|shaping| shaping.get("configuration"))
+        .map(|shaping| serde_json::from_value(shaping.clone()))
+        .transpose()?
+        .unwrap_or_default())
+}
+
+/// The shaping test files in a test directory, in any of the formats we read
+pub(crate) fn test_files(test_directory: &str) -> Result<Vec<PathBuf>, CheckError> {
+    let mut files = vec![];
+    for extension in ["json", "txt", "tests"] {
+        files.extend(glob::glob(&format!("{}/*.{}", test_directory, extension))?.flatten());
+    }
+    files.sort();
+    Ok(files)
+}
+
 pub(crate) fn create_buffer_and_run(
     face: &mut Face,
     input: &str,
@@ -80,35 +121,36 @@ pub(crate) trait ShapingCheck {
 
         let basename = t.basename().unwrap_or_default();
         let mut results = vec![];
-        let shaping_file = context
-            .configuration
-            .get("shaping")
-            .and_then(|shaping| shaping.as_object())
-            .and_then(|shaping| shaping.get("test_directory"))
-            .and_then(|test_directory: &serde_json::Value| test_directory.as_str())
-            .ok_or(CheckError::skip(
+        let test_directory =
+            shaping_test_directory(&context.configuration).ok_or(CheckError::skip(
                 "no-tests",
                 "Shaping test directory not defined in configuration file",
             ))?;
-        let files = glob::glob(&format!("{}/*.json", shaping_file))?.flatten();
 
-        for file in files {
-            let file_contents = std::fs::read_to_string(&file)?;
-            let input: ShapingInput = serde_json::from_str(&file_contents)?;
+        let default_config = shaping_configuration(&context.configuration)?;
+
+        for file in test_files(test_directory)? {
+            let input = load_tests(&file, &default_config)?;
             let config = input.configuration;
             let mut failed_checks = vec![];
             for test in input.tests {
-                if !self.applies(&config, &test) || test.excluded(&basename) {
+                if test.excluded(&basename) {
                     continue;
                 }
-                let options = test.options.fill_from_defaults(&config);
-                // Run the test
-                let glyph_buffer = create_buffer_and_run(&mut face, &test.input, &options)?;
-                if let Some(res) = self.pass_fail(&test, &config, &glyph_buffer, &face) {
-                    failed_checks.push(FailedCheck {
-                        test: test.clone(),
-                        detail: res,
-                    });
+                // Run the test at each of its locations
+                for (_, case) in test.cases(&basename) {
+                    if !self.applies(&config, &case) {
+                        continue;
+                    }
+                    let options = case.options.fill_from_defaults(&config);
+                    let glyph_buffer = create_buffer_and_run(&mut face, &case.input, &options)?;
+                    if let Some(res) = self.pass_fail(&case, &config, &glyph_buffer, &face) {
+                        failed_checks.push(FailedCheck {
+                            location: options.location(),
+                            test: case,
+                            detail: res,
+                        });
+                    }
                 }
             }
             results.push((file.to_string_lossy().to_string(), failed_checks));
@@ -0,0 +1,220 @@
+use super::{
+    create_buffer_and_run, formats::load_tests, regression::serialize_expectation,
+    schema::ExpectationSource, shaping_configuration, test_files,
+};
+use fontspector_checkapi::{CheckError, Testable};
+use rustybuzz::Face;
+use serde_json::{json, Map, Value};
+
+/// A test whose expectation was changed by recording
+pub struct ChangedExpectation {
+    /// The test file containing the test
+    pub file: String,
+    /// The font the expectation was recorded from
+    pub font: String,
+    /// The input string of the test
+    pub input: String,
+    /// The note attached to the test, if any
+    pub note: Option<String>,
+    /// Where the test was shaped, e.g. " @ wght=700"
+    pub location: String,
+    /// The expectation before recording, if there was one
+    pub old: Option<String>,
+    /// The newly recorded expectation
+    pub new: String,
+}
+
+impl ChangedExpectation {
+    /// Describe the change as a glyph-by-glyph diff of the old and new expectations
+    pub fn diff(&self) -> String {
+        let old = self.old.as_deref().unwrap_or_default().replace('|', "\n");
+        let new = self.new.replace('|', "\n");
+        similar::TextDiff::from_lines(&old, &new)
+            .iter_all_changes()
+            .map(|change| {
+                let sign = match change.tag() {
+                    similar::ChangeTag::Equal => " ",
+                    similar::ChangeTag::Delete => "-",
+                    similar::ChangeTag::Insert => "+",
+                };
+                format!("{} {}", sign, change.value().trim_end())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Record the shaping of known-good fonts as the expectations of shaping tests
+///
+/// Each test in the JSON files of `test_directory` which applies to a font is
+/// shaped, and the result is written back into the file as the test's expectation,
+/// serialized as the `shaping/regression` check will compare it. Tests which already
+/// have an expectation keep its form (with or without positions); new expectations
+/// include positions if `positions` is set. Everything else in the files is kept.
+///
+/// A test run at several `locations` gets an entry in its `expectations` for each
+/// location. When recording from more than one font, expectations which differ
+/// from the test's own are recorded as entries for the font. Text corpora and
+/// HarfBuzz test files are not rewritten, but their changes are still reported.
+/// If `write` is false, no files are changed and the changes are only reported.
+pub fn record_expectations(
+    fonts: &[Testable],
+    test_directory: &str,
+    configuration: &Map<String, Value>,
+    positions: bool,
+    write: bool,
+) -> Result<Vec<ChangedExpectation>, CheckError> {
+    let default_config = shaping_configuration(configuration)?;
+    let per_font = fonts.len() > 1;
+    let mut changes = vec![];
+    for file in test_files(test_directory)? {
+        let input = load_tests(&file, &default_config)?;
+        let is_json = file
+            .extension()
+            .is_some_and(|extension| extension == "json");
+        // Edit JSON files as plain JSON, so that fields we don't model survive
+        let mut document: Value = if is_json {
+            serde_json::from_str(&std::fs::read_to_string(&file)?)?
+        } else {
+            Value::Null
+        };
+        let mut changed = false;
+        for font in fonts {
+            let mut face = Face::from_slice(&font.contents, 0)
+                .ok_or(CheckError::Error("Failed to load font file".to_string()))?;
+            let basename = font.basename().unwrap_or_default();
+            for (index, test) in input.tests.iter().enumerate() {
+                if test.excluded(&basename) {
+                    continue;
+                }
+                for (source, case) in test.cases(&basename) {
+                    let options = case.options.fill_from_defaults(&input.configuration);
+                    let glyph_buffer = create_buffer_and_run(&mut face, &case.input, &options)?;
+                    let expectation = serialize_expectation(&glyph_buffer, &face, &case, positions);
+                    if case.expectation.as_ref() == Some(&expectation) {
+                        continue;
+                    }
+                    if let Some(test_object) = document
+                        .get_mut("tests")
+                        .and_then(|tests| tests.get_mut(index))
+                        .and_then(|test| test.as_object_mut())
+                    {
+                        let new_entry = |variations: Option<&Value>| {
+                            let mut entry = Map::new();
+                            if per_font {
+                                entry.insert("font".to_string(), json!(basename));
+                            }
+                            if let Some(variations) = variations {
+                                entry.insert("variations".to_string(), variations.clone());
+                            }
+                            entry.insert("expectation".to_string(), json!(expectation));
+                            Value::Object(entry)
+                        };
+                        let entry = match source {
+                            ExpectationSource::Test if !per_font => {
+                                test_object.insert("expectation".to_string(), json!(expectation));
+                                None
+                            }
+                            ExpectationSource::Test => Some(new_entry(None)),
+                            ExpectationSource::Location(location) => Some(new_entry(
+                                test_object
+                                    .get("locations")
+                                    .and_then(|locations| locations.get(location)),
+                            )),
+                            ExpectationSource::Entry(entry) => {
+                                if let Some(entry) = test_object
+                                    .get_mut("expectations")
+                                    .and_then(|entries| entries.get_mut(entry))
+                                    .and_then(|entry| entry.as_object_mut())
+                                {
+                                    entry.insert("expectation".to_string(), json!(expectation));
+                                }
+                                None
+                            }
+                        };
+                        if let Some(entry) = entry {
+                            if let Some(entries) = test_object
+                                .entry("expectations")
+                                .or_insert_with(|| json!([]))
+                                .as_array_mut()
+                            {
+                                entries.push(entry);
+                            }
+                        }
+                        changed = true;
+                    }
+                    changes.push(ChangedExpectation {
+                        file: file.to_string_lossy().to_string(),
+                        font: basename.clone(),
+                        input: case.input.clone(),
+                        note: case.note.clone(),
+                        location: options.location(),
+                        old: case.expectation.clone(),
+                        new: expectation,
+                    });
+                }
+            }
+        }
+        if changed && write {
+            std::fs::write(&file, serde_json::to_string_pretty(&document)? + "\n")?;
+        }
+    }
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::TEST_FILE;
+
+    #[test]
+    fn test_record_expectations() {
+        let directory =
+            std::env::temp_dir().join(format!("fontspector-record-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let file = directory.join("test.json");
+        std::fs::write(
+            &file,
+            serde_json::to_string_pretty(&json!({
+                "configuration": {},
+                "comment": "Not something we model",
+                "tests": [
+                    {"input": "AV", "expectation": "A=0+0|V=1+0", "note": "kerning"},
+                    {"input": "AV", "only": ["Slabo13px.ttf"], "expectation": "A=0+600|V=1+600"},
+                    {"input": "AV", "expectations": [
+                        {"font": "Slabo13px.ttf", "expectation": "A=0+600|V=1+600"}
+                    ]},
+                ]
+            }))
+            .unwrap(),
+        )
+        .unwrap();
+        let directory = directory.to_string_lossy().to_string();
+        let fonts = [TEST_FILE!("nunito/Nunito-Regular.ttf")];
+
+        let changes = record_expectations(&fonts, &directory, &Map::new(), false, true).unwrap();
+        assert_eq!(changes.len(), 2);
+        let recorded: Value =
+            serde_json::from_str(&std::fs::read_to_string(&file).unwrap()).unwrap();
+        assert_eq!(recorded["comment"], "Not something we model");
+        // The existing expectation keeps its form, with positions
+        assert_eq!(recorded["tests"][0]["expectation"], "A=0+664|V=1+691");
+        assert_eq!(recorded["tests"][0]["note"], "kerning");
+        // Tests for other fonts are left alone
+        assert_eq!(recorded["tests"][1]["expectation"], "A=0+600|V=1+600");
+        assert_eq!(
+            recorded["tests"][2]["expectations"],
+            json!([{"font": "Slabo13px.ttf", "expectation": "A=0+600|V=1+600"}])
+        );
+        assert_eq!(recorded["tests"][2]["expectation"], "A|V");
+
+        // Recording again changes nothing
+        let before = std::fs::read_to_string(&file).unwrap();
+        let changes = record_expectations(&fonts, &directory, &Map::new(), false, true).unwrap();
+        assert!(changes.is_empty());
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), before);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
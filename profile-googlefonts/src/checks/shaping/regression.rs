@@ -22,7 +22,8 @@ use rustybuzz::{Face, GlyphBuffer};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3223",
-    title = "Check that texts shape as per expectation"
+    title = "Check that texts shape as per expectation",
+    tags = ["slow"]
 )]
 fn regression(t: &Testable, context: &Context) -> CheckFnResult {
     let _f = testfont!(t); // We just use this to make sure it's a font
@@ -32,10 +33,11 @@ fn regression(t: &Testable, context: &Context) -> CheckFnResult {
         let mut report = String::new();
         for fail in fails {
             report.push_str(&format!(
-                "{}: {}{}\n{}\n\n",
+                "{}: {}{}{}\n{}\n\n",
                 message,
                 fail.test.input,
                 fail.test.note(),
+                fail.location,
                 fail.detail
             ));
         }
@@ -53,14 +55,33 @@ fn regression(t: &Testable, context: &Context) -> CheckFnResult {
     return_result(problems)
 }
 
-fn serialize_appropriately(buffer: &GlyphBuffer, face: &Face, test: &ShapingTest) -> String {
-    let mut flags = rustybuzz::SerializeFlags::default();
-    #[allow(clippy::unwrap_used)] // the .applies filter ensures there's an expectation
-    if !test.expectation.as_ref().unwrap().contains("=") {
-        flags |= rustybuzz::SerializeFlags::NO_POSITIONS
-            | rustybuzz::SerializeFlags::NO_ADVANCES
-            | rustybuzz::SerializeFlags::NO_CLUSTERS;
-    }
+/// Serialize a shaped buffer in the form used for a test's expectation
+///
+/// Tests read from HarfBuzz test files say how they are serialized. Otherwise
+/// the output has glyph names only, or clusters and positions too if the
+/// expectation has them (or, without an expectation, if `positions` is set).
+pub(crate) fn serialize_expectation(
+    buffer: &GlyphBuffer,
+    face: &Face,
+    test: &ShapingTest,
+    positions: bool,
+) -> String {
+    let flags = test
+        .serialize_flags
+        .map(rustybuzz::SerializeFlags::from_bits_truncate)
+        .unwrap_or_else(|| {
+            let positions = test
+                .expectation
+                .as_ref()
+                .map_or(positions, |expectation| expectation.contains('='));
+            if positions {
+                rustybuzz::SerializeFlags::default()
+            } else {
+                rustybuzz::SerializeFlags::NO_POSITIONS
+                    | rustybuzz::SerializeFlags::NO_ADVANCES
+                    | rustybuzz::SerializeFlags::NO_CLUSTERS
+            }
+        });
     buffer.serialize(face, flags)
 }
 
@@ -74,9 +95,9 @@ impl ShapingCheck for RegressionTest {
         buffer: &GlyphBuffer,
         face: &Face,
     ) -> Option<String> {
-        let serialized = serialize_appropriately(buffer, face, test);
         #[allow(clippy::unwrap_used)] // the .applies filter ensures there's an expectation
         let expected = test.expectation.as_ref().unwrap();
+        let serialized = serialize_expectation(buffer, face, test, false);
         println!("Expected: {}\nGot     : {}\n", expected, serialized);
         if &serialized == expected {
             return None;
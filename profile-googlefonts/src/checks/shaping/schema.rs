@@ -1,3 +1,4 @@
+use itertools::Itertools;
 use serde::Deserialize;
 use std::collections::HashMap;
 
@@ -7,15 +8,18 @@ pub struct ShapingInput {
     pub tests: Vec<ShapingTest>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone, Default)]
 pub struct ShapingConfig {
     #[serde(default)]
     pub defaults: ShapingOptions,
     #[serde(default)]
     pub forbidden_glyphs: Vec<String>,
+    pub collidoscope: Option<CollidoscopeOptions>,
+    #[serde(default, rename = "allowedcollisions")]
+    pub allowed_collisions: Vec<String>,
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, Default)]
 pub struct ShapingTest {
     #[serde(default)]
     pub exclude: Vec<String>,
@@ -26,9 +30,93 @@ pub struct ShapingTest {
     pub input: String,
     pub expectation: Option<String>,
     pub note: Option<String>,
+    pub collidoscope: Option<CollidoscopeOptions>,
+    /// Locations to run the test at, instead of the single `variations`
+    #[serde(default)]
+    pub locations: Vec<HashMap<String, f32>>,
+    /// Expectations for particular fonts and locations
+    #[serde(default)]
+    pub expectations: Vec<ExpectationEntry>,
+    /// How to serialize the shaping for comparison (as `SerializeFlags` bits),
+    /// when it isn't implied by the expectation
+    #[serde(skip)]
+    pub serialize_flags: Option<u8>,
+}
+
+/// An expectation which only applies to one font, or at one location
+#[derive(Deserialize, Clone)]
+pub struct ExpectationEntry {
+    pub font: Option<String>,
+    pub variations: Option<HashMap<String, f32>>,
+    pub expectation: String,
+}
+
+/// Where the expectation of a test case comes from in its test file
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum ExpectationSource {
+    /// The `expectation` of the test itself
+    Test,
+    /// An entry of the test's `expectations`
+    Entry(usize),
+    /// One of the test's `locations`, which has no expectation of its own
+    Location(usize),
 }
 
 impl ShapingTest {
+    /// The cases to run this test as for a font: one for each expectation which
+    /// applies to the font, or otherwise one for each location
+    pub(crate) fn cases(&self, font: &str) -> Vec<(ExpectationSource, ShapingTest)> {
+        let entries = self
+            .expectations
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.font.as_ref().is_none_or(|f| f == font))
+            .map(|(index, entry)| {
+                (
+                    ExpectationSource::Entry(index),
+                    self.at(
+                        entry.variations.clone().or(self.options.variations.clone()),
+                        Some(entry.expectation.clone()),
+                    ),
+                )
+            })
+            .collect::<Vec<_>>();
+        if !entries.is_empty() {
+            entries
+        } else if self.locations.is_empty() {
+            vec![(ExpectationSource::Test, self.clone())]
+        } else {
+            self.locations
+                .iter()
+                .enumerate()
+                .map(|(index, location)| {
+                    (
+                        ExpectationSource::Location(index),
+                        self.at(Some(location.clone()), self.expectation.clone()),
+                    )
+                })
+                .collect()
+        }
+    }
+
+    /// A copy of this test at a single location, with a single expectation
+    fn at(
+        &self,
+        variations: Option<HashMap<String, f32>>,
+        expectation: Option<String>,
+    ) -> ShapingTest {
+        ShapingTest {
+            options: ShapingOptions {
+                variations,
+                ..self.options.clone()
+            },
+            expectation,
+            locations: vec![],
+            expectations: vec![],
+            ..self.clone()
+        }
+    }
+
     pub(crate) fn excluded(&self, file: &str) -> bool {
         let file = file.to_string();
         self.exclude.contains(&file) || (!self.only.is_empty() && !self.only.contains(&file))
@@ -66,4 +154,62 @@ impl ShapingOptions {
                 .or(config.defaults.variations.clone()),
         }
     }
+
+    /// Describe the location the options shape at, e.g. " @ wdth=75,wght=700"
+    pub(crate) fn location(&self) -> String {
+        self.variations
+            .as_ref()
+            .filter(|variations| !variations.is_empty())
+            .map(|variations| {
+                format!(
+                    " @ {}",
+                    variations
+                        .iter()
+                        .sorted_by(|a, b| a.0.cmp(b.0))
+                        .map(|(tag, value)| format!("{}={}", tag, value))
+                        .join(",")
+                )
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Which kinds of glyph pairs the collision check looks at
+#[derive(Deserialize, Clone)]
+pub struct CollidoscopeOptions {
+    /// Check base glyphs against other base glyphs
+    #[serde(default = "yes")]
+    pub bases: bool,
+    /// Check marks against other marks, and against bases they are not attached to
+    #[serde(default = "yes")]
+    pub marks: bool,
+    /// Check glyphs in neighbouring clusters
+    #[serde(default = "yes")]
+    pub adjacent_clusters: bool,
+    /// Check glyphs which are further apart than neighbouring clusters
+    #[serde(default = "yes")]
+    pub faraway: bool,
+    /// Check bases which are joined by a cursive attachment
+    #[serde(default)]
+    pub cursive: bool,
+    /// Percentage of the smaller glyph's area which must overlap to count as a collision
+    #[serde(default)]
+    pub area: f32,
+}
+
+fn yes() -> bool {
+    true
+}
+
+impl Default for CollidoscopeOptions {
+    fn default() -> Self {
+        CollidoscopeOptions {
+            bases: true,
+            marks: true,
+            adjacent_clusters: true,
+            faraway: true,
+            cursive: false,
+            area: 0.0,
+        }
+    }
 }
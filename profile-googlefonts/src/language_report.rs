@@ -0,0 +1,406 @@
+//! An inventory of the languages, Unicode blocks and glyphsets a font supports
+//!
+//! Unlike the `googlefonts/glyphsets/shape_languages` check, which only looks
+//! at languages in the GF glyphsets a font mostly covers and only reports
+//! problems, this reports on every language shaperglot knows about.
+use std::collections::{BTreeMap, HashSet};
+
+use fontspector_checkapi::{is_graphic, CheckError, FileTypeConvert, Glyphset, Testable, TTF};
+use google_fonts_glyphsets::get_glyphset_coverage;
+use google_fonts_languages::LANGUAGES;
+use markdown_table::{Heading, MarkdownTable};
+use serde::Serialize;
+use shaperglot::{Checker, Languages, ResultCode};
+use unicode_normalization::UnicodeNormalization;
+
+/// How well a font supports a language
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Support {
+    /// All base and mark exemplars are present and shape correctly
+    Supported,
+    /// Some of the base exemplars are present, but something is missing or misshapes
+    Partial,
+    /// None of the base exemplars are present
+    Unsupported,
+}
+
+/// A language, and how well a font supports it
+#[derive(Serialize, Debug)]
+pub struct LanguageSupport {
+    /// The language ID, e.g. `en_Latn`
+    pub id: String,
+    /// The language's name
+    pub name: String,
+    /// How well the font supports the language
+    pub support: Support,
+    /// Base exemplar characters the font does not have
+    pub missing_bases: Vec<String>,
+    /// Mark exemplar characters the font does not have
+    pub missing_marks: Vec<String>,
+    /// Shaping problems found by shaperglot
+    pub problems: Vec<String>,
+}
+
+/// How many of the assigned characters in a Unicode block a font has
+#[derive(Serialize, Debug)]
+pub struct BlockCoverage {
+    /// The name of the block
+    pub name: String,
+    /// The first codepoint of the block
+    pub start: u32,
+    /// The last codepoint of the block
+    pub end: u32,
+    /// Number of the block's characters in the font
+    pub covered: usize,
+    /// Number of assigned characters in the block, not counting control characters
+    pub total: usize,
+}
+
+/// How much of a glyphset a font covers
+#[derive(Serialize, Debug)]
+pub struct GlyphsetCoverage {
+    /// The name of the glyphset
+    pub name: String,
+    /// Percentage of the glyphset's characters in the font
+    pub percentage: f64,
+    /// Codepoints of the glyphset which the font does not have
+    pub missing: Vec<String>,
+}
+
+/// The languages, blocks and glyphsets supported by a font
+#[derive(Serialize, Debug)]
+pub struct LanguageReport {
+    /// The font's filename
+    pub font: String,
+    /// Every language known to shaperglot
+    pub languages: Vec<LanguageSupport>,
+    /// Unicode blocks in which the font has at least one character
+    pub blocks: Vec<BlockCoverage>,
+    /// Google Fonts glyphsets of which the font has at least one character
+    pub glyphsets: Vec<GlyphsetCoverage>,
+    /// User-defined glyphsets which apply to the font
+    pub custom_glyphsets: Vec<GlyphsetCoverage>,
+}
+
+/// Split a string of exemplars into characters, dropping the dotted circles used to display marks
+fn exemplar_chars(exemplars: &str) -> Vec<char> {
+    let mut chars = exemplars
+        .split_whitespace()
+        .map(|exemplar| exemplar.trim_start_matches('{').trim_end_matches('}'))
+        .flat_map(|exemplar| exemplar.nfc().collect::<Vec<_>>())
+        .filter(|c| *c != '\u{25CC}')
+        .collect::<Vec<_>>();
+    chars.sort();
+    chars.dedup();
+    chars
+}
+
+fn missing(chars: &[char], codepoints: &HashSet<u32>) -> Vec<String> {
+    chars
+        .iter()
+        .filter(|c| !codepoints.contains(&(**c as u32)))
+        .map(|c| format!("{} (U+{:04X})", c, *c as u32))
+        .collect()
+}
+
+/// Report which languages, Unicode blocks and glyphsets a font supports
+///
+/// As well as the Google Fonts glyphsets, the coverage of any of the `custom_glyphsets`
+/// which apply to the font is reported.
+pub fn language_report(
+    t: &Testable,
+    custom_glyphsets: &[Glyphset],
+) -> Result<LanguageReport, CheckError> {
+    let f = TTF
+        .from_testable(t)
+        .ok_or(CheckError::Error("Not a TrueType font".to_string()))?;
+    let checker = Checker::new(&t.contents).map_err(|e| CheckError::Error(e.to_string()))?;
+    let codepoints = f.codepoints(None);
+
+    let mut languages = vec![];
+    for language in Languages::new().iter() {
+        let exemplars = LANGUAGES
+            .get(language.id())
+            .and_then(|proto| proto.exemplar_chars.as_ref());
+        let bases = exemplars.map_or(vec![], |e| exemplar_chars(e.base()));
+        let marks = exemplars.map_or(vec![], |e| exemplar_chars(e.marks()));
+        let missing_bases = missing(&bases, &codepoints);
+        let mut support = LanguageSupport {
+            id: language.id().to_string(),
+            name: language.name().to_string(),
+            support: Support::Unsupported,
+            missing_marks: missing(&marks, &codepoints),
+            missing_bases,
+            problems: vec![],
+        };
+        // Don't bother shaping text in a script the font doesn't cover at all
+        if support.missing_bases.len() < bases.len() {
+            support.problems = checker
+                .check(language)
+                .iter()
+                .filter(|result| matches!(result.status, ResultCode::Warn | ResultCode::Fail))
+                .map(|result| result.to_string())
+                .collect();
+            support.support = if support.missing_bases.is_empty()
+                && support.missing_marks.is_empty()
+                && support.problems.is_empty()
+            {
+                Support::Supported
+            } else {
+                Support::Partial
+            };
+        }
+        languages.push(support);
+    }
+    languages.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut blocks = BTreeMap::new();
+    for c in codepoints
+        .iter()
+        .flat_map(|cp| char::from_u32(*cp))
+        .filter(|c| is_graphic(*c))
+    {
+        if let Some(block) = yeslogic_unicode_blocks::find_unicode_block(c) {
+            blocks
+                .entry(block.start())
+                .or_insert_with(|| BlockCoverage {
+                    name: block.name().to_string(),
+                    start: block.start(),
+                    end: block.end(),
+                    covered: 0,
+                    total: (block.start()..=block.end())
+                        .flat_map(char::from_u32)
+                        .filter(|c| is_graphic(*c))
+                        .count(),
+                })
+                .covered += 1;
+        }
+    }
+
+    let mut glyphsets = get_glyphset_coverage(&codepoints)
+        .iter()
+        .filter(|(_name, coverage)| coverage.fraction > 0.0)
+        .map(|(name, coverage)| {
+            let mut missing = coverage
+                .missing
+                .iter()
+                .map(|c| format!("U+{:04X}", c))
+                .collect::<Vec<_>>();
+            missing.sort();
+            GlyphsetCoverage {
+                name: name.to_string(),
+                percentage: f64::from(coverage.fraction) * 100.0,
+                missing,
+            }
+        })
+        .collect::<Vec<_>>();
+    glyphsets.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let custom_glyphsets = custom_glyphsets
+        .iter()
+        .filter(|glyphset| glyphset.applies_to(&t.basename().unwrap_or_default()))
+        .map(|glyphset| {
+            let expected = glyphset.codepoints()?;
+            let missing = expected
+                .iter()
+                .filter(|c| !codepoints.contains(c))
+                .map(|c| format!("U+{:04X}", c))
+                .collect::<Vec<_>>();
+            Ok(GlyphsetCoverage {
+                name: glyphset.name.clone(),
+                percentage: if expected.is_empty() {
+                    100.0
+                } else {
+                    (expected.len() - missing.len()) as f64 * 100.0 / expected.len() as f64
+                },
+                missing,
+            })
+        })
+        .collect::<Result<Vec<_>, CheckError>>()?;
+
+    Ok(LanguageReport {
+        font: t.basename().unwrap_or_default(),
+        languages,
+        blocks: blocks.into_values().collect(),
+        glyphsets,
+        custom_glyphsets,
+    })
+}
+
+fn table(headings: &[&str], rows: Vec<Vec<String>>) -> String {
+    if rows.is_empty() {
+        return "None\n".to_string();
+    }
+    let mut table = MarkdownTable::new(rows);
+    table.with_headings(
+        headings
+            .iter()
+            .map(|heading| Heading::new(heading.to_string(), None))
+            .collect(),
+    );
+    table.as_markdown().unwrap_or_default()
+}
+
+impl LanguageReport {
+    /// Format the report as Markdown
+    pub fn to_markdown(&self) -> String {
+        let count = |support| {
+            self.languages
+                .iter()
+                .filter(|language| language.support == support)
+                .count()
+        };
+        let mut markdown = format!(
+            "## {}\n\n{} languages supported, {} partially supported, {} unsupported.\n\n",
+            self.font,
+            count(Support::Supported),
+            count(Support::Partial),
+            count(Support::Unsupported),
+        );
+
+        markdown.push_str("### Supported languages\n\n");
+        let supported = self
+            .languages
+            .iter()
+            .filter(|language| language.support == Support::Supported)
+            .map(|language| format!("{} ({})", language.name, language.id))
+            .collect::<Vec<_>>();
+        if supported.is_empty() {
+            markdown.push_str("None\n");
+        } else {
+            markdown.push_str(&supported.join(", "));
+            markdown.push('\n');
+        }
+
+        markdown.push_str("\n### Partially supported languages\n\n");
+        markdown.push_str(&table(
+            &["Language", "Missing bases", "Missing marks", "Problems"],
+            self.languages
+                .iter()
+                .filter(|language| language.support == Support::Partial)
+                .map(|language| {
+                    vec![
+                        format!("{} ({})", language.name, language.id),
+                        language.missing_bases.join(", "),
+                        language.missing_marks.join(", "),
+                        language.problems.join("<br>").replace('\n', " "),
+                    ]
+                })
+                .collect(),
+        ));
+
+        markdown.push_str("\n### Unsupported languages\n\n");
+        markdown.push_str(
+            &self
+                .languages
+                .iter()
+                .filter(|language| language.support == Support::Unsupported)
+                .map(|language| language.id.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+
+        markdown.push_str("\n\n### Unicode blocks\n\n");
+        markdown.push_str(&table(
+            &["Block", "Range", "Coverage"],
+            self.blocks
+                .iter()
+                .map(|block| {
+                    vec![
+                        block.name.clone(),
+                        format!("U+{:04X}..U+{:04X}", block.start, block.end),
+                        format!("{}/{}", block.covered, block.total),
+                    ]
+                })
+                .collect(),
+        ));
+
+        markdown.push_str("\n### Glyphsets\n\n");
+        markdown.push_str(&table(
+            &["Glyphset", "Coverage", "Missing"],
+            self.glyphsets
+                .iter()
+                .chain(self.custom_glyphsets.iter())
+                .map(|glyphset| {
+                    vec![
+                        glyphset.name.clone(),
+                        format!("{:.1}%", glyphset.percentage),
+                        glyphset.missing.join(", "),
+                    ]
+                })
+                .collect(),
+        ));
+        markdown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::TEST_FILE;
+
+    #[test]
+    fn test_language_report() {
+        let custom = Glyphset {
+            name: "Latin and Zhe".to_string(),
+            codepoints: vec!["A".to_string(), "U+0416".to_string()],
+            ..Default::default()
+        };
+        let report = language_report(&TEST_FILE!("nunito/Nunito-Regular.ttf"), &[custom]).unwrap();
+        assert_eq!(report.font, "Nunito-Regular.ttf");
+
+        // shaperglot
+        let language = |id: &str| {
+            report
+                .languages
+                .iter()
+                .find(|language| language.id == id)
+                .unwrap()
+        };
+        assert_eq!(language("en_Latn").support, Support::Supported);
+        assert!(language("en_Latn").missing_bases.is_empty());
+        assert_eq!(language("ar_Arab").support, Support::Unsupported);
+        assert!(language("ar_Arab").problems.is_empty());
+
+        // Unicode blocks, counting only characters a font would be expected to have
+        let basic_latin = report
+            .blocks
+            .iter()
+            .find(|block| block.name == "Basic Latin")
+            .unwrap();
+        assert_eq!((basic_latin.covered, basic_latin.total), (95, 95));
+        assert!(!report.blocks.iter().any(|block| block.name == "Arabic"));
+
+        // Glyphsets the font doesn't touch are left out
+        assert!(report
+            .glyphsets
+            .iter()
+            .any(|glyphset| glyphset.name == "GF_Latin_Kernel" && glyphset.percentage > 0.0));
+        assert!(!report
+            .glyphsets
+            .iter()
+            .any(|glyphset| glyphset.name == "GF_Arabic_Core"));
+        assert_eq!(report.custom_glyphsets.len(), 1);
+        assert_eq!(report.custom_glyphsets[0].percentage, 50.0);
+        assert_eq!(report.custom_glyphsets[0].missing, vec!["U+0416"]);
+
+        let markdown = report.to_markdown();
+        for section in [
+            "### Supported languages",
+            "### Unsupported languages",
+            "### Unicode blocks",
+            "### Glyphsets",
+            "Basic Latin",
+            "GF_Latin_Kernel",
+            "Latin and Zhe",
+        ] {
+            assert!(
+                markdown.contains(section),
+                "{} missing from report",
+                section
+            );
+        }
+    }
+}
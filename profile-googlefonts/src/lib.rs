@@ -2,10 +2,13 @@
 mod checks;
 
 pub mod constants;
+pub mod language_report;
 use fontspector_checkapi::{prelude::*, ProfileBuilder, Registry};
 
 mod network_conditions;
 mod utils;
+#[cfg(not(target_family = "wasm"))]
+pub use checks::shaping::{record_expectations, shaping_test_directory, ChangedExpectation};
 use serde_json::json;
 use std::collections::HashMap;
 
@@ -81,6 +84,7 @@ impl fontspector_checkapi::Plugin for GoogleFonts {
             .add_and_register_check(checks::googlefonts::metadata::valid_nameid25)
             .add_and_register_check(checks::googlefonts::metadata::weightclass)
             .add_section("Glyphset Checks")
+            .add_and_register_check(checks::googlefonts::glyphsets::mark_anchors)
             .add_and_register_check(checks::googlefonts::glyphsets::shape_languages)
             .add_and_register_check(checks::googlefonts::tofu)
             .add_and_register_check(checks::googlefonts::separator_glyphs)
@@ -127,8 +131,7 @@ impl fontspector_checkapi::Plugin for GoogleFonts {
             .add_and_register_check(checks::dotted_circle);
         #[cfg(not(target_family = "wasm"))]
         let builder = builder
-            //            Realistically Simon is the only person who uses this check, and it can wait until he needs it again.
-            //            checks::shaping::collides
+            .add_and_register_check(checks::shaping::collides)
             .add_and_register_check(checks::shaping::forbidden)
             .add_and_register_check(checks::shaping::regression)
             .add_and_register_check(checks::soft_dotted);
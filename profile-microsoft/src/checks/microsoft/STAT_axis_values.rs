@@ -9,7 +9,8 @@ use skrifa::raw::{tables::stat::AxisValue, TableProvider};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/4657",
-    title = "STAT axis values must be unique."
+    title = "STAT axis values must be unique.",
+    tags = ["variable"]
 )]
 fn STAT_axis_values(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -9,7 +9,8 @@ use skrifa::raw::TableProvider;
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/4657",
-    title = "STAT table axis order."
+    title = "STAT table axis order.",
+    tags = ["variable"]
 )]
 fn STAT_table_axis_order(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
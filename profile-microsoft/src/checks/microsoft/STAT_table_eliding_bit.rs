@@ -9,7 +9,8 @@ use skrifa::raw::{tables::stat::AxisValueTableFlags, TableProvider};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/4657",
-    title = "Validate STAT table eliding bit"
+    title = "Validate STAT table eliding bit",
+    tags = ["variable"]
 )]
 fn STAT_table_eliding_bit(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
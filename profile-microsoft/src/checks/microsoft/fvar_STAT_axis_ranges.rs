@@ -11,7 +11,8 @@ use skrifa::raw::{tables::stat::AxisValue, TableProvider};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/4657",
-    title = "Requirements for named instances and STAT axis ranges."
+    title = "Requirements for named instances and STAT axis ranges.",
+    tags = ["variable"]
 )]
 fn fvar_STAT_axis_ranges(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
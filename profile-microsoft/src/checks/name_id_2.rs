@@ -8,7 +8,8 @@ use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/4657",
-    title = "Font has a name with ID 2."
+    title = "Font has a name with ID 2.",
+    tags = ["naming"]
 )]
 fn name_id_2(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -9,7 +9,8 @@ use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/4657",
-    title = "Maximum allowed length for family and subfamily names."
+    title = "Maximum allowed length for family and subfamily names.",
+    tags = ["naming"]
 )]
 fn name_length_req(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
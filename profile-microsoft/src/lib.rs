@@ -37,4 +37,5 @@ impl fontspector_checkapi::Plugin for Microsoft {
     }
 }
 
-pluginator::plugin_implementation!(fontspector_checkapi::Plugin, Microsoft);
+#[cfg(all(feature = "plugin", not(target_family = "wasm")))]
+fontspector_checkapi::declare_plugin!(Microsoft);
@@ -0,0 +1,2 @@
+mod unexpected_subtables;
+pub use unexpected_subtables::unexpected_subtables;
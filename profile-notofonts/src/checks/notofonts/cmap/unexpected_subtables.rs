@@ -0,0 +1,91 @@
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
+use skrifa::raw::TableProvider;
+
+/// The (format, platform ID, encoding ID) combinations we expect to see in a font
+const EXPECTED_SUBTABLES: [(u16, u16, u16); 7] = [
+    (0, 1, 0),   // Mac Roman, byte encoding table
+    (6, 1, 0),   // Mac Roman, trimmed table mapping
+    (4, 0, 3),   // Unicode 2.0+, BMP only
+    (4, 3, 1),   // Windows, BMP only
+    (12, 0, 4),  // Unicode 2.0+, full repertoire
+    (12, 3, 10), // Windows, full repertoire
+    (14, 0, 5),  // Unicode Variation Sequences
+];
+
+/// The subtable which a symbol font is additionally expected to have
+const SYMBOL_SUBTABLE: (u16, u16, u16) = (4, 3, 0);
+
+#[check(
+    id = "notofonts/cmap/unexpected_subtables",
+    rationale = "
+        There are just a few typical types of cmap subtables that are used in fonts.
+        If anything different is declared in a font, it will be treated as a FAIL.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/issues/2676",
+    title = "Ensure all cmap subtables are the typical types expected in a font.",
+    codes = {
+        "unexpected-subtable": WARN,
+    }
+)]
+fn unexpected_subtables(t: &Testable, _context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let cmap = f.font().cmap()?;
+    // Bit 31 of ulCodePageRange1 is "Symbol Character Set"
+    let is_symbol_font = f
+        .font()
+        .os2()
+        .ok()
+        .and_then(|os2| os2.ul_code_page_range_1())
+        .is_some_and(|codepages| codepages & (1 << 31) != 0);
+    let mut problems = vec![];
+    for record in cmap.encoding_records() {
+        let subtable = record.subtable(cmap.offset_data())?;
+        let key = (
+            subtable.format(),
+            record.platform_id() as u16,
+            record.encoding_id(),
+        );
+        if EXPECTED_SUBTABLES.contains(&key) || (is_symbol_font && key == SYMBOL_SUBTABLE) {
+            continue;
+        }
+        problems.push(Status::warn(
+            "unexpected-subtable",
+            &format!(
+                "'cmap' has a subtable of (format={}, platform={}, encoding={}), which it shouldn't have.",
+                key.0, key.1, key.2
+            ),
+        ));
+    }
+    return_result(problems)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, replace_table, run_check},
+        StatusCode, TEST_FILE,
+    };
+    use skrifa::{raw::tables::cmap::PlatformId, FontRef};
+    use write_fonts::{from_obj::FromTableRef, tables::cmap::Cmap};
+
+    #[test]
+    fn test_unexpected_subtables() {
+        let mut testable = TEST_FILE!("montserrat/Montserrat-Regular.ttf");
+        assert_pass(run_check(unexpected_subtables, testable.clone()));
+
+        // A subtable on the ISO platform is not something we expect
+        let font = FontRef::new(&testable.contents).unwrap();
+        let mut cmap = Cmap::from_table_ref(&font.cmap().unwrap());
+        let mut record = cmap.encoding_records[0].clone();
+        record.platform_id = PlatformId::ISO;
+        cmap.encoding_records.push(record);
+        replace_table(&mut testable, &cmap);
+        assert_results_contain(
+            run_check(unexpected_subtables, testable),
+            StatusCode::Warn,
+            Some("unexpected-subtable".to_string()),
+        );
+    }
+}
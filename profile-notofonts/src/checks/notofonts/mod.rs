@@ -0,0 +1,3 @@
+pub mod cmap;
+mod unicode_range_bits;
+pub use unicode_range_bits::unicode_range_bits;
@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
+use skrifa::raw::TableProvider;
+
+use crate::constants::UNICODERANGE_DATA;
+
+/// The codepoints in the font which fall into the ranges of a given UnicodeRange bit
+fn chars_in_range(codepoints: &HashSet<u32>, bit: usize) -> usize {
+    codepoints
+        .iter()
+        .filter(|cp| {
+            UNICODERANGE_DATA[bit]
+                .iter()
+                .any(|(_, start, end)| (start..=end).contains(cp))
+        })
+        .count()
+}
+
+#[check(
+    id = "notofonts/unicode_range_bits",
+    rationale = "
+        When the UnicodeRange bits on the OS/2 table are not properly set,
+        some programs running on Windows may not recognize the font and use a
+        system fallback font instead. For that reason, this check calculates the
+        proper settings by inspecting the glyphs declared on the cmap table and
+        then ensures that their corresponding ranges are enabled.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/issues/2676",
+    title = "Ensure UnicodeRange bits are properly set.",
+    codes = {
+        "bad-range-bit": WARN,
+    }
+)]
+fn unicode_range_bits(t: &Testable, context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let os2 = f.font().os2()?;
+    let unicoderange: u128 = (os2.ul_unicode_range_1() as u128)
+        | ((os2.ul_unicode_range_2() as u128) << 32)
+        | ((os2.ul_unicode_range_3() as u128) << 64)
+        | ((os2.ul_unicode_range_4() as u128) << 96);
+    let codepoints = f.codepoints(Some(context));
+    let mut problems = vec![];
+    for (bit, blocks) in UNICODERANGE_DATA.iter().enumerate() {
+        let is_set = unicoderange & (1 << bit) != 0;
+        let num_chars = chars_in_range(&codepoints, bit);
+        if is_set == (num_chars > 0) {
+            continue;
+        }
+        let range_size: u32 = blocks.iter().map(|(_, start, end)| end - start + 1).sum();
+        problems.push(Status::warn(
+            "bad-range-bit",
+            &format!(
+                "UnicodeRange bit {} \"{}\" should be {} because cmap has {} of the {} codepoints in this range.",
+                bit,
+                blocks[0].0,
+                if num_chars == 0 { "unset" } else { "set" },
+                num_chars,
+                range_size
+            ),
+        ));
+    }
+    return_result(problems)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_results_contain, replace_table, run_check},
+        StatusCode, TEST_FILE,
+    };
+    use skrifa::FontRef;
+    use write_fonts::{from_obj::FromTableRef, tables::os2::Os2};
+
+    #[test]
+    fn test_unicode_range_bits() {
+        let mut testable = TEST_FILE!("montserrat/Montserrat-Regular.ttf");
+        let font = FontRef::new(&testable.contents).unwrap();
+        let mut os2 = Os2::from_table_ref(&font.os2().unwrap());
+        os2.ul_unicode_range_1 = 0;
+        replace_table(&mut testable, &os2);
+        // The font has Basic Latin characters, but bit 0 is no longer set
+        assert_results_contain(
+            run_check(unicode_range_bits, testable),
+            StatusCode::Warn,
+            Some("bad-range-bit".to_string()),
+        );
+    }
+}
@@ -0,0 +1,17 @@
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+mod checks;
+
+pub mod constants;
+use fontspector_checkapi::{ProfileBuilder, Registry};
+
+pub struct NotoFonts;
+impl fontspector_checkapi::Plugin for NotoFonts {
+    fn register(&self, cr: &mut Registry) -> Result<(), String> {
+        let builder = ProfileBuilder::new()
+            .include_profile("universal")
+            .add_section("Noto Fonts Checks")
+            .add_and_register_check(checks::notofonts::cmap::unexpected_subtables)
+            .add_and_register_check(checks::notofonts::unicode_range_bits);
+        builder.build("notofonts", cr)
+    }
+}
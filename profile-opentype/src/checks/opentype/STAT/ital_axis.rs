@@ -209,7 +209,8 @@ fn check_ital_is_binary_and_last(t: &TestFont, is_italic: bool) -> Result<Vec<St
     proposal = "https://github.com/fonttools/fontbakery/issues/3668",
     proposal = "https://github.com/fonttools/fontbakery/issues/3669",
     implementation = "all",
-    title = "Ensure VFs have 'ital' STAT axis."
+    title = "Ensure VFs have 'ital' STAT axis.",
+    tags = ["variable"]
 )]
 fn ital_axis(c: &TestableCollection, _context: &Context) -> CheckFnResult {
     let fonts = TTF.from_collection(c);
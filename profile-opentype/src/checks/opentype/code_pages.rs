@@ -22,7 +22,10 @@ use skrifa::raw::TableProvider;
         So here we simply detect as a FAIL when a given font has no code page
         declared at all.
     ",
-    proposal = "https://github.com/fonttools/fontbakery/issues/2474"
+    proposal = "https://github.com/fonttools/fontbakery/issues/2474",
+    codes = {
+        "no-code-pages": FAIL,
+    }
 )]
 fn code_pages(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -32,7 +32,8 @@ use skrifa::MetadataProvider;
     "#,
     proposal = "https://github.com/fonttools/fontbakery/issues/4112",
     title = "Verify that family names in the name table are consistent across all fonts in the family. Checks Typographic Family name (nameID 16) if present, otherwise uses Font Family name (nameID 1)",
-    implementation = "all"
+    implementation = "all",
+    tags = ["naming"]
 )]
 fn consistent_family_name(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let fonts = TTF.from_collection(c);
@@ -18,7 +18,11 @@ const NAME_LIMITS: [(NameId, usize); 6] = [
         recommended by the OpenType specification.
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Font follows the family naming recommendations?"
+    title = "Font follows the family naming recommendations?",
+    tags = ["naming"],
+    codes = {
+        "bad-entries": INFO,
+    }
 )]
 fn family_naming_recommendations(t: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(t);
@@ -9,7 +9,8 @@ use skrifa::MetadataProvider;
         If a variable font has a 'wdth' (Width) axis, the valid numeric range is strictly greater than zero.
         If a variable font has a 'slnt' (Slant) axis, then the coordinate of its 'Regular' instance is required to be 0.
         If a variable font has a 'ital' (Slant) axis, then the coordinate of its 'Regular' instance is required to be 0.",
-    proposal = "https://github.com/fonttools/fontbakery/issues/2572"
+    proposal = "https://github.com/fonttools/fontbakery/issues/2572",
+    tags = ["variable"]
 )]
 fn axis_ranges_correct(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
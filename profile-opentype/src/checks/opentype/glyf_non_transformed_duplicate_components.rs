@@ -17,7 +17,8 @@ use std::collections::HashSet;
         which have the same x,y coordinates.
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/2709",
-    title = "Check glyphs do not have duplicate components which have the same x,y coordinates."
+    title = "Check glyphs do not have duplicate components which have the same x,y coordinates.",
+    tags = ["outline"]
 )]
 fn glyf_non_transformed_duplicate_components(t: &Testable, context: &Context) -> CheckFnResult {
     let ttf = testfont!(t);
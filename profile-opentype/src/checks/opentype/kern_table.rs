@@ -1,38 +1,73 @@
-// Fontations doesn't yet support kern table
+use std::collections::HashSet;
 
-// use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
-// use skrifa::raw::TableProvider;
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
+use skrifa::MetadataProvider;
 
-// #[check(
-//     id = "opentype/kern_table",
-//     rationale = "
-//         Even though all fonts should have their kerning implemented in the GPOS table,
-//         there may be kerning info at the kern table as well.
+#[check(
+    id = "opentype/kern_table",
+    rationale = "
+        Even though all fonts should have their kerning implemented in the GPOS table,
+        there may be kerning info at the kern table as well.
 
-//         Some applications such as MS PowerPoint require kerning info on the kern table.
-//         More specifically, they require a format 0 kern subtable from a kern table
-//         version 0 with only glyphs defined in the cmap table, which is the only one
-//         that Windows understands (and which is also the simplest and more limited
-//         of all the kern subtables).
+        Some applications such as MS PowerPoint require kerning info on the kern table.
+        More specifically, they require a format 0 kern subtable from a kern table
+        version 0 with only glyphs defined in the cmap table, which is the only one
+        that Windows understands (and which is also the simplest and more limited
+        of all the kern subtables).
 
-//         Google Fonts ingests fonts made for download and use on desktops, and does
-//         all web font optimizations in the serving pipeline (using libre libraries
-//         that anyone can replicate.)
+        Google Fonts ingests fonts made for download and use on desktops, and does
+        all web font optimizations in the serving pipeline (using libre libraries
+        that anyone can replicate.)
 
-//         Ideally, TTFs intended for desktop users (and thus the ones intended for
-//         Google Fonts) should have both KERN and GPOS tables.
+        Ideally, TTFs intended for desktop users (and thus the ones intended for
+        Google Fonts) should have both KERN and GPOS tables.
 
-//         Given all of the above, we currently treat kerning on a v0 kern table
-//         as a good-to-have (but optional) feature.
-//     ",
-//     proposal = "https://github.com/fonttools/fontbakery/issues/1675",
-//     title = "Is there a usable 'kern' table declared in the font?"
-// )]
-// fn kern_table(f: &Testable, _context: &Context) -> CheckFnResult {
-//     let font = testfont!(f);
-//     if !font.has_table(b"kern") {
-//         return Ok(Status::just_one_pass());
-//     }
-//     let kern = font.font().kern().ok();
-//     Ok(Status::just_one_pass())
-// }
+        Given all of the above, we currently treat kerning on a v0 kern table
+        as a good-to-have (but optional) feature.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/issues/1675",
+    title = "Is there a usable 'kern' table declared in the font?"
+)]
+fn kern_table(t: &Testable, context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let Some(kern) = f.legacy_kern() else {
+        return Ok(Status::just_one_pass());
+    };
+    let kern = kern?;
+    if !kern.has_format0() {
+        return Ok(Status::just_one_warn(
+            "kern-unknown-format",
+            "The 'kern' table does not have any format-0 subtable and will not work in a few programs that may require the table.",
+        ));
+    }
+    let mapped_glyphs: HashSet<u32> = f
+        .font()
+        .charmap()
+        .mappings()
+        .map(|(_u, gid)| gid.to_u32())
+        .collect();
+    let mut non_character_glyphs = kern
+        .subtables
+        .iter()
+        .flat_map(|subtable| subtable.pairs.iter())
+        .flat_map(|(left, right, _)| [*left, *right])
+        .filter(|gid| !mapped_glyphs.contains(&gid.to_u32()))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .map(|gid| f.glyph_name_for_id_synthesise(gid))
+        .collect::<Vec<_>>();
+    non_character_glyphs.sort();
+    if !non_character_glyphs.is_empty() {
+        return Ok(Status::just_one_fail(
+            "kern-non-character-glyphs",
+            &format!(
+                "The following glyphs should not be used in the 'kern' table because they are not in the 'cmap' table:\n\n{}",
+                bullet_list(context, non_character_glyphs)
+            ),
+        ));
+    }
+    Ok(Status::just_one_info(
+        "kern-found",
+        "Only a few programs may require the kerning info that this font provides on its 'kern' table.",
+    ))
+}
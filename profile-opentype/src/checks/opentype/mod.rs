@@ -10,6 +10,7 @@ mod fsselection;
 mod glyf_non_transformed_duplicate_components;
 mod glyf_unused_data;
 mod italic_angle;
+mod kern_table;
 mod layout_valid_feature_tags;
 mod layout_valid_language_tags;
 mod layout_valid_script_tags;
@@ -28,7 +29,6 @@ mod xavgcharwidth;
 pub mod STAT;
 pub mod family;
 pub mod fvar;
-pub mod kern_table;
 pub mod loca;
 pub mod name;
 pub mod varfont;
@@ -41,6 +41,7 @@ pub use fsselection::fsselection;
 pub use glyf_non_transformed_duplicate_components::glyf_non_transformed_duplicate_components;
 pub use glyf_unused_data::glyf_unused_data;
 pub use italic_angle::italic_angle;
+pub use kern_table::kern_table;
 pub use layout_valid_feature_tags::layout_valid_feature_tags;
 pub use layout_valid_language_tags::layout_valid_language_tags;
 pub use layout_valid_script_tags::layout_valid_script_tags;
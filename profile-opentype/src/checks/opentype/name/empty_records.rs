@@ -5,7 +5,8 @@ use skrifa::raw::TableProvider;
     id = "opentype/name/empty_records",
     title = "Check name table for empty records.",
     rationale = "Check the name table for empty records, as this can cause problems in Adobe apps.",
-    proposal = "https://github.com/fonttools/fontbakery/pull/2369"
+    proposal = "https://github.com/fonttools/fontbakery/pull/2369",
+    tags = ["naming"]
 )]
 fn empty_records(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -22,7 +22,8 @@ use skrifa::raw::types::NameId;
         https://docs.microsoft.com/en-us/typography/opentype/otspec150/name#name-ids
     "#,
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Does full font name begin with the font family name?"
+    title = "Does full font name begin with the font family name?",
+    tags = ["naming"]
 )]
 fn match_familyname_fullfont(t: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(t);
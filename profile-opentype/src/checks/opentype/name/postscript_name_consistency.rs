@@ -12,7 +12,8 @@ use skrifa::raw::types::NameId;
         This is the TTF/CFF2 equivalent of the CFF 'name/postscript_vs_cff' check.
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/2394",
-    title = "Name table ID 6 (PostScript name) must be consistent across platforms."
+    title = "Name table ID 6 (PostScript name) must be consistent across platforms.",
+    tags = ["naming"]
 )]
 fn postscript_name_consistency(t: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(t);
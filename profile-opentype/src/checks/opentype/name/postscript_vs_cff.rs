@@ -12,7 +12,8 @@ use skrifa::raw::{types::NameId, TableProvider};
         no guarantee which table an app will get the data from.
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/2229",
-    title = "CFF table FontName must match name table ID 6 (PostScript name)."
+    title = "CFF table FontName must match name table ID 6 (PostScript name).",
+    tags = ["naming"]
 )]
 fn postscript_vs_cff(t: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(t);
@@ -15,7 +15,8 @@ use skrifa::GlyphId;
         some software, and should be corrected.
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/735",
-    title = "Check for points out of bounds"
+    title = "Check for points out of bounds",
+    tags = ["outline"]
 )]
 fn points_out_of_bounds(t: &Testable, context: &Context) -> CheckFnResult {
     let ttf = testfont!(t);
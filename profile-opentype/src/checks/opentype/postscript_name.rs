@@ -5,7 +5,8 @@ use skrifa::raw::types::NameId;
     id = "opentype/postscript_name",
     title = "PostScript name follows OpenType specification requirements?",
     rationale = "The PostScript name is used by some applications to identify the font. It should only consist of characters from the set A-Z, a-z, 0-9, and hyphen.",
-    proposal = "https://github.com/miguelsousa/openbakery/issues/62"
+    proposal = "https://github.com/miguelsousa/openbakery/issues/62",
+    tags = ["naming"]
 )]
 fn postscript_name(t: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(t);
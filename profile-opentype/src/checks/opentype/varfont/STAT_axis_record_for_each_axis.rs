@@ -13,7 +13,8 @@ use skrifa::MetadataProvider;
         https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-records
     ",
     title = "All fvar axes have a correspondent Axis Record on STAT table?",
-    proposal = "https://github.com/fonttools/fontbakery/pull/3017"
+    proposal = "https://github.com/fonttools/fontbakery/pull/3017",
+    tags = ["variable"]
 )]
 fn STAT_axis_record_for_each_axis(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
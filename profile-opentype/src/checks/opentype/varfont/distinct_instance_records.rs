@@ -13,7 +13,8 @@ use skrifa::MetadataProvider;
         and distinct subfamilyNameID and postScriptName ID values. If two or more
         records share the same coordinates, the same nameID values or the same
         postScriptNameID values, then all but the first can be ignored.",
-    proposal = "https://github.com/fonttools/fontbakery/issues/3706"
+    proposal = "https://github.com/fonttools/fontbakery/issues/3706",
+    tags = ["variable"]
 )]
 fn distinct_instance_records(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
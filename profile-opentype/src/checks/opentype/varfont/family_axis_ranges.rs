@@ -5,7 +5,8 @@ use fontspector_checkapi::{prelude::*, skip, FileTypeConvert, StatusCode};
     title = "Check that family axis ranges are identical",
     rationale = "Between members of a family (such as Roman & Italic), the ranges of variable axes must be identical.",
     proposal = "https://github.com/fonttools/fontbakery/issues/4445",
-    implementation = "all"
+    implementation = "all",
+    tags = ["variable"]
 )]
 fn family_axis_ranges(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mut fonts = TTF.from_collection(c);
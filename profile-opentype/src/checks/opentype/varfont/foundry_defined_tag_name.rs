@@ -12,7 +12,8 @@ const REGISTERED_AXIS_TAGS: [&str; 5] = ["ital", "opsz", "slnt", "wdth", "wght"]
 
     Foundry-defined tags must begin with an uppercase letter
     and must use only uppercase letters or digits.",
-    proposal = "https://github.com/fonttools/fontbakery/issues/4043"
+    proposal = "https://github.com/fonttools/fontbakery/issues/4043",
+    tags = ["variable"]
 )]
 fn foundry_defined_tag_name(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -13,7 +13,8 @@ use skrifa::MetadataProvider;
         all either including or omitting the postScriptNameID field. [...]
         If the value is 0xFFFF, then the value is ignored, and no PostScript name
         equivalent is provided for the instance.",
-    proposal = "https://github.com/fonttools/fontbakery/issues/3705"
+    proposal = "https://github.com/fonttools/fontbakery/issues/3705",
+    tags = ["variable"]
 )]
 fn same_size_instance_records(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
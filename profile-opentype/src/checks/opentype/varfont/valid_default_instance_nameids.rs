@@ -21,7 +21,8 @@ use skrifa::MetadataProvider;
         included in instance records, and the postScriptNameID value should be set
         to 6 or to a name ID with the same value as name ID 6.
     "#,
-    proposal = "https://github.com/fonttools/fontbakery/issues/3708"
+    proposal = "https://github.com/fonttools/fontbakery/issues/3708",
+    tags = ["naming", "variable"]
 )]
 fn valid_default_instance_nameids(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
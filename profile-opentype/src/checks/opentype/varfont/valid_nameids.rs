@@ -25,7 +25,8 @@ use skrifa::MetadataProvider;
         2 or 17 can be used; otherwise, values must be greater than 255 and less
         than 32768.
     "#,
-    proposal = "https://github.com/fonttools/fontbakery/issues/3703"
+    proposal = "https://github.com/fonttools/fontbakery/issues/3703",
+    tags = ["naming", "variable"]
 )]
 fn valid_nameids(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
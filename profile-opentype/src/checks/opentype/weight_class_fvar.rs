@@ -5,7 +5,8 @@ use skrifa::raw::TableProvider;
     id = "opentype/weight_class_fvar",
     rationale = "According to Microsoft's OT Spec the OS/2 usWeightClass should match the fvar default value.",
     proposal = "https://github.com/googlefonts/gftools/issues/477",
-    title = "Checking if OS/2 usWeightClass matches fvar."
+    title = "Checking if OS/2 usWeightClass matches fvar.",
+    tags = ["variable"]
 )]
 fn weight_class_fvar(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
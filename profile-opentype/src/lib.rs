@@ -34,7 +34,7 @@ impl fontspector_checkapi::Plugin for OpenType {
             .add_and_register_check(checks::opentype::glyf_non_transformed_duplicate_components)
             .add_and_register_check(checks::opentype::glyf_unused_data)
             .add_and_register_check(checks::opentype::italic_angle)
-            // .add_and_register_check(checks::opentype::kern_table) // https://github.com/googlefonts/fontations/issues/1183
+            .add_and_register_check(checks::opentype::kern_table)
             .add_and_register_check(checks::opentype::layout_valid_feature_tags)
             .add_and_register_check(checks::opentype::layout_valid_language_tags)
             .add_and_register_check(checks::opentype::layout_valid_script_tags)
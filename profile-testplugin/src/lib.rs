@@ -88,4 +88,4 @@ impl fontspector_checkapi::Plugin for Test {
 }
 
 #[cfg(not(target_family = "wasm"))]
-pluginator::plugin_implementation!(fontspector_checkapi::Plugin, Test);
+fontspector_checkapi::declare_plugin!(Test);
@@ -0,0 +1,127 @@
+use std::collections::{BTreeSet, HashSet};
+
+use fontspector_checkapi::{prelude::*, FileTypeConvert};
+use skrifa::string::StringId;
+
+/// Add a name to a set of seen names, recording it as a duplicate if it was already there
+fn record_name(name: String, seen: &mut HashSet<String>, duplicates: &mut BTreeSet<String>) {
+    if !seen.insert(name.clone()) {
+        duplicates.insert(name);
+    }
+}
+
+#[check(
+    id = "typenetwork/family/duplicated_names",
+    rationale = "
+        Having duplicated name records can produce several issues like not all fonts
+        being listed on design apps or incorrect automatic creation of CSS classes
+        and @font-face rules.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/4260",
+    title = "Check if font doesn't have duplicated names within a family.",
+    implementation = "all",
+    tags = ["naming"],
+    codes = {
+        "duplicated-subfamily-names": FAIL,
+        "duplicated-fullfont-names": FAIL,
+    }
+)]
+fn duplicated_names(c: &TestableCollection, context: &Context) -> CheckFnResult {
+    let fonts = TTF.from_collection(c);
+    let mut subfamily_names = HashSet::new();
+    let mut fullfont_names = HashSet::new();
+    let mut duplicate_subfamily_names = BTreeSet::new();
+    let mut duplicate_fullfont_names = BTreeSet::new();
+
+    for font in fonts.iter() {
+        let family_name = font.best_familyname().unwrap_or_default();
+        if font.is_variable_font() {
+            for (instance_name, _) in font.named_instances() {
+                record_name(
+                    format!("{} {}", family_name, instance_name),
+                    &mut fullfont_names,
+                    &mut duplicate_fullfont_names,
+                );
+                record_name(
+                    instance_name,
+                    &mut subfamily_names,
+                    &mut duplicate_subfamily_names,
+                );
+            }
+        } else {
+            if let Some(subfamily_name) = font.best_subfamilyname() {
+                record_name(
+                    subfamily_name,
+                    &mut subfamily_names,
+                    &mut duplicate_subfamily_names,
+                );
+            }
+            if let Some(full_name) = font.get_best_name(&[StringId::FULL_NAME]) {
+                record_name(
+                    full_name,
+                    &mut fullfont_names,
+                    &mut duplicate_fullfont_names,
+                );
+            }
+        }
+    }
+
+    let mut problems = vec![];
+    if !duplicate_subfamily_names.is_empty() {
+        problems.push(Status::fail(
+            "duplicated-subfamily-names",
+            &format!(
+                "Following subfamily names are duplicate:\n\n{}",
+                bullet_list(context, duplicate_subfamily_names)
+            ),
+        ));
+    }
+    if !duplicate_fullfont_names.is_empty() {
+        problems.push(Status::fail(
+            "duplicated-fullfont-names",
+            &format!(
+                "Following full font names are duplicate:\n\n{}",
+                bullet_list(context, duplicate_fullfont_names)
+            ),
+        ));
+    }
+    return_result(problems)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, run_check_on_collection},
+        StatusCode, TEST_FILE,
+    };
+
+    #[test]
+    fn test_duplicated_names() {
+        assert_pass(run_check_on_collection(
+            duplicated_names,
+            vec![
+                TEST_FILE!("montserrat/Montserrat-Regular.ttf"),
+                TEST_FILE!("montserrat/Montserrat-Bold.ttf"),
+            ],
+        ));
+
+        let mut duplicate = TEST_FILE!("montserrat/Montserrat-Regular.ttf");
+        duplicate.filename = "Montserrat-Regular-Copy.ttf".into();
+        let result = run_check_on_collection(
+            duplicated_names,
+            vec![TEST_FILE!("montserrat/Montserrat-Regular.ttf"), duplicate],
+        );
+        assert_results_contain(
+            result.clone(),
+            StatusCode::Fail,
+            Some("duplicated-subfamily-names".to_string()),
+        );
+        assert_results_contain(
+            result,
+            StatusCode::Fail,
+            Some("duplicated-fullfont-names".to_string()),
+        );
+    }
+}
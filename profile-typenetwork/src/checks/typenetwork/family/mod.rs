@@ -0,0 +1,8 @@
+mod duplicated_names;
+pub use duplicated_names::duplicated_names;
+mod tnum_horizontal_metrics;
+pub use tnum_horizontal_metrics::tnum_horizontal_metrics;
+mod valid_strikeout;
+pub use valid_strikeout::valid_strikeout;
+mod valid_underline;
+pub use valid_underline::valid_underline;
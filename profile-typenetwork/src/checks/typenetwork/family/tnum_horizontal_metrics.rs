@@ -0,0 +1,118 @@
+use std::collections::{BTreeSet, HashMap};
+
+use fontspector_checkapi::{prelude::*, FileTypeConvert};
+
+#[check(
+    id = "typenetwork/family/tnum_horizontal_metrics",
+    rationale = "
+        Tabular figures need to have the same metrics in all styles in order to allow
+        tables to be set with proper typographic control, but to maintain the placement
+        of decimals and numeric columns between rows.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/4260",
+    title = "All tabular figures must have the same width across the family.",
+    implementation = "all",
+    codes = {
+        "no-siblings": SKIP,
+        "inconsistent-widths": WARN,
+    }
+)]
+fn tnum_horizontal_metrics(c: &TestableCollection, context: &Context) -> CheckFnResult {
+    let fonts = TTF.from_collection(c);
+    if fonts.len() < 2 {
+        return Err(CheckError::Skip {
+            code: "no-siblings".to_string(),
+            message: "No sibling fonts found".to_string(),
+        });
+    }
+    let mut tnum_widths: HashMap<i32, BTreeSet<String>> = HashMap::new();
+    for font in fonts {
+        let tnum_glyphs = font
+            .all_glyphs()
+            .flat_map(|g| font.glyph_name_for_id(g).map(|name| (g, name)))
+            .filter(|(_, name)| name.contains(".tnum"))
+            .collect::<Vec<_>>();
+        // Tabular figures must keep their width at every instance of a variable font
+        for (instance, location) in font.instance_locations() {
+            for (glyph_id, glyph_name) in tnum_glyphs.iter() {
+                if let Some(width) = font.advance_width(*glyph_id, &location) {
+                    let glyph_name = if font.is_variable_font() {
+                        format!("{} ({})", glyph_name, instance)
+                    } else {
+                        glyph_name.to_string()
+                    };
+                    tnum_widths
+                        .entry(width.round() as i32)
+                        .or_default()
+                        .insert(glyph_name);
+                }
+            }
+        }
+    }
+    if tnum_widths.len() < 2 {
+        return Ok(Status::just_one_pass());
+    }
+    let most_common_width = tnum_widths
+        .iter()
+        .max_by_key(|(width, glyphs)| (glyphs.len(), *width))
+        .map(|(width, _)| *width)
+        .ok_or_else(|| CheckError::Error("No tabular glyph widths found".to_string()))?;
+    let mut others = tnum_widths
+        .iter()
+        .filter(|(width, _)| **width != most_common_width)
+        .collect::<Vec<_>>();
+    others.sort_by_key(|(width, _)| **width);
+    Ok(Status::just_one_warn(
+        "inconsistent-widths",
+        &format!(
+            "The most common tabular glyph width is {}. But there are other tabular glyphs with different widths such as the following ones:\n\n{}",
+            most_common_width,
+            bullet_list(
+                context,
+                others.into_iter().map(|(width, glyphs)| {
+                    format!(
+                        "Width: {} - Glyphs: {}",
+                        width,
+                        glyphs
+                            .iter()
+                            .map(|s| format!("'{}'", s))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                })
+            )
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, run_check_on_collection},
+        StatusCode, TEST_FILE,
+    };
+
+    #[test]
+    fn test_tnum_horizontal_metrics() {
+        assert_pass(run_check_on_collection(
+            tnum_horizontal_metrics,
+            vec![
+                TEST_FILE!("librecaslontext/LibreCaslonText[wght].ttf"),
+                TEST_FILE!("librecaslontext/LibreCaslonText-Italic[wght].ttf"),
+            ],
+        ));
+        assert_results_contain(
+            run_check_on_collection(
+                tnum_horizontal_metrics,
+                vec![
+                    TEST_FILE!("shantell/ShantellSans[BNCE,INFM,SPAC,wght].ttf"),
+                    TEST_FILE!("shantell/ShantellSans-Italic[BNCE,INFM,SPAC,wght].ttf"),
+                ],
+            ),
+            StatusCode::Warn,
+            Some("inconsistent-widths".to_string()),
+        );
+    }
+}
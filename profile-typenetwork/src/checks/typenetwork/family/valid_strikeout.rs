@@ -0,0 +1,57 @@
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
+use skrifa::raw::TableProvider;
+
+#[check(
+    id = "typenetwork/family/valid_strikeout",
+    rationale = "
+        If strikeout size is not set, nothing gets rendered on Figma.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/4260",
+    title = "Font has a valid strikeout size?",
+    codes = {
+        "invalid-strikeout-size": FAIL,
+    }
+)]
+fn valid_strikeout(t: &Testable, _context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let strikeout_size = f.font().os2()?.y_strikeout_size();
+    Ok(if strikeout_size == 0 {
+        Status::just_one_fail(
+            "invalid-strikeout-size",
+            &format!(
+                "Size of the strikeout is {} which is not valid.",
+                strikeout_size
+            ),
+        )
+    } else {
+        Status::just_one_pass()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, replace_table, run_check},
+        StatusCode, TEST_FILE,
+    };
+    use skrifa::FontRef;
+    use write_fonts::{from_obj::FromTableRef, tables::os2::Os2};
+
+    #[test]
+    fn test_valid_strikeout() {
+        let mut testable = TEST_FILE!("montserrat/Montserrat-Regular.ttf");
+        assert_pass(run_check(valid_strikeout, testable.clone()));
+
+        let font = FontRef::new(&testable.contents).unwrap();
+        let mut os2 = Os2::from_table_ref(&font.os2().unwrap());
+        os2.y_strikeout_size = 0;
+        replace_table(&mut testable, &os2);
+        assert_results_contain(
+            run_check(valid_strikeout, testable),
+            StatusCode::Fail,
+            Some("invalid-strikeout-size".to_string()),
+        );
+    }
+}
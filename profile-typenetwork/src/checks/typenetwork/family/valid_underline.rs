@@ -0,0 +1,57 @@
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
+use skrifa::raw::TableProvider;
+
+#[check(
+    id = "typenetwork/family/valid_underline",
+    rationale = "
+        If underline thickness is not set nothing gets rendered on Figma.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/4260",
+    title = "Font has a valid underline thickness?",
+    codes = {
+        "invalid-underline-thickness": FAIL,
+    }
+)]
+fn valid_underline(t: &Testable, _context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let underline_thickness = f.font().post()?.underline_thickness().to_i16();
+    Ok(if underline_thickness == 0 {
+        Status::just_one_fail(
+            "invalid-underline-thickness",
+            &format!(
+                "Thickness of the underline is {} which is not valid.",
+                underline_thickness
+            ),
+        )
+    } else {
+        Status::just_one_pass()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, replace_table, run_check},
+        StatusCode, TEST_FILE,
+    };
+    use skrifa::{raw::types::FWord, FontRef};
+    use write_fonts::{from_obj::FromTableRef, tables::post::Post};
+
+    #[test]
+    fn test_valid_underline() {
+        let mut testable = TEST_FILE!("montserrat/Montserrat-Regular.ttf");
+        assert_pass(run_check(valid_underline, testable.clone()));
+
+        let font = FontRef::new(&testable.contents).unwrap();
+        let mut post = Post::from_table_ref(&font.post().unwrap());
+        post.underline_thickness = FWord::new(0);
+        replace_table(&mut testable, &post);
+        assert_results_contain(
+            run_check(valid_underline, testable),
+            StatusCode::Fail,
+            Some("invalid-underline-thickness".to_string()),
+        );
+    }
+}
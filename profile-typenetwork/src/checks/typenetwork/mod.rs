@@ -0,0 +1,4 @@
+pub mod family;
+pub mod name;
+mod weightclass;
+pub use weightclass::weightclass;
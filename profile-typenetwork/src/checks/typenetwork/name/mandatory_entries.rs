@@ -0,0 +1,78 @@
+use fontspector_checkapi::{constants::RIBBI_STYLE_NAMES, prelude::*, testfont, FileTypeConvert};
+use skrifa::string::StringId;
+
+#[check(
+    id = "typenetwork/name/mandatory_entries",
+    rationale = "
+        For proper functioning, fonts must have some specific records.
+        Other name records are optional but desirable to be present.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/4260",
+    title = "Font has all mandatory 'name' table entries?",
+    tags = ["naming"],
+    codes = {
+        "missing-entry": FAIL,
+    }
+)]
+fn mandatory_entries(t: &Testable, _context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let mut problems = vec![];
+    let mut required_name_ids = vec![
+        StringId::FAMILY_NAME,
+        StringId::SUBFAMILY_NAME,
+        StringId::UNIQUE_ID,
+        StringId::FULL_NAME,
+        StringId::VERSION_STRING,
+        StringId::POSTSCRIPT_NAME,
+    ];
+    if let Some(style) = f.style() {
+        if !RIBBI_STYLE_NAMES.contains(&style) {
+            required_name_ids.push(StringId::TYPOGRAPHIC_FAMILY_NAME);
+            required_name_ids.push(StringId::TYPOGRAPHIC_SUBFAMILY_NAME);
+        }
+    }
+    for name_id in required_name_ids {
+        let strings = f.get_name_entry_strings(name_id).collect::<Vec<_>>();
+        if strings.is_empty() || strings.iter().any(|s| s.is_empty()) {
+            problems.push(Status::fail(
+                "missing-entry",
+                &format!(
+                    "Font lacks entry with nameId={} ({:?})",
+                    name_id.to_u16(),
+                    name_id
+                ),
+            ));
+        }
+    }
+    return_result(problems)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, run_check, set_name_entry},
+        StatusCode, TEST_FILE,
+    };
+
+    #[test]
+    fn test_mandatory_entries() {
+        let mut testable = TEST_FILE!("montserrat/Montserrat-Regular.ttf");
+        assert_pass(run_check(mandatory_entries, testable.clone()));
+
+        set_name_entry(
+            &mut testable,
+            3,
+            1,
+            0x409,
+            StringId::VERSION_STRING,
+            "".to_string(),
+        );
+        assert_results_contain(
+            run_check(mandatory_entries, testable),
+            StatusCode::Fail,
+            Some("missing-entry".to_string()),
+        );
+    }
+}
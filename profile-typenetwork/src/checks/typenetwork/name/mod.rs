@@ -0,0 +1,2 @@
+mod mandatory_entries;
+pub use mandatory_entries::mandatory_entries;
@@ -0,0 +1,118 @@
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+use skrifa::raw::TableProvider;
+
+/// The expected usWeightClass values for each weight name
+const WEIGHT_VALUES: [(&str, u16); 9] = [
+    ("Thin", 100),
+    ("ExtraLight", 200),
+    ("Light", 300),
+    ("Regular", 400),
+    ("Medium", 500),
+    ("SemiBold", 600),
+    ("Bold", 700),
+    ("ExtraBold", 800),
+    ("Black", 900),
+];
+
+#[check(
+    id = "typenetwork/weightclass",
+    rationale = "
+        The Type Network expects that fonts in the catalogue have the correct
+        OS/2 usWeightClass value for their best subfamily name.
+
+        For variable fonts, usWeightClass should match the default location
+        of the weight axis.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/4260",
+    title = "Checking OS/2 usWeightClass.",
+    codes = {
+        "no-wght": SKIP,
+        "unknown-weight-name": SKIP,
+        "bad-value": FAIL,
+    }
+)]
+fn weightclass(t: &Testable, _context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let value = f.font().os2()?.us_weight_class();
+    if f.is_variable_font() {
+        let Some(default_weight) = f
+            .default_location()
+            .and_then(|location| location.get("wght").copied())
+        else {
+            skip!("no-wght", "Variable font has no 'wght' axis");
+        };
+        let expected_value = default_weight.round() as u16;
+        return Ok(if value != expected_value {
+            Status::just_one_fail(
+                "bad-value",
+                &format!(
+                    "The default weight of the variable font is {}. Expected OS/2 usWeightClass is {}, got {}.",
+                    default_weight, expected_value, value
+                ),
+            )
+        } else {
+            Status::just_one_pass()
+        });
+    }
+    let style_name = f.best_subfamilyname().unwrap_or("Regular".to_string());
+    let mut weight_name = style_name.replace("Italic", "").replace(' ', "");
+    if weight_name.is_empty() {
+        weight_name = "Regular".to_string();
+    }
+    let Some(expected_value) = WEIGHT_VALUES
+        .iter()
+        .find(|(name, _)| *name == weight_name)
+        .map(|(_, value)| *value)
+    else {
+        skip!(
+            "unknown-weight-name",
+            &format!(
+                "Could not determine a weight from the style name '{}'",
+                style_name
+            )
+        );
+    };
+    Ok(if value != expected_value {
+        Status::just_one_fail(
+            "bad-value",
+            &format!(
+                "Best SubFamily name is '{}'. Expected OS/2 usWeightClass is {}, got {}.",
+                style_name, expected_value, value
+            ),
+        )
+    } else {
+        Status::just_one_pass()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use fontspector_checkapi::{
+        codetesting::{assert_pass, assert_results_contain, replace_table, run_check},
+        StatusCode, TEST_FILE,
+    };
+    use skrifa::FontRef;
+    use write_fonts::{from_obj::FromTableRef, tables::os2::Os2};
+
+    #[test]
+    fn test_weightclass() {
+        assert_pass(run_check(
+            weightclass,
+            TEST_FILE!("montserrat/Montserrat-Bold.ttf"),
+        ));
+        assert_pass(run_check(weightclass, TEST_FILE!("varfont/Oswald-VF.ttf")));
+
+        let mut testable = TEST_FILE!("montserrat/Montserrat-Bold.ttf");
+        let font = FontRef::new(&testable.contents).unwrap();
+        let mut os2 = Os2::from_table_ref(&font.os2().unwrap());
+        os2.us_weight_class = 600;
+        replace_table(&mut testable, &os2);
+        assert_results_contain(
+            run_check(weightclass, testable),
+            StatusCode::Fail,
+            Some("bad-value".to_string()),
+        );
+    }
+}
@@ -0,0 +1,20 @@
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+mod checks;
+
+use fontspector_checkapi::{ProfileBuilder, Registry};
+
+pub struct TypeNetwork;
+impl fontspector_checkapi::Plugin for TypeNetwork {
+    fn register(&self, cr: &mut Registry) -> Result<(), String> {
+        let builder = ProfileBuilder::new()
+            .include_profile("universal")
+            .add_section("Type Network Checks")
+            .add_and_register_check(checks::typenetwork::family::duplicated_names)
+            .add_and_register_check(checks::typenetwork::family::tnum_horizontal_metrics)
+            .add_and_register_check(checks::typenetwork::family::valid_strikeout)
+            .add_and_register_check(checks::typenetwork::family::valid_underline)
+            .add_and_register_check(checks::typenetwork::name::mandatory_entries)
+            .add_and_register_check(checks::typenetwork::weightclass);
+        builder.build("typenetwork", cr)
+    }
+}
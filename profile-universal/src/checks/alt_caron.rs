@@ -43,7 +43,13 @@ fn mangle_name(glyph: &str) -> String {
         http://diacritics.typo.cz/index.php?id=5
         https://www.typotheque.com/articles/lcaron
     ",
-    proposal = "https://github.com/fonttools/fontbakery/issues/3308"
+    proposal = "https://github.com/fonttools/fontbakery/issues/3308",
+    codes = {
+        "decomposed-outline": WARN,
+        "single-compoents": WARN,
+        "bad-mark": WARN,
+        "wrong-mark": FAIL,
+    }
 )]
 fn alt_caron(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
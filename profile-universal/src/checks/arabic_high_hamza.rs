@@ -1,6 +1,4 @@
-use fontspector_checkapi::{
-    pens::AreaPen, prelude::*, skip, testfont, FileTypeConvert, DEFAULT_LOCATION,
-};
+use fontspector_checkapi::{pens::AreaPen, prelude::*, skip, testfont, FileTypeConvert};
 use skrifa::raw::tables::gdef::GlyphClassDef;
 use skrifa::MetadataProvider;
 
@@ -20,7 +18,8 @@ const ARABIC_LETTER_HIGH_HAMZA: u32 = 0x0675;
         Not doing so effectively makes the font useless for Jawi and
         possibly Kazakh as well.
     ",
-    proposal = "https://github.com/googlefonts/fontbakery/issues/4290"
+    proposal = "https://github.com/googlefonts/fontbakery/issues/4290",
+    location_sensitive = true
 )]
 fn arabic_high_hamza(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -45,13 +44,13 @@ fn arabic_high_hamza(t: &Testable, context: &Context) -> CheckFnResult {
         ))
     }
     let mut pen = AreaPen::new();
-    f.draw_glyph(high_hamza, &mut pen, DEFAULT_LOCATION)?;
+    f.draw_glyph(high_hamza, &mut pen, context.location())?;
     let high_hamza_area = pen.area();
 
     #[allow(clippy::unwrap_used)] // We just tested for it
     let hamza = f.font().charmap().map(ARABIC_LETTER_HAMZA).unwrap();
     let mut pen = AreaPen::new();
-    f.draw_glyph(hamza, &mut pen, DEFAULT_LOCATION)?;
+    f.draw_glyph(hamza, &mut pen, context.location())?;
     let hamza_area = pen.area();
 
     if ((high_hamza_area - hamza_area) / hamza_area).abs() > 0.1 {
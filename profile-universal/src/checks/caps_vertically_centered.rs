@@ -0,0 +1,66 @@
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert, DEFAULT_LOCATION};
+use skrifa::{raw::TableProvider, MetadataProvider};
+
+const SOME_UPPERCASE_GLYPHS: [char; 12] =
+    ['A', 'B', 'C', 'D', 'E', 'H', 'I', 'M', 'O', 'S', 'T', 'X'];
+
+#[check(
+    id = "caps_vertically_centered",
+    rationale = "
+        This check suggests one possible approach to designing vertical metrics,
+        but can be ingnored if you follow a different approach.
+
+        In order to center text in buttons, lists, and grid systems
+        with minimal additional CSS work, the uppercase glyphs should be
+        vertically centered in the em box.
+
+        This check mainly applies to Latin, Greek, Cyrillic, and other similar scripts.
+
+        For non-latin scripts like Arabic, this check might not be applicable.
+
+        There is a detailed description of this subject at:
+        https://x.com/romanshamin_en/status/1562801657691672576
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/issues/4139",
+    title = "Check if uppercase glyphs are vertically centered.",
+    metadata = "{\"experimental\": \"Since 2023/Oct/05\"}",
+    experimental = true,
+    tags = ["outline"]
+)]
+fn caps_vertically_centered(t: &Testable, context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    skip!(
+        f.is_cjk_font(Some(context)),
+        "cjk-font",
+        "This check does not apply to CJK fonts."
+    );
+    let charmap = f.font().charmap();
+    let mut highest_points = vec![];
+    for c in SOME_UPPERCASE_GLYPHS {
+        let bounds = match charmap.map(c) {
+            Some(gid) => f.glyph_bounds(gid, DEFAULT_LOCATION)?,
+            None => None,
+        };
+        let Some(bounds) = bounds else {
+            skip!(
+                "lacks-ascii",
+                "The implementation of this check relies on a few samples of uppercase latin characters that are not available in this font."
+            );
+        };
+        highest_points.push(bounds.y_max);
+    }
+    let upm = f.font().head()?.units_per_em() as f32;
+    let error_margin = upm * 0.05;
+    let average_cap_height = highest_points.iter().sum::<f32>() / highest_points.len() as f32;
+    let descender = f.font().hhea()?.descender().to_i16() as f32;
+    let top_margin = upm - average_cap_height;
+    let difference = (top_margin - descender.abs()).abs();
+    Ok(if difference > error_margin {
+        Status::just_one_warn(
+            "vertical-metrics-not-centered",
+            "Uppercase glyphs are not vertically centered in the em box.",
+        )
+    } else {
+        Status::just_one_pass()
+    })
+}
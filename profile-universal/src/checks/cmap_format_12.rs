@@ -11,7 +11,12 @@ use skrifa::raw::{tables::cmap::CmapSubtable, TableProvider};
         contain all characters mapped in the format 4 subtable.
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3681",
-    title = "Check that format 12 cmap subtables are correctly constituted."
+    title = "Check that format 12 cmap subtables are correctly constituted.",
+    codes = {
+        "pointless-format-12": FAIL,
+        "missing-format-4": WARN,
+        "no-format-12": SKIP,
+    }
 )]
 fn cmap_format_12(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
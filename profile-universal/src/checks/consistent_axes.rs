@@ -13,7 +13,8 @@ use skrifa::MetadataProvider;
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/2810",
     title = "Ensure that all variable font files have the same set of axes and axis ranges.",
-    implementation = "all"
+    implementation = "all",
+    tags = ["variable"]
 )]
 fn consistent_axes(c: &TestableCollection, _context: &Context) -> CheckFnResult {
     let ttfs = TTF.from_collection(c);
@@ -61,7 +61,12 @@ static GLYPHS_BY_UNICODE: LazyLock<HashMap<u32, HashSet<usize>>> = LazyLock::new
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Check if each glyph has the recommended amount of contours."
+    title = "Check if each glyph has the recommended amount of contours.",
+    tags = ["outline"],
+    codes = {
+        "contour-count": WARN,
+        "no-contour": FAIL,
+    }
 )]
 fn contour_count(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -48,7 +48,8 @@ fn is_letter(codepoint: u32) -> bool {
         More details available at https://github.com/fonttools/fontbakery/issues/2894
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/2460",
-    title = "Letters in font have glyphs that are not empty?"
+    title = "Letters in font have glyphs that are not empty?",
+    tags = ["outline"]
 )]
 fn empty_letters(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -13,7 +13,8 @@ const NAMECHECK_API_URL: &str = "http://namecheck.fontdata.com/api/";
         that is http://namecheck.fontdata.com
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/494",
-    title = "Familyname must be unique according to namecheck.fontdata.com"
+    title = "Familyname must be unique according to namecheck.fontdata.com",
+    tags = ["network"]
 )]
 fn fontdata_namecheck(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
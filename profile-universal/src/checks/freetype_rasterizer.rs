@@ -5,7 +5,8 @@ use freetype;
     id = "freetype_rasterizer",
     rationale = "Malformed fonts can cause FreeType to crash.",
     proposal = "https://github.com/fonttools/fontbakery/issues/3642",
-    title = "Ensure that the font can be rasterized by FreeType."
+    title = "Ensure that the font can be rasterized by FreeType.",
+    tags = ["slow"]
 )]
 fn freetype_rasterizer(f: &Testable, _context: &Context) -> CheckFnResult {
     let library = freetype::Library::init().map_err(|e| {
@@ -5,7 +5,8 @@ use skrifa::MetadataProvider;
     id = "fvar_name_entries",
     rationale = "The purpose of this check is to make sure that all name entries referenced by variable font instances do exist in the name table.",
     proposal = "https://github.com/fonttools/fontbakery/issues/2069",
-    title = "All name entries referenced by fvar instances exist on the name table?"
+    title = "All name entries referenced by fvar instances exist on the name table?",
+    tags = ["naming", "variable"]
 )]
 fn fvar_name_entries(t: &Testable, _context: &Context) -> CheckFnResult {
     let mut problems: Vec<Status> = vec![];
@@ -0,0 +1,121 @@
+use std::collections::{BTreeSet, HashSet};
+
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert, Glyphset};
+use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
+
+fn describe(codepoint: u32) -> String {
+    format!(
+        "U+{:04X}: {}",
+        codepoint,
+        char::from_u32(codepoint)
+            .and_then(unicode_names2::name)
+            .map(|s| s.to_string())
+            .unwrap_or("Unknown".to_string()),
+    )
+}
+
+#[check(
+    id = "glyphset_coverage",
+    rationale = "
+        Foundries and clients often have their own specifications of which
+        characters a font should support, such as \"Latin Pro\" or \"Cyrillic
+        Extended\". These can be declared as glyphsets in the `glyphsets` list of
+        this check's configuration, either in a profile's configuration defaults
+        or in the configuration file. Each glyphset has a `name` and any of
+        `codepoints` (codepoints, characters or ranges such as \"U+0400-U+04FF\"),
+        `blocks` (names of Unicode blocks) and `glyphs` (glyph names). A glyphset
+        with a `files` glob, such as \"*-Italic.ttf\", only applies to matching fonts.
+
+        This check reports characters and glyphs of the glyphsets which are missing
+        from the font, and characters in the font which are in none of them.
+    ",
+    title = "Check fonts cover the glyphsets declared for them."
+)]
+fn glyphset_coverage(t: &Testable, context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let basename = t.basename().unwrap_or_default();
+    let glyphsets = Glyphset::from_configuration(&context.local_config("glyphset_coverage"))?
+        .into_iter()
+        .filter(|glyphset| glyphset.applies_to(&basename))
+        .collect::<Vec<_>>();
+    skip!(
+        glyphsets.is_empty(),
+        "no-glyphsets",
+        "No glyphsets are declared for this font"
+    );
+    let codepoints = f.codepoints(Some(context));
+    let glyph_names = f
+        .all_glyphs()
+        .flat_map(|gid| f.glyph_name_for_id(gid))
+        .collect::<HashSet<_>>();
+
+    let mut problems = vec![];
+    let mut expected = BTreeSet::new();
+    for glyphset in glyphsets.iter() {
+        let glyphset_codepoints = glyphset.codepoints()?;
+        let missing = glyphset_codepoints
+            .iter()
+            .filter(|codepoint| !codepoints.contains(codepoint))
+            .map(|codepoint| describe(*codepoint))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            problems.push(Status::fail(
+                "missing-codepoints",
+                &format!(
+                    "The font is missing {} characters of the {} glyphset:\n\n{}",
+                    missing.len(),
+                    glyphset.name,
+                    bullet_list(context, missing)
+                ),
+            ));
+        }
+        let missing_glyphs = glyphset
+            .glyphs
+            .iter()
+            .filter(|name| !glyph_names.contains(*name))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !missing_glyphs.is_empty() {
+            problems.push(Status::fail(
+                "missing-glyphs",
+                &format!(
+                    "The font is missing {} glyphs of the {} glyphset:\n\n{}",
+                    missing_glyphs.len(),
+                    glyphset.name,
+                    bullet_list(context, missing_glyphs)
+                ),
+            ));
+        }
+        expected.extend(glyphset_codepoints);
+    }
+
+    // Glyphsets made only of glyph names say nothing about which characters to expect
+    if !expected.is_empty() {
+        let mut extra = codepoints
+            .iter()
+            .filter(|codepoint| !expected.contains(*codepoint))
+            .filter(|codepoint| {
+                char::from_u32(**codepoint)
+                    .is_some_and(|c| c.general_category() != GeneralCategory::Control)
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        extra.sort();
+        if !extra.is_empty() {
+            problems.push(Status::warn(
+                "extra-codepoints",
+                &format!(
+                    "The font has {} characters which are not in any of its glyphsets ({}):\n\n{}",
+                    extra.len(),
+                    glyphsets
+                        .iter()
+                        .map(|glyphset| glyphset.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    bullet_list(context, extra.into_iter().map(describe))
+                ),
+            ));
+        }
+    }
+    return_result(problems)
+}
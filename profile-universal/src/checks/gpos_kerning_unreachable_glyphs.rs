@@ -0,0 +1,50 @@
+use std::collections::BTreeMap;
+
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
+
+#[check(
+    id = "gpos_kerning_unreachable_glyphs",
+    rationale = "
+        Kerning pairs only have an effect when both of their glyphs can turn up
+        in text: either mapped directly from a codepoint, or produced by GSUB
+        substitutions from mapped glyphs. Kerning involving glyphs which can
+        never be reached is dead weight in the font, and often points to
+        glyphs which were meant to be reachable (for example, through a
+        feature which was not compiled).
+    ",
+    title = "Check that kerning pairs only involve reachable glyphs"
+)]
+fn gpos_kerning_unreachable_glyphs(t: &Testable, context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let model = f.gpos_model(&[])?;
+    if model.kerning.is_empty() {
+        return Ok(Status::just_one_pass());
+    }
+    let closure = f.gsub_closure(f.codepoints(None))?;
+    let mut unreachable = BTreeMap::new();
+    for pair in model.kerning.iter() {
+        for gid in [pair.left, pair.right] {
+            if !closure.contains(gid) {
+                *unreachable.entry(gid).or_insert(0) += 1;
+            }
+        }
+    }
+    if unreachable.is_empty() {
+        Ok(Status::just_one_pass())
+    } else {
+        Ok(Status::just_one_warn(
+            "kerning-unreachable-glyphs",
+            &format!(
+                "The following glyphs are kerned, but cannot be reached by codepoint or substitution rules:\n\n{}",
+                bullet_list(
+                    context,
+                    unreachable.iter().map(|(gid, count)| format!(
+                        "{} ({} pairs)",
+                        f.glyph_name_for_id_synthesise(*gid),
+                        count
+                    ))
+                )
+            ),
+        ))
+    }
+}
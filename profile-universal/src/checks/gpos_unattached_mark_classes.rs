@@ -0,0 +1,48 @@
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
+
+#[check(
+    id = "gpos_unattached_mark_classes",
+    rationale = "
+        Mark attachment lookups in the GPOS table sort marks into classes,
+        and give each base, ligature or mark glyph an anchor for each class.
+        A mark class which no glyph has an anchor for can never be positioned
+        by that lookup; this usually means that anchors were lost or renamed
+        in the font sources, so that marks will sit in the wrong place.
+    ",
+    title = "Check that every GPOS mark class is attached to some glyph"
+)]
+fn gpos_unattached_mark_classes(t: &Testable, context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let model = f.gpos_model(&[])?;
+    let unattached = model
+        .mark_classes
+        .iter()
+        .filter(|class| !class.is_attached())
+        .map(|class| {
+            format!(
+                "Lookup {}, subtable {}, class {} ({} marks): {}",
+                class.lookup,
+                class.subtable,
+                class.class,
+                class.marks.len(),
+                class
+                    .marks
+                    .keys()
+                    .map(|gid| f.glyph_name_for_id_synthesise(*gid))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+        .collect::<Vec<_>>();
+    if unattached.is_empty() {
+        Ok(Status::just_one_pass())
+    } else {
+        Ok(Status::just_one_warn(
+            "unattached-mark-classes",
+            &format!(
+                "The following mark classes have no anchors on any glyph, so the marks in them cannot be positioned:\n\n{}",
+                bullet_list(context, unattached)
+            ),
+        ))
+    }
+}
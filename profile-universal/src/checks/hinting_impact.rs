@@ -79,7 +79,8 @@ fn any_glyphs_have_instructions(font: &FontRef<'_>) -> Result<bool, ReadError> {
         of hinted versus unhinted font files.
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Show hinting filesize impact."
+    title = "Show hinting filesize impact.",
+    tags = ["slow"]
 )]
 fn hinting_impact(f: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(f);
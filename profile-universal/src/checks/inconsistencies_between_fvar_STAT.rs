@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+use skrifa::{
+    raw::{tables::stat::AxisValue, TableProvider},
+    MetadataProvider,
+};
+
+#[check(
+    id = "inconsistencies_between_fvar_STAT",
+    rationale = "
+        Check for inconsistencies in names and values between the fvar instances
+        and STAT table. Inconsistencies may cause issues in apps like Adobe InDesign.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/3636",
+    title = "Checking if STAT entries matches fvar and vice versa.",
+    tags = ["variable"]
+)]
+fn inconsistencies_between_fvar_STAT(t: &Testable, _context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    skip!(
+        !f.is_variable_font(),
+        "not-variable",
+        "Font is not a variable font"
+    );
+    let Ok(stat) = f.font().stat() else {
+        return Ok(Status::just_one_fail(
+            "missing-stat-table",
+            "Missing STAT table in variable font.",
+        ));
+    };
+    let stat_axis_tags = stat
+        .design_axes()?
+        .iter()
+        .map(|axis| axis.axis_tag().to_string())
+        .collect::<Vec<_>>();
+    let axis_tag = |index: u16| stat_axis_tags.get(index as usize).cloned();
+
+    // Gather all the values that STAT knows about, per axis
+    let mut stat_values: HashMap<String, Vec<f32>> = HashMap::new();
+    if let Some(Ok(subtable)) = stat.offset_to_axis_values() {
+        for axis_value in subtable.axis_values().iter().flatten() {
+            match axis_value {
+                AxisValue::Format1(av) => {
+                    if let Some(tag) = axis_tag(av.axis_index()) {
+                        stat_values
+                            .entry(tag)
+                            .or_default()
+                            .push(av.value().to_f32());
+                    }
+                }
+                AxisValue::Format2(av) => {
+                    if let Some(tag) = axis_tag(av.axis_index()) {
+                        stat_values
+                            .entry(tag)
+                            .or_default()
+                            .push(av.nominal_value().to_f32());
+                    }
+                }
+                AxisValue::Format3(av) => {
+                    if let Some(tag) = axis_tag(av.axis_index()) {
+                        stat_values
+                            .entry(tag)
+                            .or_default()
+                            .push(av.value().to_f32());
+                    }
+                }
+                AxisValue::Format4(av) => {
+                    for record in av.axis_values() {
+                        if let Some(tag) = axis_tag(record.axis_index()) {
+                            stat_values
+                                .entry(tag)
+                                .or_default()
+                                .push(record.value().to_f32());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut problems = vec![];
+    let axes = f.font().axes();
+    for instance in f.font().named_instances().iter() {
+        let name_id = instance.subfamily_name_id();
+        let Some(instance_name) = f
+            .font()
+            .localized_strings(name_id)
+            .english_or_first()
+            .map(|s| s.chars().collect::<String>())
+        else {
+            problems.push(Status::fail(
+                "missing-name-id",
+                &format!(
+                    "The name ID {} used in an fvar instance is missing from the name table.",
+                    name_id.to_u16()
+                ),
+            ));
+            continue;
+        };
+        for (axis, value) in axes.iter().zip(instance.user_coords()) {
+            let tag = axis.tag().to_string();
+            if !stat_values
+                .get(&tag)
+                .is_some_and(|values| values.contains(&value))
+            {
+                problems.push(Status::fail(
+                    "missing-fvar-instance-axis-value",
+                    &format!(
+                        "{}: '{}' axis value '{}' missing in STAT table.",
+                        instance_name, tag, value
+                    ),
+                ));
+            }
+        }
+    }
+    return_result(problems)
+}
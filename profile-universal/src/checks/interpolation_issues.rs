@@ -1,58 +1,23 @@
-use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert, TestFont};
 use interpolatable::{run_tests, Problem, ProblemDetails};
-use skrifa::raw::{tables::fvar::VariationAxisRecord, ReadError, TableProvider};
-use skrifa::{setting::VariationSetting, FontRef, GlyphId};
+use skrifa::raw::{types::F2Dot14, ReadError, TableProvider};
+use skrifa::{setting::VariationSetting, GlyphId};
 
-fn lerp(a: f32, b: f32, t: f32) -> f32 {
-    a + (b - a) * t
-}
-fn poor_mans_denormalize(peak: f32, axis: &VariationAxisRecord) -> f32 {
-    // Insert avar here
-    if peak > 0.0 {
-        lerp(
-            axis.default_value().to_f32(),
-            axis.max_value().to_f32(),
-            peak,
-        )
-    } else {
-        lerp(
-            axis.default_value().to_f32(),
-            axis.min_value().to_f32(),
-            -peak,
-        )
-    }
-}
-
-pub trait DenormalizeLocation {
-    /// Given a normalized location tuple, turn it back into a friendly representation in userspace
-    fn denormalize_location(&self, tuple: &[f32]) -> Result<Vec<VariationSetting>, ReadError>;
-}
-
-impl DenormalizeLocation for FontRef<'_> {
-    fn denormalize_location(&self, tuple: &[f32]) -> Result<Vec<VariationSetting>, ReadError> {
-        let all_axes = self.fvar()?.axes()?;
-        Ok(all_axes
-            .iter()
-            .zip(tuple)
-            .filter(|&(_axis, peak)| *peak != 0.0)
-            .map(|(axis, peak)| {
-                let value = poor_mans_denormalize(*peak, axis);
-                (axis.axis_tag().to_string().as_str(), value).into()
-            })
-            .collect())
-    }
-}
-
-fn glyph_variations(font: &FontRef, gid: GlyphId) -> Result<Vec<Vec<VariationSetting>>, ReadError> {
-    Ok(font
+fn glyph_variations(f: &TestFont, gid: GlyphId) -> Result<Vec<Vec<VariationSetting>>, ReadError> {
+    Ok(f.font()
         .gvar()?
         .glyph_variation_data(gid)?
         .map_or_else(Vec::new, |data| {
             data.tuples()
-                .flat_map(|t| {
-                    let tuple: Vec<f32> =
-                        t.peak().values.iter().map(|v| v.get().to_f32()).collect();
-                    font.denormalize_location(&tuple)
+                .map(|t| {
+                    let peak: Vec<F2Dot14> = t.peak().values.iter().map(|v| v.get()).collect();
+                    // Only name the axes this master varies along
+                    f.user_location(&peak)
+                        .into_iter()
+                        .zip(&peak)
+                        .filter(|(_, coord)| **coord != F2Dot14::ZERO)
+                        .map(|(setting, _)| setting)
+                        .collect()
                 })
                 .collect()
         }))
@@ -150,7 +115,8 @@ fn problem_report(p: &Problem) -> String {
         interpolatable crate.
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3930",
-    title = "Detect any interpolation issues in the font."
+    title = "Detect any interpolation issues in the font.",
+    tags = ["slow", "outline", "variable"]
 )]
 fn interpolation_issues(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -171,7 +137,7 @@ fn interpolation_issues(t: &Testable, context: &Context) -> CheckFnResult {
         )?;
         default_glyph.master_name = "default".to_string();
         default_glyph.master_index = 0;
-        if let Ok(variations) = glyph_variations(&font, gid) {
+        if let Ok(variations) = glyph_variations(&f, gid) {
             for variation in variations {
                 let mut glyph =
                     interpolatable::Glyph::new_from_font(&font, gid, &variation).ok_or(
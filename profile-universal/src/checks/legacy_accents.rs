@@ -29,7 +29,11 @@ const LEGACY_ACCENTS: [u32; 13] = [
         and ^ is used as an exponential operator in maths.
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4310",
-    title = "Check that legacy accents aren't used in composite glyphs."
+    title = "Check that legacy accents aren't used in composite glyphs.",
+    codes = {
+        "legacy-accents-width": FAIL,
+        "legacy-accents-gdef": FAIL,
+    }
 )]
 fn legacy_accents(f: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(f);
@@ -16,7 +16,8 @@ use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
         each instance is at the expected weight, width etc.
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3100",
-    title = "Ensure variable fonts include an avar table."
+    title = "Ensure variable fonts include an avar table.",
+    tags = ["variable"]
 )]
 fn mandatory_avar_table(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -2,7 +2,6 @@ use std::collections::{HashMap, HashSet};
 
 use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
 use itertools::Itertools;
-use skrifa::raw::TableProvider;
 use skrifa::{GlyphId, MetadataProvider};
 
 const COMMON_WIDTH_MATH_CHARS: [char; 314] = [
@@ -40,46 +39,55 @@ const COMMON_WIDTH_MATH_CHARS: [char; 314] = [
 fn math_signs_width(f: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(f);
     let charmap = font.font().charmap();
-    let hmtx = font.font().hmtx()?;
-    let mut widths: HashMap<u16, HashSet<GlyphId>> = HashMap::new();
-    for (glyph, width) in COMMON_WIDTH_MATH_CHARS
+    let math_glyphs = COMMON_WIDTH_MATH_CHARS
         .iter()
         .flat_map(|c| charmap.map(*c as u32))
-        .map(|gid| (gid, hmtx.advance(gid).unwrap_or(0)))
-    {
-        widths.entry(width).or_default().insert(glyph);
+        .collect::<Vec<_>>();
+    let mut problems = vec![];
+    for (instance, location) in font.instance_locations() {
+        let mut widths: HashMap<i32, HashSet<GlyphId>> = HashMap::new();
+        for glyph in math_glyphs.iter() {
+            let width = font.advance_width(*glyph, &location).unwrap_or(0.0);
+            widths
+                .entry(width.round() as i32)
+                .or_default()
+                .insert(*glyph);
+        }
+        if widths.len() == 1 {
+            continue;
+        }
+        let most_common_width = widths
+            .iter()
+            .max_by_key(|(_, glyphs)| glyphs.len())
+            .map(|(width, _)| *width);
+        let num_glyphs = widths.values().map(|g| g.len()).sum::<usize>();
+        if let Some(width) = most_common_width {
+            let summary = widths
+                .into_iter()
+                .filter(|(w, _)| *w != width)
+                .map(|(w, glyphs)| {
+                    format!(
+                        "width={}: {}",
+                        w,
+                        glyphs
+                            .iter()
+                            .map(|gid| font.glyph_name_for_id_synthesise(*gid))
+                            .join(", ")
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let at = if font.is_variable_font() {
+                format!(" at {}", instance)
+            } else {
+                String::new()
+            };
+            problems.push(Status::warn(
+                "width-outliers",
+                &format!("The most common width{} is {} among a set of {}  math glyphs.\nThe following math glyphs have a different width, though:\n{}"
+                , at, width, num_glyphs, summary)
+            ));
+        }
     }
-
-    let most_common_width = widths
-        .iter()
-        .max_by_key(|(_, glyphs)| glyphs.len())
-        .map(|(width, _)| *width);
-    if widths.len() == 1 {
-        return Ok(Status::just_one_pass());
-    }
-    let num_glyphs = widths.values().map(|g| g.len()).sum::<usize>();
-    if let Some(width) = most_common_width {
-        let summary = widths
-            .into_iter()
-            .filter(|(w, _)| *w != width)
-            .map(|(w, glyphs)| {
-                format!(
-                    "width={}: {}",
-                    w,
-                    glyphs
-                        .iter()
-                        .map(|gid| font.glyph_name_for_id_synthesise(*gid))
-                        .join(", ")
-                )
-            })
-            .collect::<Vec<_>>()
-            .join("\n");
-        return Ok(Status::just_one_warn(
-            "width-outliers",
-            &format!("The most common width is {} among a set of {}  math glyphs.\nThe following math glyphs have a different width, though:\n{}"
-            , width, num_glyphs, summary)
-        ));
-    }
-    // No most common
-    return Ok(Status::just_one_pass());
+    return_result(problems)
 }
@@ -5,6 +5,7 @@ mod alt_caron;
 mod arabic_high_hamza;
 mod arabic_spacing_symbols;
 mod base_has_width;
+mod caps_vertically_centered;
 mod case_mapping;
 mod cjk_chws_feature;
 mod cjk_not_enough_glyphs;
@@ -23,9 +24,13 @@ mod fontdata_namecheck;
 #[cfg(not(target_family = "wasm"))]
 mod freetype_rasterizer;
 mod fvar_name_entries;
+mod glyphset_coverage;
 mod gpos7;
 mod gpos_kerning_info;
+mod gpos_kerning_unreachable_glyphs;
+mod gpos_unattached_mark_classes;
 mod hinting_impact;
+mod inconsistencies_between_fvar_STAT;
 mod integer_ppem_if_hinted;
 mod interpolation_issues;
 mod legacy_accents;
@@ -45,6 +50,7 @@ mod smallcaps_before_ligatures;
 mod smart_dropout;
 mod soft_hyphen;
 mod stylisticset_description;
+mod table_roundtrip;
 mod tabular_kerning;
 mod transformed_components;
 mod typoascender_exceeds_Agrave;
@@ -55,7 +61,9 @@ mod unsupported_axes;
 mod unwanted_aat_tables;
 mod unwanted_tables;
 mod valid_glyphnames;
+mod varfont_bold_wght_coord;
 mod varfont_duplexed_axis_reflow;
+mod varfont_duplicate_instance_names;
 mod varfont_instances_in_order;
 mod vtt_volt_data;
 mod whitespace_glyphs;
@@ -69,6 +77,7 @@ pub use alt_caron::alt_caron;
 pub use arabic_high_hamza::arabic_high_hamza;
 pub use arabic_spacing_symbols::arabic_spacing_symbols;
 pub use base_has_width::base_has_width;
+pub use caps_vertically_centered::caps_vertically_centered;
 pub use case_mapping::case_mapping;
 pub use cjk_chws_feature::cjk_chws_feature;
 pub use cjk_not_enough_glyphs::cjk_not_enough_glyphs;
@@ -87,9 +96,13 @@ pub use fontdata_namecheck::fontdata_namecheck;
 #[cfg(not(target_family = "wasm"))]
 pub use freetype_rasterizer::freetype_rasterizer;
 pub use fvar_name_entries::fvar_name_entries;
+pub use glyphset_coverage::glyphset_coverage;
 pub use gpos7::gpos7;
 pub use gpos_kerning_info::gpos_kerning_info;
+pub use gpos_kerning_unreachable_glyphs::gpos_kerning_unreachable_glyphs;
+pub use gpos_unattached_mark_classes::gpos_unattached_mark_classes;
 pub use hinting_impact::hinting_impact;
+pub use inconsistencies_between_fvar_STAT::inconsistencies_between_fvar_STAT;
 pub use integer_ppem_if_hinted::integer_ppem_if_hinted;
 pub use interpolation_issues::interpolation_issues;
 pub use legacy_accents::legacy_accents;
@@ -109,6 +122,7 @@ pub use smallcaps_before_ligatures::smallcaps_before_ligatures;
 pub use smart_dropout::smart_dropout;
 pub use soft_hyphen::soft_hyphen;
 pub use stylisticset_description::stylisticset_description;
+pub use table_roundtrip::table_roundtrip;
 pub use tabular_kerning::tabular_kerning;
 pub use transformed_components::transformed_components;
 pub use typoascender_exceeds_Agrave::typoascender_exceeds_Agrave;
@@ -119,7 +133,9 @@ pub use unsupported_axes::unsupported_axes;
 pub use unwanted_aat_tables::unwanted_aat_tables;
 pub use unwanted_tables::unwanted_tables;
 pub use valid_glyphnames::valid_glyphnames;
+pub use varfont_bold_wght_coord::varfont_bold_wght_coord;
 pub use varfont_duplexed_axis_reflow::varfont_duplexed_axis_reflow;
+pub use varfont_duplicate_instance_names::varfont_duplicate_instance_names;
 pub use varfont_instances_in_order::varfont_instances_in_order;
 pub use vtt_volt_data::vtt_volt_data;
 pub use whitespace_glyphs::whitespace_glyphs;
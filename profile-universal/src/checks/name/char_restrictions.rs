@@ -13,7 +13,8 @@ use skrifa::raw::types::NameId;
     "#,
     proposal = "https://github.com/fonttools/fontbakery/issues/1718",
     proposal = "https://github.com/fonttools/fontbakery/issues/1663",
-    title = "Are there disallowed characters in the NAME table?"
+    title = "Are there disallowed characters in the NAME table?",
+    tags = ["naming"]
 )]
 fn char_restrictions(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
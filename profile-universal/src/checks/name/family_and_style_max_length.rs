@@ -35,7 +35,8 @@ fn low_level_names(name: &Name<'_>, name_id: NameId) -> HashMap<(u16, u16, u16),
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/1488",
     proposal = "https://github.com/fonttools/fontbakery/issues/2179",
-    title = "Combined length of family and style must not exceed 32 characters."
+    title = "Combined length of family and style must not exceed 32 characters.",
+    tags = ["naming"]
 )]
 fn family_and_style_max_length(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
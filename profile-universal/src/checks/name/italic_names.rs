@@ -9,7 +9,8 @@ use skrifa::raw::types::NameId;
         namely IDs 1 & 2 as well as 16 & 17 if they're present.
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3666",
-    title = "Check name table IDs 1, 2, 16, 17 to conform to Italic style."
+    title = "Check name table IDs 1, 2, 16, 17 to conform to Italic style.",
+    tags = ["naming"]
 )]
 fn italic_names(t: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(t);
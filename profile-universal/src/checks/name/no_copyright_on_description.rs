@@ -10,7 +10,8 @@ use skrifa::raw::types::NameId;
         be any copyright information in the description entry.
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Description strings in the name table must not contain copyright info"
+    title = "Description strings in the name table must not contain copyright info",
+    tags = ["naming"]
 )]
 fn no_copyright_on_description(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
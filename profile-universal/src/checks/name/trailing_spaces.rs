@@ -9,7 +9,8 @@ use skrifa::raw::TableProvider;
                 In most cases this can be fixed by removing trailing spaces from the
                 metadata fields in the font editor.",
     proposal = "https://github.com/googlefonts/fontbakery/issues/2417",
-    hotfix = fix_trailing_spaces
+    hotfix = fix_trailing_spaces,
+    tags = ["naming"]
 )]
 fn trailing_spaces(f: &Testable, _context: &Context) -> CheckFnResult {
     let mut problems: Vec<Status> = vec![];
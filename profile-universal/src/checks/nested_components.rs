@@ -25,7 +25,8 @@ use super::transformed_components::decompose_components_impl;
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/2961",
     title = "Ensure glyphs do not have components which are themselves components.",
-    hotfix = decompose_nested_components
+    hotfix = decompose_nested_components,
+    tags = ["outline"]
 )]
 fn nested_components(f: &Testable, context: &Context) -> CheckFnResult {
     let font = testfont!(f);
@@ -13,7 +13,8 @@ use skrifa::raw::TableProvider;
         Olli Meier a couple years ago (as of January/2022) that these entries are
         outdated and should not be produced anymore.",
     proposal = "https://github.com/googlefonts/gftools/issues/469",
-    title = "Ensure font doesn't have Mac name table entries (platform=1)."
+    title = "Ensure font doesn't have Mac name table entries (platform=1).",
+    tags = ["naming"]
 )]
 fn no_mac_entries(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
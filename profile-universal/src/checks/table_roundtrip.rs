@@ -0,0 +1,270 @@
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
+use skrifa::raw::{ReadError, TableProvider};
+use skrifa::{FontRef, GlyphId, Tag};
+use write_fonts::{
+    dump_table,
+    from_obj::FromTableRef,
+    tables::{
+        avar::Avar,
+        cmap::Cmap,
+        colr::Colr,
+        cpal::Cpal,
+        fvar::Fvar,
+        gasp::Gasp,
+        gdef::Gdef,
+        glyf::{GlyfLocaBuilder, Glyph},
+        gpos::Gpos,
+        gsub::Gsub,
+        head::Head,
+        hhea::Hhea,
+        hmtx::Hmtx,
+        hvar::Hvar,
+        loca::LocaFormat,
+        maxp::Maxp,
+        meta::Meta,
+        mvar::Mvar,
+        name::Name,
+        os2::Os2,
+        post::Post,
+        stat::Stat,
+        vhea::Vhea,
+        vmtx::Vmtx,
+    },
+    validate::Validate,
+    FontBuilder, FontWrite,
+};
+
+/// Ways in which a table can fail to survive a round trip
+enum RoundtripProblem {
+    /// skrifa could not read the table from the original font
+    Unparseable(String),
+    /// write-fonts could not compile the owned representation of the table
+    Uncompilable(String),
+    /// The compiled table means something different to the original
+    Changed(String),
+}
+
+/// Read a table, compile it, read it back again and compare the two.
+///
+/// `to_owned` reads the table from a font and converts it to its write-fonts
+/// representation; it is called once on the original font and once on a
+/// font containing the recompiled table, and the two results are compared.
+fn roundtrip_table<T>(
+    font: &FontRef,
+    tag: Tag,
+    to_owned: impl Fn(&FontRef) -> Result<T, ReadError>,
+) -> Option<RoundtripProblem>
+where
+    T: FontWrite + Validate + PartialEq,
+{
+    let original = match to_owned(font) {
+        Ok(table) => table,
+        Err(e) => return Some(RoundtripProblem::Unparseable(e.to_string())),
+    };
+    let compiled = match dump_table(&original) {
+        Ok(bytes) => bytes,
+        Err(e) => return Some(RoundtripProblem::Uncompilable(e.to_string())),
+    };
+    let rebuilt = FontBuilder::new()
+        .add_raw(tag, compiled)
+        .copy_missing_tables(font.clone())
+        .build();
+    let reparsed = FontRef::new(&rebuilt)
+        .map_err(|e| e.to_string())
+        .and_then(|f| to_owned(&f).map_err(|e| e.to_string()));
+    match reparsed {
+        Ok(table) if table == original => None,
+        Ok(_) => Some(RoundtripProblem::Changed(
+            "recompiled table differs from the original".to_string(),
+        )),
+        Err(e) => Some(RoundtripProblem::Changed(format!(
+            "recompiled table could not be read back: {}",
+            e
+        ))),
+    }
+}
+
+/// Read all glyphs in the `glyf` table as owned write-fonts glyphs
+fn owned_glyphs(font: &FontRef) -> Result<Vec<Glyph>, ReadError> {
+    let glyf = font.glyf()?;
+    let loca = font.loca(None)?;
+    let glyph_count: u32 = font.maxp()?.num_glyphs().into();
+    (0..glyph_count)
+        .map(GlyphId::from)
+        .map(|gid| {
+            Ok(loca
+                .get_glyf(gid, &glyf)?
+                .map(|g| Glyph::from_table_ref(&g))
+                .unwrap_or(Glyph::Empty))
+        })
+        .collect()
+}
+
+/// The `glyf` and `loca` tables can't be compiled separately, so they get
+/// their own round trip through [GlyfLocaBuilder].
+fn roundtrip_glyf(font: &FontRef) -> Option<RoundtripProblem> {
+    let glyphs = match owned_glyphs(font) {
+        Ok(glyphs) => glyphs,
+        Err(e) => return Some(RoundtripProblem::Unparseable(e.to_string())),
+    };
+    let mut builder = GlyfLocaBuilder::new();
+    for glyph in glyphs.iter() {
+        if let Err(e) = builder.add_glyph(glyph) {
+            return Some(RoundtripProblem::Uncompilable(e.to_string()));
+        }
+    }
+    let (glyf, loca, loca_format) = builder.build();
+    let mut head = match font.head() {
+        Ok(head) => Head::from_table_ref(&head),
+        Err(e) => return Some(RoundtripProblem::Unparseable(e.to_string())),
+    };
+    head.index_to_loc_format = if loca_format == LocaFormat::Long {
+        1
+    } else {
+        0
+    };
+    let mut builder = FontBuilder::new();
+    if let Err(e) = builder
+        .add_table(&glyf)
+        .and_then(|b| b.add_table(&loca))
+        .and_then(|b| b.add_table(&head))
+    {
+        return Some(RoundtripProblem::Uncompilable(e.to_string()));
+    }
+    let rebuilt = builder.copy_missing_tables(font.clone()).build();
+    let reparsed = FontRef::new(&rebuilt)
+        .map_err(|e| e.to_string())
+        .and_then(|f| owned_glyphs(&f).map_err(|e| e.to_string()));
+    match reparsed {
+        Ok(new_glyphs) => glyphs
+            .iter()
+            .zip(new_glyphs.iter())
+            .position(|(a, b)| a != b)
+            .map(|gid| {
+                RoundtripProblem::Changed(format!("glyph {} differs after recompilation", gid))
+            }),
+        Err(e) => Some(RoundtripProblem::Changed(format!(
+            "recompiled table could not be read back: {}",
+            e
+        ))),
+    }
+}
+
+#[check(
+    id = "table_roundtrip",
+    rationale = "
+        One way of ensuring that a font's binary data is well-formed is to
+        decompile each table, compile it again, and check that the result
+        means the same thing as the original. Tables which can't be parsed,
+        or which change when they are recompiled, often contain subtly
+        corrupt data (bad offsets, overlapping subtables, truncated arrays)
+        which some font consumers will tolerate and others will not.
+
+        This check parses each table it knows about with skrifa, converts it
+        to its write-fonts representation, compiles it and reads it back,
+        and reports any table which fails at any stage of this process.
+        It replaces the `ttx_roundtrip` check of FontBakery, which performed
+        the same task using fontTools.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/issues/1763",
+    title = "Checking font tables survive a decompile/compile round trip.",
+    tags = ["slow"]
+)]
+fn table_roundtrip(t: &Testable, context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    let font = f.font();
+    let mut results: Vec<(Tag, RoundtripProblem)> = vec![];
+
+    macro_rules! roundtrip {
+        ($tag:literal, $method:ident, $owned:ty) => {
+            let tag = Tag::new($tag);
+            if font.table_data(tag).is_some() {
+                if let Some(problem) = roundtrip_table(&font, tag, |f| {
+                    f.$method().map(|table| <$owned>::from_table_ref(&table))
+                }) {
+                    results.push((tag, problem));
+                }
+            }
+        };
+    }
+
+    roundtrip!(b"head", head, Head);
+    roundtrip!(b"hhea", hhea, Hhea);
+    roundtrip!(b"maxp", maxp, Maxp);
+    roundtrip!(b"OS/2", os2, Os2);
+    roundtrip!(b"post", post, Post);
+    roundtrip!(b"name", name, Name);
+    roundtrip!(b"cmap", cmap, Cmap);
+    roundtrip!(b"hmtx", hmtx, Hmtx);
+    roundtrip!(b"vhea", vhea, Vhea);
+    roundtrip!(b"vmtx", vmtx, Vmtx);
+    roundtrip!(b"gasp", gasp, Gasp);
+    roundtrip!(b"fvar", fvar, Fvar);
+    roundtrip!(b"avar", avar, Avar);
+    roundtrip!(b"STAT", stat, Stat);
+    roundtrip!(b"HVAR", hvar, Hvar);
+    roundtrip!(b"MVAR", mvar, Mvar);
+    roundtrip!(b"GDEF", gdef, Gdef);
+    roundtrip!(b"GSUB", gsub, Gsub);
+    roundtrip!(b"GPOS", gpos, Gpos);
+    roundtrip!(b"CPAL", cpal, Cpal);
+    roundtrip!(b"COLR", colr, Colr);
+    roundtrip!(b"meta", meta, Meta);
+
+    if f.has_table(b"glyf") {
+        if let Some(problem) = roundtrip_glyf(&font) {
+            results.push((Tag::new(b"glyf"), problem));
+        }
+    }
+
+    let mut problems = vec![];
+    let unparseable = results
+        .iter()
+        .filter_map(|(tag, problem)| match problem {
+            RoundtripProblem::Unparseable(e) => Some(format!("{}: {}", tag, e)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if !unparseable.is_empty() {
+        problems.push(Status::fail(
+            "unparseable-table",
+            &format!(
+                "The following tables could not be parsed:\n\n{}",
+                bullet_list(context, unparseable)
+            ),
+        ));
+    }
+    let uncompilable = results
+        .iter()
+        .filter_map(|(tag, problem)| match problem {
+            RoundtripProblem::Uncompilable(e) => Some(format!("{}: {}", tag, e)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if !uncompilable.is_empty() {
+        problems.push(Status::warn(
+            "uncompilable-table",
+            &format!(
+                "The following tables could be parsed but not compiled again:\n\n{}",
+                bullet_list(context, uncompilable)
+            ),
+        ));
+    }
+    let changed = results
+        .iter()
+        .filter_map(|(tag, problem)| match problem {
+            RoundtripProblem::Changed(e) => Some(format!("{}: {}", tag, e)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if !changed.is_empty() {
+        problems.push(Status::warn(
+            "changed-table",
+            &format!(
+                "The following tables changed when they were recompiled:\n\n{}",
+                bullet_list(context, changed)
+            ),
+        ));
+    }
+    return_result(problems)
+}
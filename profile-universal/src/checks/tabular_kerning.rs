@@ -112,25 +112,27 @@ fn tabular_kerning(t: &Testable, _context: &Context) -> CheckFnResult {
         }
         (tabular_numerals, tabular_glyphs)
     } else {
-        let hmtx = f.font().hmtx()?;
+        // Glyphs are only tabular if they share widths at every instance
+        let locations = f.instance_locations();
+        let widths_of = |gid: GlyphId| {
+            locations
+                .iter()
+                .map(|(_, location)| {
+                    f.advance_width(gid, location)
+                        .map(|width| width.round() as i32)
+                })
+                .collect::<Vec<_>>()
+        };
         let widths = numeral_glyphs
             .iter()
-            .flat_map(|gid| hmtx.advance(*gid))
+            .map(|gid| widths_of(*gid))
             .collect::<HashSet<_>>();
-        if widths.len() == 1 {
-            #[allow(clippy::unwrap_used)] // We know there's one element
-            let tabular_width = *widths.iter().next().unwrap();
-            let others_with_same_width = hmtx
-                .h_metrics()
-                .iter()
-                .enumerate()
-                .map(|(gid, metric)| (GlyphId::from(gid as u32), metric))
-                .filter(|(gid, metric)| {
-                    metric.advance() == tabular_width && !numeral_glyphs.contains(gid)
-                })
-                .map(|(gid, _)| gid);
-            let tabular_glyphs: HashSet<_> = others_with_same_width
+        if let (1, Some(tabular_widths)) = (widths.len(), widths.iter().next()) {
+            let tabular_glyphs: HashSet<_> = f
+                .all_glyphs()
+                .filter(|gid| !numeral_glyphs.contains(gid))
                 .filter(|gid| unicode_per_glyph.get(gid).is_some_and(is_symbol))
+                .filter(|gid| widths_of(*gid) == *tabular_widths)
                 .collect();
             (numeral_glyphs, tabular_glyphs)
         } else {
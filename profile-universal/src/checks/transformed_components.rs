@@ -52,7 +52,8 @@ fn transform_is_semi_flipped(t: Transform) -> bool {
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/2011",
     title = "Ensure component transforms do not perform scaling or rotation.",
-    hotfix = decompose_transformed_components
+    hotfix = decompose_transformed_components,
+    tags = ["outline"]
 )]
 fn transformed_components(f: &Testable, context: &Context) -> CheckFnResult {
     let font = testfont!(f);
@@ -22,7 +22,9 @@ use skrifa::{
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3170",
     title = "Checking that the typoAscender exceeds the yMax of the /Agrave.",
-    metadata = "{\"experimental\": \"since 2024/Jul/17\"}"
+    metadata = "{\"experimental\": \"since 2024/Jul/17\"}",
+    experimental = true,
+    tags = ["outline"]
 )]
 fn typoascender_exceeds_Agrave(f: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(f);
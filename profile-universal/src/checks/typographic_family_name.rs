@@ -9,7 +9,8 @@ use skrifa::raw::types::NameId;
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/4567",
     title = "Typographic Family name consistency.",
-    implementation = "all"
+    implementation = "all",
+    tags = ["naming"]
 )]
 fn typographic_family_name(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let ttfs = TTF.from_collection(c);
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert, GetSubstitutionMap};
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert, GsubClosure, Reached};
 use itertools::Itertools;
 use skrifa::raw::{
     tables::{
@@ -9,13 +9,14 @@ use skrifa::raw::{
     },
     TableProvider,
 };
-use skrifa::{charmap::MapVariant, GlyphId, MetadataProvider};
+use skrifa::{charmap::MapVariant, GlyphId, GlyphId16, MetadataProvider};
 
 #[check(
     id = "unreachable_glyphs",
     rationale = "
         Glyphs are either accessible directly through Unicode codepoints or through
-        substitution rules.
+        substitution rules. Substitutions are only followed when the glyphs they
+        act on are themselves reachable, including through contextual lookups.
 
         In Color Fonts, glyphs are also referenced by the COLR table. And mathematical
         fonts also reference glyphs via the MATH table.
@@ -30,18 +31,35 @@ fn unreachable_glyphs(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut glyphs = f.all_glyphs().collect::<HashSet<_>>();
     // cmap
-    for (_, gid) in f.font().charmap().mappings() {
-        glyphs.remove(&gid);
-    }
+    let mut start = f
+        .font()
+        .charmap()
+        .mappings()
+        .map(|(codepoint, gid)| (gid, Reached::Cmap(codepoint)))
+        .collect::<Vec<_>>();
     // UVS
     for (_, _, map) in f.font().charmap().variant_mappings() {
         match map {
             MapVariant::UseDefault => {}
             MapVariant::Variant(glyph_id) => {
-                glyphs.remove(&glyph_id);
+                start.push((glyph_id, Reached::Start));
             }
         }
     }
+    for (gid, _) in start.iter() {
+        glyphs.remove(gid);
+    }
+    // GSUB productions
+    let closure = GsubClosure::new(
+        &f,
+        start
+            .into_iter()
+            .flat_map(|(gid, how)| GlyphId16::try_from(gid).ok().map(|gid| (gid, how))),
+        |_| true,
+    )?;
+    for gid in closure.glyphs() {
+        glyphs.remove(&GlyphId::from(gid));
+    }
 
     // No math table support yet, working on it...
     // if let Some(Ok(math)) = f.font().math() {}
@@ -89,17 +107,6 @@ fn unreachable_glyphs(t: &Testable, context: &Context) -> CheckFnResult {
         }
     }
 
-    // GSUB productions
-    if let Ok(gsub) = f.font().gsub() {
-        for lookup in gsub.lookup_list()?.lookups().iter().flatten() {
-            let substitutions = lookup.subtables()?.substitutions()?;
-            for (_lhs, rhs) in substitutions {
-                for gid in rhs.iter() {
-                    glyphs.remove(&GlyphId::from(*gid));
-                }
-            }
-        }
-    }
     // Remove components used in TrueType table
     for glyph in f
         .all_glyphs()
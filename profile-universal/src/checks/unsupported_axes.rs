@@ -13,7 +13,8 @@ use skrifa::MetadataProvider;
         https://arrowtype.github.io/vf-slnt-test/
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/2866",
-    title = "Ensure VFs do not contain (yet) the ital axis."
+    title = "Ensure VFs do not contain (yet) the ital axis.",
+    tags = ["variable"]
 )]
 fn unsupported_axes(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
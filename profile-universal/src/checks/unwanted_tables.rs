@@ -28,7 +28,10 @@ The current recommendation is to completely remove the DSIG table."),
     title = "Are there unwanted tables?",
     rationale = "Some font editors store source data in their own SFNT tables, and these can sometimes sneak into final release files, which should only have OpenType spec tables.",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",  // legacy check
-    hotfix = delete_unwanted_tables
+    hotfix = delete_unwanted_tables,
+    codes = {
+        "unwanted-tables": FAIL,
+    }
 )]
 fn unwanted_tables(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
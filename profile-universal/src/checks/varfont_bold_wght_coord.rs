@@ -0,0 +1,47 @@
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+
+#[check(
+    id = "varfont/bold_wght_coord",
+    rationale = "
+        The Open-Type spec's registered design-variation tag 'wght' available at
+        https://docs.microsoft.com/en-gb/typography/opentype/spec/dvaraxistag_wght
+        does not specify a required value for the 'Bold' instance of a variable font.
+
+        But Dave Crossland suggested that we should enforce a required value of 700
+        in this case (NOTE: a distinction is made between 'no bold instance present'
+        vs 'bold instance is present but its wght coordinate is not == 700').
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/issues/1707",
+    title = "The variable font 'wght' (Weight) axis coordinate must be 700 on the 'Bold' instance.",
+    tags = ["variable"]
+)]
+fn varfont_bold_wght_coord(t: &Testable, _context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    skip!(!f.has_axis("wght"), "no-wght", "Font has no weight axis");
+    let bold_wght_coord = f
+        .named_instances()
+        .find(|(name, _coords)| name == "Bold")
+        .and_then(|(_name, coords)| coords.get("wght").copied());
+    Ok(match bold_wght_coord {
+        Some(700.0) => Status::just_one_pass(),
+        Some(coord) => Status::just_one_fail(
+            "wght-not-700",
+            &format!(
+                "The \"wght\" axis coordinate of the \"Bold\" instance must be 700. Got {} instead.",
+                coord
+            ),
+        ),
+        None => {
+            let max_wght = f
+                .axis_ranges()
+                .find(|(tag, _, _, _)| tag == "wght")
+                .map(|(_, _, _, max)| max);
+            skip!(
+                max_wght.is_some_and(|max| max < 700.0),
+                "no-bold-weight",
+                "Weight axis doesn't go up to bold"
+            );
+            Status::just_one_fail("no-bold-instance", "\"Bold\" instance not present.")
+        }
+    })
+}
@@ -25,7 +25,8 @@ use skrifa::{GlyphId, MetadataProvider};
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3187",
-    title = "Ensure VFs with duplexed axes do not vary horizontal advance."
+    title = "Ensure VFs with duplexed axes do not vary horizontal advance.",
+    tags = ["variable"]
 )]
 fn varfont_duplexed_axis_reflow(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+use skrifa::{raw::TableProvider, MetadataProvider};
+
+const PLAT_ID: u16 = 3;
+const ENC_ID: u16 = 1;
+const LANG_ID: u16 = 0x409;
+
+#[check(
+    id = "varfont/duplicate_instance_names",
+    rationale = "
+        This check's purpose is to detect duplicate named instances names in a
+        given variable font.
+
+        Repeating instance names may be the result of instances for several VF axes
+        defined in `fvar`, but since currently only weight+italic tokens are allowed
+        in instance names as per GF specs, they ended up repeating.
+
+        Instead, only a base set of fonts for the most default representation of the
+        family can be defined through instances in the `fvar` table, all other
+        instances will have to be left to access through the `STAT` table.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/issues/2986",
+    title = "Check variable font instances don't have duplicate names",
+    tags = ["naming", "variable"]
+)]
+fn varfont_duplicate_instance_names(t: &Testable, context: &Context) -> CheckFnResult {
+    let f = testfont!(t);
+    skip!(
+        !f.is_variable_font(),
+        "not-variable",
+        "Font is not a variable font"
+    );
+    let name = f.font().name()?;
+    let mut problems = vec![];
+    let mut seen = HashSet::new();
+    let mut duplicates = HashSet::new();
+    for instance in f.font().named_instances().iter() {
+        let name_id = instance.subfamily_name_id();
+        let instance_name = name
+            .name_record()
+            .iter()
+            .find(|record| {
+                record.name_id() == name_id
+                    && record.platform_id() == PLAT_ID
+                    && record.encoding_id() == ENC_ID
+                    && record.language_id() == LANG_ID
+            })
+            .and_then(|record| record.string(name.string_data()).ok())
+            .map(|s| s.chars().collect::<String>());
+        if let Some(instance_name) = instance_name {
+            if !seen.insert(instance_name.clone()) {
+                duplicates.insert(instance_name);
+            }
+        } else if !(256..32768).contains(&name_id.to_u16()) {
+            problems.push(Status::fail(
+                "invalid-name-id",
+                &format!(
+                    "Instance name ID {} is not within the range 256-32767",
+                    name_id.to_u16()
+                ),
+            ));
+        } else {
+            problems.push(Status::fail(
+                "name-record-not-found",
+                &format!(
+                    "A 'name' table record for platformID {}, encodingID {}, languageID {}({:04X}), and nameID {} was not found.",
+                    PLAT_ID, ENC_ID, LANG_ID, LANG_ID, name_id.to_u16()
+                ),
+            ));
+        }
+    }
+    if !duplicates.is_empty() {
+        let mut duplicates = duplicates.into_iter().collect::<Vec<_>>();
+        duplicates.sort();
+        problems.push(Status::fail(
+            "duplicate-instance-names",
+            &format!(
+                "Following instances names are duplicate:\n\n{}",
+                bullet_list(context, duplicates)
+            ),
+        ));
+    }
+    return_result(problems)
+}
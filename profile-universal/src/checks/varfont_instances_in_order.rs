@@ -11,7 +11,8 @@ use std::collections::HashMap;
         instances are not in order of weight.
     ",
     proposal = "https://github.com/googlefonts/fontbakery/issues/3334",
-    title = "Ensure the font's instances are in the correct order."
+    title = "Ensure the font's instances are in the correct order.",
+    tags = ["variable"]
 )]
 fn varfont_instances_in_order(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
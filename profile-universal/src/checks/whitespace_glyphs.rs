@@ -16,7 +16,11 @@ use skrifa::MetadataProvider;
         soft hyphen (U+00AD), but these are not mandatory.
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
-    title = "Font contains glyphs for whitespace characters?"
+    title = "Font contains glyphs for whitespace characters?",
+    codes = {
+        "missing-whitespace-glyph-0x0020": FAIL,
+        "missing-whitespace-glyph-0x00A0": FAIL,
+    }
 )]
 fn whitespace_glyphs(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
@@ -17,7 +17,8 @@ const BUT_NOT: [u32; 2] = [0xAD, 0x1680];
        ",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
     proposal = "https://github.com/fonttools/fontspector/issues/93",
-    title = "Whitespace glyphs have ink?"
+    title = "Whitespace glyphs have ink?",
+    tags = ["outline"]
 )]
 fn whitespace_ink(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
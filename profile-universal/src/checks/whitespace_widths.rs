@@ -1,5 +1,4 @@
 use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
-use skrifa::raw::TableProvider;
 use skrifa::MetadataProvider;
 
 #[check(
@@ -27,17 +26,24 @@ fn whitespace_widths(t: &Testable, _context: &Context) -> CheckFnResult {
         f.font().charmap().map(0x0020u32),
         f.font().charmap().map(0x00A0u32),
     ) {
-        let space_width = f.font().hmtx()?.advance(space).unwrap_or(0);
-        let nbsp_width = f.font().hmtx()?.advance(nbspace).unwrap_or(0);
-        Ok(if space_width == nbsp_width {
-            Status::just_one_pass()
-        } else {
-            let space_name = f.glyph_name_for_id_synthesise(space);
-            let nbsp_name = f.glyph_name_for_id_synthesise(nbspace);
-            Status::just_one_fail("different-widths",
-            &format!("The space glyph named {space_name} is {space_width} font units wide, non-breaking space named ({nbsp_name}) is {nbsp_width} font units wide, and both should be positive and the same. GlyphsApp has \"Sidebearing arithmetic\" (https://glyphsapp.com/tutorials/spacing) which allows you to set the non-breaking space width to always equal the space width.")
-        )
-        })
+        let space_name = f.glyph_name_for_id_synthesise(space);
+        let nbsp_name = f.glyph_name_for_id_synthesise(nbspace);
+        let mut problems = vec![];
+        for (instance, location) in f.instance_locations() {
+            let space_width = f.advance_width(space, &location).unwrap_or(0.0);
+            let nbsp_width = f.advance_width(nbspace, &location).unwrap_or(0.0);
+            if space_width != nbsp_width {
+                let at = if f.is_variable_font() {
+                    format!(" at {instance}")
+                } else {
+                    String::new()
+                };
+                problems.push(Status::fail("different-widths",
+                &format!("The space glyph named {space_name} is {space_width} font units wide{at}, non-breaking space named ({nbsp_name}) is {nbsp_width} font units wide, and both should be positive and the same. GlyphsApp has \"Sidebearing arithmetic\" (https://glyphsapp.com/tutorials/spacing) which allows you to set the non-breaking space width to always equal the space width.")
+            ));
+            }
+        }
+        return_result(problems)
     } else {
         skip!("missing-glyphs", "Space and nbspace not found in font");
     }
@@ -19,6 +19,7 @@ impl fontspector_checkapi::Plugin for Universal {
             .add_and_register_check(checks::arabic_high_hamza)
             .add_and_register_check(checks::arabic_spacing_symbols)
             .add_and_register_check(checks::base_has_width)
+            .add_and_register_check(checks::caps_vertically_centered)
             .add_and_register_check(checks::case_mapping)
             .add_and_register_check(checks::cjk_chws_feature)
             .add_and_register_check(checks::cjk_not_enough_glyphs)
@@ -46,9 +47,13 @@ impl fontspector_checkapi::Plugin for Universal {
 
         builder
             .add_and_register_check(checks::fvar_name_entries)
+            .add_and_register_check(checks::glyphset_coverage)
             .add_and_register_check(checks::gpos7)
             .add_and_register_check(checks::gpos_kerning_info)
+            .add_and_register_check(checks::gpos_kerning_unreachable_glyphs)
+            .add_and_register_check(checks::gpos_unattached_mark_classes)
             .add_and_register_check(checks::hinting_impact)
+            .add_and_register_check(checks::inconsistencies_between_fvar_STAT)
             .add_and_register_check(checks::integer_ppem_if_hinted)
             .add_and_register_check(checks::interpolation_issues)
             .add_and_register_check(checks::legacy_accents)
@@ -75,6 +80,7 @@ impl fontspector_checkapi::Plugin for Universal {
             .add_and_register_check(checks::STAT_in_statics)
             .add_and_register_check(checks::STAT_strings)
             .add_and_register_check(checks::stylisticset_description)
+            .add_and_register_check(checks::table_roundtrip)
             .add_and_register_check(checks::tabular_kerning)
             .add_and_register_check(checks::transformed_components)
             .add_and_register_check(checks::typoascender_exceeds_Agrave)
@@ -85,7 +91,9 @@ impl fontspector_checkapi::Plugin for Universal {
             .add_and_register_check(checks::unwanted_tables)
             .add_and_register_check(checks::valid_glyphnames)
             .add_and_register_check(checks::consistent_axes)
+            .add_and_register_check(checks::varfont_bold_wght_coord)
             .add_and_register_check(checks::varfont_duplexed_axis_reflow)
+            .add_and_register_check(checks::varfont_duplicate_instance_names)
             .add_and_register_check(checks::varfont_instances_in_order)
             .add_and_register_check(checks::unsupported_axes)
             .add_and_register_check(checks::vtt_volt_data)
@@ -97,18 +105,14 @@ impl fontspector_checkapi::Plugin for Universal {
         //  Checks which don't make sense any more
         //  "family/single_directory", # Fontspector assumes families are in a directory
         //  "ots", # ots checks need to be directly integrated
-        //  "ttx_roundtrip", # What's ttx? :-)
+        //  "ttx_roundtrip", # What's ttx? :-) Replaced by table_roundtrip
         //  "fontspector_version", # we'll just do this once at the start of the program, doesn't make sense for web
         //  "kerning_for_non_ligated_sequences", # I just think this is a bad check
         //  "unique_glyphnames", # valid_glyphnames also checks for uniqueness
         //  "name/italic_names", # GF-specific: https://github.com/fonttools/fontbakery/issues/4971
 
         //  Checks left to port
-        //  "caps_vertically_centered",  # Disabled: issue #4274
-        //  "inconsistencies_between_fvar_STAT",  # https://github.com/simoncozens/fontspector/issues/30
         //  "overlapping_path_segments", # This is now an outline check, right?
-        //  "varfont/bold_wght_coord",
-        //   "varfont/duplicate_instance_names",
         // ]
         // # Source checks are a good idea and we can do them with norad, but let's hold them
         // # over for another version.